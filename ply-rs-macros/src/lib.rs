@@ -33,9 +33,22 @@
 //!
 //! Use this on a container struct (e.g. `Mesh`) to read an entire PLY file into strictly typed vectors.
 //!
-//! - **Implements**: `FromPly`.
+//! - **Implements**: `FromPly` and `FromPlyWithMask`.
 //! - **Usage**: The struct must have named fields of type `Vec<T>`, where `T` implements `PlyRead` (or `PropertyAccess` + `ReadSchema`).
 //!   The field names (or `#[ply(name="...")]`) map to PLY element names (e.g. "vertex", "face").
+//! - **Presence**: A field's element must be present in the file unless the field is marked
+//!   `#[ply(optional)]`, in which case a missing element leaves it at `Vec::new()` instead of
+//!   erroring. `FromPlyWithMask::read_ply_with_mask` additionally returns a `ReadMask`
+//!   reporting, per field, whether its element was found under its primary name, a synonym,
+//!   or was missing (`ElementPresence::{Present, Synonym, Missing}`).
+//! - **Streaming**: The derive also adds an inherent `Self::read_ply_streaming(reader, on_vertex, on_face, ...)`
+//!   (one `on_<field>: impl FnMut(&Field) -> PlyResult<()>` closure per `Vec<Field>` field), which
+//!   calls the matching closure once per decoded record instead of collecting into `Vec`s - for
+//!   files too large to hold in memory at once. A closure's `Err` stops the read immediately.
+//! - **Triangulation**: A field marked `#[ply(triangulate)]` is fan-triangulated after its
+//!   element group is read - each decoded record is expanded into zero or more 3-index
+//!   records via [`ply::Polygon`](crate::ply::Polygon), so a `Vec<Face>` only ever contains
+//!   triangles regardless of how the file's faces were wound.
 //!
 //! ## `#[derive(ToPly)]`
 //!
@@ -44,13 +57,101 @@
 //! - **Implements**: `ToPly`.
 //! - **Usage**: The struct must have named fields of type `Vec<T>`, where `T` implements `WriteSchema` and `PropertyAccess`.
 //!
+//! ## `#[derive(PlyEnum)]`
+//!
+//! Use this on a field-less enum to store a PLY scalar property as a small set of named
+//! categories (e.g. a `uchar material_id`) instead of a raw integer.
+//!
+//! - **Implements**: `ply::PlyEnum`.
+//! - **Container attributes**: `#[ply(repr = "uchar")]` (required) names the backing integer PLY
+//!   scalar; `#[ply(value = 3)]` on a variant overrides its discriminant, defaulting to the
+//!   previous variant's value + 1 (0 for the first variant).
+//! - **Usage**: Reference the enum from a `#[derive(PlyRead)]`/`#[derive(PlyWrite)]` field with
+//!   `#[ply(enum, type = "uchar")]` (the `type` must name the same scalar as the enum's `repr`) -
+//!   see `#[ply(enum)]` below.
+//!
 //! # Attributes
 //!
 //! Fields can be annotated with `#[ply(...)]` to customize behavior:
 //!
 //! - `#[ply(name = "prop_name")]`: Maps the Rust field to a PLY property/element named "prop_name".
+//!   On a fixed-size array field `[T; N]` or a homogeneous N-tuple `(T, T, ..., T)` (for
+//!   `PlyRead`/`PlyWrite`), give a comma-separated list of exactly `N` names (e.g.
+//!   `#[ply(name = "x, y, z")] pos: [f32; 3]`, or the same names for `pos: (f32, f32, f32)`) to
+//!   bind each slot to its own property - a compile error if the name count and `N` disagree. `T`
+//!   must be a recognized scalar type; `Option<T>`, lists, and the `map`/`assert` attributes
+//!   aren't supported on array/tuple fields, but `#[ply(type = "...")]` is - it overrides the
+//!   on-disk scalar uniformly across all `N` components.
+//! - `#[ply(rename = "prop_name")]`: (For `PlyWrite`, non-array fields only) Overrides the
+//!   canonical property name used when writing, independently of `#[ply(name = "...")]`'s read
+//!   aliases - e.g. `#[ply(name = "nx, normal_x", rename = "nx")]` reads either spelling but
+//!   always writes `nx`. Without it, writing falls back to the first name in `#[ply(name = ...)]`
+//!   (or the field's own name). Real-world files disagree on property naming (`nx` vs `normal_x`,
+//!   `s/t` vs `u/v`); this and `#[ply(name = "...")]` together let one struct round-trip both.
 //! - `#[ply(type = "float")]`: (For `PlyRead`/`PlyWrite`) Enforces a specific PLY data type (e.g., "float", "uchar", "int").
+//! - `#[ply(enum)]`: (For `PlyRead`/`PlyWrite`, plain non-optional scalar fields only) Marks the
+//!   field's type as a `#[derive(PlyEnum)]` enum, decoding/encoding it through that derive's
+//!   discriminant mapping instead of a direct numeric cast. Always paired with
+//!   `#[ply(type = "...")]` naming the enum's `repr`, since the macro needs that to pick the
+//!   right `PropertyAccess` getter/setter bucket without knowing the enum's definition. Not
+//!   supported on `Vec<T>`, `Option<T>`, or array/tuple fields, or together with `coerce`/`map`/`map_read`/`map_write`.
+//! - `#[ply(list)]`: (For `PlyRead`/`PlyWrite`, `Vec<T>` fields only) Documents that a field is a PLY
+//!   list property - purely informational, since a `Vec<T>` field type already implies this; mirrors
+//!   the header's own `property list <count> <value> ...` wording. Most naturally paired with
+//!   `#[ply(count = "...")]` when the count type isn't the default `uchar`.
 //! - `#[ply(count = "uchar")]`: (For `PlyWrite`) Specifies the type used for the count of a list property (only for `Vec<T>` fields).
+//! - `#[ply(skip)]`: (For `PlyRead`/`PlyWrite`) Excludes a field from the property schema entirely; it's
+//!   initialized from `#[ply(default = ...)]` (or `Default`) and never read from or written to the file.
+//! - `#[ply(default = "expr")]` / bare `#[ply(default)]`: (For `PlyRead`) The expression used to
+//!   initialize the field in `new()`, in place of `Default::default()` - what the field becomes
+//!   before a matching property is read, or permanently for a `#[ply(skip)]` field. A non-bare
+//!   `#[ply(default = "expr")]` also marks the field's `ReadSchema` entry `Requiredness::Optional`,
+//!   since the whole point of giving it a meaningful fallback is that the property may legitimately
+//!   be absent from the header; the bare form only documents that the field falls back to
+//!   `Default::default()` and doesn't change its requiredness.
+//! - `#[ply(optional)]`: (For `PlyRead`, scalar and array fields) Marks the field's `ReadSchema`
+//!   entry as `Requiredness::Optional` even though the field itself isn't `Option<T>` and has no
+//!   `#[ply(default = "expr")]` - same effect `Option<T>` already has on the schema, for a field
+//!   that'd rather keep a plain type and its `Default::default()` value than wrap in `Option`.
+//!   `ReadSchema::validate_required` - which the generated `FromPly` reader calls automatically,
+//!   per matched element, before decoding any of its properties - is what actually enforces this:
+//!   a `Requiredness::Required` property missing from the header is a hard
+//!   `PlyError::Schema` naming the element and property, while an `Optional` one is silently left
+//!   at its default/fallback value.
+//! - `#[ply(assert = "expr")]`: (For `PlyRead`, scalar fields only) A boolean expression, with `value`
+//!   bound to `&self.field`, checked right after the property is set; panics naming the field and the
+//!   expression if it evaluates to `false`. There's no fallible path from `PropertyAccess::set_property`
+//!   today, so this is a hard panic rather than a recoverable `PlyError` - reject out-of-range input
+//!   before it reaches application code that isn't prepared for it.
+//! - `#[ply(coerce = "strict")]`: (For `PlyRead`, plain scalar fields only) Rejects a decoded value
+//!   that doesn't round-trip exactly into the field's type - an out-of-range or negative-into-unsigned
+//!   integer, or a `double` that isn't exactly representable as `f32` - instead of the default
+//!   `#[ply(coerce = "lossy")]` behavior of silently casting with `as`. Like `#[ply(assert = ...)]`,
+//!   there's no fallible path out of `PropertyAccess::set_property`, so a rejected value panics,
+//!   naming the property and target type. Not supported together with `ply(map/map_read)` or
+//!   `ply(type = ...)`, or on `Vec<T>` fields. For a *type*-level check instead of a value-level
+//!   one - rejecting a header that declares the wrong scalar type for a field at all, e.g. a
+//!   `double` column for an `i8` field, as a recoverable `PlyError::Schema` rather than a panic -
+//!   use the whole-struct `Self::read_strict` entry point instead of a per-field attribute.
+//! - `#[ply(triangulate)]`: (For `FromPly`, on a `Vec<T>` field where `T: ply::Polygon`) Fan-triangulates
+//!   each decoded n-gon into 3-index records; faces with fewer than three indices are dropped.
+//! - `#[ply(map = "path::to_fn")]` / `#[ply(map_read = "...", map_write = "...")]`: (For `PlyRead`, scalar
+//!   fields only) Runs a conversion between the on-disk scalar and the field's own type - e.g. a `u8`
+//!   0-255 color channel stored as a normalized `f32`. `map_read: fn(Raw) -> Field` runs after decoding;
+//!   `map_write: fn(&Field) -> Raw` runs in the generated getter so writing round-trips the conversion.
+//!   `map` is shorthand for `map_read` alone (read-only, matching `binrw`'s one-directional `map`); combine
+//!   `#[ply(type = "...")]` with these whenever the field's own type isn't itself a recognized PLY scalar.
+//! - `#[ply(flatten)]`: (For `PlyRead`/`PlyWrite`, on a field whose own type derives `PlyRead`/`PlyWrite`)
+//!   Splices the child's properties into the parent element instead of nesting it under the field's own
+//!   name - e.g. a shared `Rgba { r, g, b, a }` block embedded directly into several element types rather
+//!   than copy-pasted into each. Unlike `#[ply(name = "...")]`'s array/tuple expansion, the set of names a
+//!   flattened field contributes isn't known until `<ChildTy as ReadSchema>::schema()` /
+//!   `<ChildTy as WriteSchema>::property_type_schema()` run, so `set_property`/the getters delegate to the
+//!   child at runtime instead of matching literal names, and a collision with a sibling field's name is
+//!   caught by a `debug_assert!` in the generated schema functions rather than at compile time. Not
+//!   supported together with `name`/`rename`/`type`/`list`/`count`/`enum`/`optional`/`default`/`assert`/
+//!   `coerce`/`map`/`map_read`/`map_write`/`skip`, since none of those make sense without a single named
+//!   property of a recognized scalar type to apply to.
 //!
 //! # Example
 //!
@@ -114,16 +215,42 @@ fn get_crate_name() -> proc_macro2::TokenStream {
 
 struct PlyAttr {
     names: Vec<String>,
+    rename: Option<String>,
+    list: bool,
     count_type: Option<String>,
     explicit_type: Option<String>,
+    optional: bool,
+    skip: bool,
+    default_expr: Option<syn::Expr>,
+    assert_expr: Option<syn::Expr>,
+    map_read: Option<syn::Path>,
+    map_write: Option<syn::Path>,
+    triangulate: bool,
+    strict_coerce: bool,
+    is_enum: bool,
+    flatten: bool,
+    header: bool,
 }
 
 /// Parses the `#[ply(...)]` attributes and returns the PLY property name and optional count type.
 fn parse_ply_attr(field: &syn::Field) -> Result<PlyAttr, syn::Error> {
     let mut attr_data = PlyAttr {
         names: vec![field.ident.as_ref().unwrap().to_string()],
+        rename: None,
+        list: false,
         count_type: None,
         explicit_type: None,
+        optional: false,
+        skip: false,
+        default_expr: None,
+        assert_expr: None,
+        map_read: None,
+        map_write: None,
+        triangulate: false,
+        strict_coerce: false,
+        is_enum: false,
+        flatten: false,
+        header: false,
     };
 
     for attr in &field.attrs {
@@ -142,6 +269,17 @@ fn parse_ply_attr(field: &syn::Field) -> Result<PlyAttr, syn::Error> {
                     }
                     attr_data.names = names;
                     Ok(())
+                } else if meta.path.is_ident("rename") {
+                    let value = meta.value()?;
+                    let s: syn::LitStr = value.parse()?;
+                    attr_data.rename = Some(s.value());
+                    Ok(())
+                } else if meta.path.is_ident("list") {
+                    // `Vec<T>` already implies a list property on its own; this flag is purely
+                    // documentary, letting a field read `#[ply(list, count = "u8")]` the way
+                    // the PLY header itself reads `property list uchar ...`.
+                    attr_data.list = true;
+                    Ok(())
                 } else if meta.path.is_ident("count") {
                     let value = meta.value()?;
                     let s: syn::LitStr = value.parse()?;
@@ -152,6 +290,75 @@ fn parse_ply_attr(field: &syn::Field) -> Result<PlyAttr, syn::Error> {
                     let s: syn::LitStr = value.parse()?;
                     attr_data.explicit_type = Some(s.value());
                     Ok(())
+                } else if meta.path.is_ident("enum") {
+                    // Marks the field as backed by a `#[derive(PlyEnum)]` type; combined with
+                    // `#[ply(type = "...")]` (which picks the on-disk scalar/getter bucket) to
+                    // decode the raw discriminant through `PlyEnum::from_property`/`to_discriminant`
+                    // instead of the usual direct scalar cast.
+                    attr_data.is_enum = true;
+                    Ok(())
+                } else if meta.path.is_ident("flatten") {
+                    // Splices a nested `PlyRead`/`PlyWrite` struct's own properties into the
+                    // parent element instead of nesting under the field's own name - see the
+                    // `#[ply(flatten)]` docs above for the full set of restrictions.
+                    attr_data.flatten = true;
+                    Ok(())
+                } else if meta.path.is_ident("header") {
+                    // Marks a `FromPly`/`ToPly` container field of type `PlyHeaderMeta` as the
+                    // slot that carries the source header's comments/obj_info/element order/
+                    // property types through to `write_ply` for perfect-fidelity round-tripping.
+                    attr_data.header = true;
+                    Ok(())
+                } else if meta.path.is_ident("optional") {
+                    attr_data.optional = true;
+                    Ok(())
+                } else if meta.path.is_ident("skip") {
+                    attr_data.skip = true;
+                    Ok(())
+                } else if meta.path.is_ident("triangulate") {
+                    attr_data.triangulate = true;
+                    Ok(())
+                } else if meta.path.is_ident("coerce") {
+                    let value = meta.value()?;
+                    let s: syn::LitStr = value.parse()?;
+                    match s.value().as_str() {
+                        "strict" => attr_data.strict_coerce = true,
+                        "lossy" => attr_data.strict_coerce = false,
+                        other => return Err(meta.error(format!(
+                            "ply(coerce = \"{}\") is not recognized - use \"strict\" or \"lossy\"", other
+                        ))),
+                    }
+                    Ok(())
+                } else if meta.path.is_ident("default") {
+                    // Bare `#[ply(default)]` just documents that the field already falls back
+                    // to `Default::default()` when its property is absent - `#[ply(default = "expr")]`
+                    // overrides that fallback with a specific expression instead.
+                    if meta.input.peek(syn::Token![=]) {
+                        let value = meta.value()?;
+                        let s: syn::LitStr = value.parse()?;
+                        attr_data.default_expr = Some(s.parse()?);
+                    }
+                    Ok(())
+                } else if meta.path.is_ident("assert") {
+                    let value = meta.value()?;
+                    let s: syn::LitStr = value.parse()?;
+                    attr_data.assert_expr = Some(s.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("map") {
+                    let value = meta.value()?;
+                    let s: syn::LitStr = value.parse()?;
+                    attr_data.map_read = Some(s.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("map_read") {
+                    let value = meta.value()?;
+                    let s: syn::LitStr = value.parse()?;
+                    attr_data.map_read = Some(s.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("map_write") {
+                    let value = meta.value()?;
+                    let s: syn::LitStr = value.parse()?;
+                    attr_data.map_write = Some(s.parse()?);
+                    Ok(())
                 } else {
                     Err(meta.error(format!("unknown ply attribute: {}", meta.path.to_token_stream().to_string())))
                 }
@@ -210,18 +417,43 @@ pub fn derive_read_schema(input: TokenStream) -> TokenStream {
     let mut seen_names = std::collections::HashSet::new();
 
     for field in fields {
-        let ply_names = match parse_ply_name(field) {
-            Ok(names) => names,
+        let ply_attr = match parse_ply_attr(field) {
+            Ok(attr) => attr,
             Err(err) => return TokenStream::from(err.to_compile_error()),
         };
-        let ply_names = match validate_and_dedupe_ply_names(field, ply_names, &mut seen_names) {
+        if ply_attr.skip {
+            continue;
+        }
+        let ply_names = match validate_and_dedupe_ply_names(field, ply_attr.names, &mut seen_names) {
             Ok(names) => names,
             Err(err) => return TokenStream::from(err.to_compile_error()),
         };
-        let ply_name = ply_names[0].clone();
 
         let ply_rs = get_crate_name();
-        let requiredness = if is_option(&field.ty).is_some() {
+
+        if let Some((_, _, len)) = array_like(&field.ty) {
+            if ply_names.len() != len {
+                return TokenStream::from(syn::Error::new_spanned(field, format!(
+                    "ply(name = \"...\") lists {} name(s), but the array/tuple field has {} element(s) - they must match",
+                    ply_names.len(), len
+                )).to_compile_error());
+            }
+            let requiredness = if ply_attr.optional || ply_attr.default_expr.is_some() {
+                quote! { #ply_rs::ply::Requiredness::Optional }
+            } else {
+                quote! { #ply_rs::ply::Requiredness::Required }
+            };
+            for ply_name in &ply_names {
+                let ply_name_lit = syn::LitStr::new(ply_name, proc_macro2::Span::call_site());
+                schema_entries.push(quote! {
+                    (#ply_name_lit.to_string(), #requiredness)
+                });
+            }
+            continue;
+        }
+
+        let ply_name = ply_names[0].clone();
+        let requiredness = if is_option(&field.ty).is_some() || ply_attr.optional || ply_attr.default_expr.is_some() {
             quote! { #ply_rs::ply::Requiredness::Optional }
         } else {
             quote! { #ply_rs::ply::Requiredness::Required }
@@ -267,11 +499,28 @@ pub fn derive_ply_read(input: TokenStream) -> TokenStream {
         _ => return TokenStream::from(syn::Error::new_spanned(&input.ident, "PlyRead only supports structs").to_compile_error()),
     };
 
+    let generic_params: Vec<syn::Ident> = input.generics.type_params().map(|tp| tp.ident.clone()).collect();
+
     let mut set_arms = Vec::new();
     let mut schema_entries = Vec::new();
+    // `(property name, expects a list)` pairs `read_strict` checks a declared header
+    // property against, alongside `schema_entries`' required/optional check.
+    let mut strict_list_entries = Vec::new();
+    // `(property name, expected on-disk scalar type)` pairs `read_strict` additionally
+    // checks a declared header property's *scalar type* against - `None` for a field whose
+    // declared type isn't known until monomorphization (a generic `PlyScalar` field), which
+    // stays unchecked.
+    let mut strict_type_entries = Vec::new();
+    let mut new_field_inits = Vec::new();
     let mut seen_names = std::collections::HashSet::new();
     let ply_rs = get_crate_name();
 
+    // `#[ply(flatten)]` fields: their properties aren't known until the child's own
+    // `ReadSchema::schema()` runs, so they're threaded through separately from `set_arms`/
+    // `schema_entries` rather than matched by literal name.
+    let mut flatten_fields: Vec<&syn::Ident> = Vec::new();
+    let mut flatten_tys: Vec<&Type> = Vec::new();
+
     // Getters
     let mut get_char_arms = Vec::new();
     let mut get_uchar_arms = Vec::new();
@@ -281,6 +530,8 @@ pub fn derive_ply_read(input: TokenStream) -> TokenStream {
     let mut get_uint_arms = Vec::new();
     let mut get_float_arms = Vec::new();
     let mut get_double_arms = Vec::new();
+    let mut get_long_arms = Vec::new();
+    let mut get_ulong_arms = Vec::new();
 
     // List getters
     let mut get_list_char_arms = Vec::new();
@@ -291,6 +542,8 @@ pub fn derive_ply_read(input: TokenStream) -> TokenStream {
     let mut get_list_uint_arms = Vec::new();
     let mut get_list_float_arms = Vec::new();
     let mut get_list_double_arms = Vec::new();
+    let mut get_list_long_arms = Vec::new();
+    let mut get_list_ulong_arms = Vec::new();
 
     for field in fields {
         let field_name = &field.ident;
@@ -299,22 +552,156 @@ pub fn derive_ply_read(input: TokenStream) -> TokenStream {
             Ok(attr) => attr,
             Err(err) => return TokenStream::from(err.to_compile_error()),
         };
+
+        if ply_attr.flatten {
+            if ply_attr.skip || ply_attr.optional || ply_attr.is_enum || ply_attr.strict_coerce
+                || ply_attr.list || ply_attr.count_type.is_some() || ply_attr.explicit_type.is_some()
+                || ply_attr.default_expr.is_some() || ply_attr.assert_expr.is_some()
+                || ply_attr.map_read.is_some() || ply_attr.map_write.is_some()
+            {
+                return TokenStream::from(syn::Error::new_spanned(field, "ply(flatten) can't be combined with type/list/count/enum/optional/default/assert/coerce/map/map_read/map_write/skip").to_compile_error());
+            }
+            new_field_inits.push(quote! { #field_name: <#field_type as #ply_rs::ply::PropertyAccess>::new() });
+            flatten_fields.push(field_name.as_ref().unwrap());
+            flatten_tys.push(field_type);
+            continue;
+        }
+
+        new_field_inits.push(match &ply_attr.default_expr {
+            Some(expr) => quote! { #field_name: #expr },
+            None => quote! { #field_name: ::core::default::Default::default() },
+        });
+
+        if ply_attr.skip {
+            // A skipped field takes part in no property lookup at all; it's only
+            // initialized by `new()` above, from `#[ply(default = ...)]` or `Default`.
+            continue;
+        }
+
+        if (ply_attr.map_read.is_some() || ply_attr.map_write.is_some()) && is_vec(field_type).is_some() {
+            return TokenStream::from(syn::Error::new_spanned(field, "ply(map/map_read/map_write) is only supported on scalar fields, not Vec<T>").to_compile_error());
+        }
+        if ply_attr.assert_expr.is_some() && is_option(field_type).is_some() {
+            return TokenStream::from(syn::Error::new_spanned(field, "ply(assert = ...) is not supported on Option<T> fields").to_compile_error());
+        }
+        if ply_attr.list && is_vec(field_type).is_none() {
+            return TokenStream::from(syn::Error::new_spanned(field, "ply(list) is only valid for Vec<T> fields").to_compile_error());
+        }
+
         let ply_names = match validate_and_dedupe_ply_names(field, ply_attr.names, &mut seen_names) {
             Ok(names) => names,
             Err(err) => return TokenStream::from(err.to_compile_error()),
         };
 
+        if let Some((array_kind, elem_ty, len)) = array_like(field_type) {
+            if ply_attr.map_read.is_some() || ply_attr.map_write.is_some() || ply_attr.assert_expr.is_some() {
+                return TokenStream::from(syn::Error::new_spanned(field, "ply(map/map_read/map_write/assert) is not supported on fixed-size array/tuple fields").to_compile_error());
+            }
+            if ply_names.len() != len {
+                return TokenStream::from(syn::Error::new_spanned(field, format!(
+                    "ply(name = \"...\") lists {} name(s), but the array/tuple field has {} element(s) - they must match",
+                    ply_names.len(), len
+                )).to_compile_error());
+            }
+            // `#[ply(type = "...")]` applies uniformly to every component, the same way it
+            // overrides a plain scalar field's on-disk type below.
+            let kind = match ply_attr.explicit_type.as_deref() {
+                Some(et) => match scalar_kind_from_str(et) {
+                    Some(k) => k,
+                    None => return TokenStream::from(syn::Error::new_spanned(field, format!(
+                        "Unsupported ply(type = \"{et}\") for an array/tuple field. Use one of: i8, u8, i16, u16, i32, u32, i64, u64, f32, f64, char, uchar, short, ushort, int, uint, long, ulong, float, double"
+                    )).to_compile_error()),
+                },
+                None => match scalar_ident(elem_ty) {
+                    Some(k) => k,
+                    None => return TokenStream::from(syn::Error::new_spanned(elem_ty, "Unsupported array/tuple element type for PlyRead. Supported types: i8, u8, i16, u16, i32, u32, i64, u64, f32, f64.").to_compile_error()),
+                },
+            };
+            let (scalar_variants, cast_ty) = scalar_match_and_cast_tokens(&kind, &ply_rs);
+            let requiredness = if ply_attr.optional || ply_attr.default_expr.is_some() {
+                quote! { #ply_rs::ply::Requiredness::Optional }
+            } else {
+                quote! { #ply_rs::ply::Requiredness::Required }
+            };
+
+            for (i, ply_name) in ply_names.iter().enumerate() {
+                let element = array_kind.element_expr(field_name.as_ref().unwrap(), i);
+                let ply_name_lit = syn::LitStr::new(ply_name, proc_macro2::Span::call_site());
+                set_arms.push(quote! {
+                    #ply_name_lit => {
+                        if let Some(val) = (match property { #(#scalar_variants)* _ => None }) {
+                            #element = val;
+                        }
+                    }
+                });
+                schema_entries.push(quote! {
+                    (#ply_name_lit.to_string(), #requiredness)
+                });
+                strict_list_entries.push(quote! { (#ply_name_lit, false) });
+                let expected_type_str = kind.to_string();
+                strict_type_entries.push(quote! { (#ply_name_lit, Some(#expected_type_str)) });
+
+                let arm = quote! { #ply_name_lit => Some(#element as #cast_ty), };
+                use ScalarKind::*;
+                match kind {
+                    I8 => get_char_arms.push(arm),
+                    U8 => get_uchar_arms.push(arm),
+                    I16 => get_short_arms.push(arm),
+                    U16 => get_ushort_arms.push(arm),
+                    I32 => get_int_arms.push(arm),
+                    U32 => get_uint_arms.push(arm),
+                    F32 => get_float_arms.push(arm),
+                    F64 => get_double_arms.push(arm),
+                    I64 => get_long_arms.push(arm),
+                    U64 => get_ulong_arms.push(arm),
+                }
+            }
+            continue;
+        }
+
         let ply_name_lits: Vec<_> = ply_names.iter().map(|n| syn::LitStr::new(n, proc_macro2::Span::call_site())).collect();
         let ply_name_lit = &ply_name_lits[0];
 
         let is_opt = is_option(field_type);
         let conversion_type = if let Some(inner) = is_opt.as_ref() { inner } else { field_type };
 
+        if (ply_attr.map_read.is_some() || ply_attr.map_write.is_some())
+            && ply_attr.explicit_type.is_none()
+            && scalar_ident(conversion_type).is_none()
+        {
+            return TokenStream::from(syn::Error::new_spanned(field, "ply(map/map_read/map_write) on a field whose type isn't a recognized PLY scalar also needs ply(type = \"...\") to name the on-disk scalar").to_compile_error());
+        }
+
+        if ply_attr.strict_coerce && (is_vec(conversion_type).is_some() || ply_attr.map_read.is_some() || ply_attr.explicit_type.is_some()) {
+            return TokenStream::from(syn::Error::new_spanned(field, "ply(coerce = \"strict\") is only supported on plain scalar fields, not Vec<T>, ply(map/map_read), or ply(type = ...)").to_compile_error());
+        }
+
+        if ply_attr.is_enum {
+            if ply_attr.explicit_type.is_none() {
+                return TokenStream::from(syn::Error::new_spanned(field, "ply(enum) also needs ply(type = \"...\") to name the on-disk scalar the discriminant is encoded as").to_compile_error());
+            }
+            if is_vec(conversion_type).is_some() || is_opt.is_some() || ply_attr.strict_coerce || ply_attr.map_read.is_some() || ply_attr.map_write.is_some() {
+                return TokenStream::from(syn::Error::new_spanned(field, "ply(enum) is only supported on plain non-optional scalar fields, not Vec<T>, Option<T>, ply(coerce = \"strict\"), or ply(map/map_read/map_write)").to_compile_error());
+            }
+        }
+
         // Support explicit type override even for generic fields
-        let conversion = if let Some(et) = ply_attr.explicit_type.as_deref() {
+        let conversion = if ply_attr.is_enum {
+            Ok(quote! { #ply_rs::ply::PlyEnum::from_property(&property) })
+        } else if ply_attr.strict_coerce {
+            match scalar_ident(conversion_type) {
+                Some(kind) => Ok(strict_scalar_conversion_tokens(&kind, &ply_rs, ply_name_lit)),
+                None => Err(syn::Error::new_spanned(field, "ply(coerce = \"strict\") requires a recognized PLY scalar field type")),
+            }
+        } else if let Some(et) = ply_attr.explicit_type.as_deref() {
             let ply_rs = get_crate_name();
 
-            let check_result = if let Some(target_kind) = scalar_kind_from_str(et) {
+            // A map_read fn is expected to bridge a field type that doesn't match the
+            // on-disk scalar kind (e.g. a `u8` color channel mapped into a normalized
+            // `f32`), so the usual "declared type must match field type" check doesn't apply.
+            let check_result = if ply_attr.map_read.is_some() {
+                None
+            } else if let Some(target_kind) = scalar_kind_from_str(et) {
                 let inner_type = if let Some(inner) = is_vec(conversion_type) { inner } else { conversion_type };
                 if let Some(field_kind) = scalar_ident(inner_type) {
                     if target_kind != field_kind {
@@ -339,6 +726,8 @@ pub fn derive_ply_read(input: TokenStream) -> TokenStream {
                         "uint" | "u32" => Some(quote! { u32 }),
                         "float" | "f32" => Some(quote! { f32 }),
                         "double" | "f64" => Some(quote! { f64 }),
+                        "long" | "i64" => Some(quote! { i64 }),
+                        "ulong" | "u64" => Some(quote! { u64 }),
                         _ => None,
                     }
                 };
@@ -361,11 +750,11 @@ pub fn derive_ply_read(input: TokenStream) -> TokenStream {
                         })
                     }
                 } else {
-                    generate_conversion(conversion_type)
+                    generate_conversion(conversion_type, &generic_params)
                 }
             }
         } else {
-             generate_conversion(conversion_type)
+             generate_conversion(conversion_type, &generic_params)
         };
 
         let conversion = match conversion {
@@ -373,16 +762,42 @@ pub fn derive_ply_read(input: TokenStream) -> TokenStream {
             Err(err) => return TokenStream::from(err.to_compile_error()),
         };
 
+        // A `map`/`map_read` fn runs on the raw decoded scalar, producing the field's own
+        // type, so it composes with the existing `Option<RawScalar>` conversion above.
+        let conversion = match &ply_attr.map_read {
+            Some(path) => quote! { (#conversion).map(#path) },
+            None => conversion,
+        };
+
+        let assert_tokens = match &ply_attr.assert_expr {
+            Some(expr) => {
+                let field_name_lit = syn::LitStr::new(&field_name.as_ref().unwrap().to_string(), proc_macro2::Span::call_site());
+                quote! {
+                    {
+                        let value = &self.#field_name;
+                        if !(#expr) {
+                            panic!(
+                                "ply assert failed for field '{}': {}",
+                                #field_name_lit, stringify!(#expr)
+                            );
+                        }
+                    }
+                }
+            }
+            None => quote! {},
+        };
+
         let arm = quote! {
             #( #ply_name_lits )|* => {
                 if let Some(val) = #conversion {
                     #ply_rs::ply::SetProperty::set(&mut self.#field_name, val);
+                    #assert_tokens
                 }
             }
         };
         set_arms.push(arm);
 
-        let requiredness = if is_opt.is_some() {
+        let requiredness = if is_opt.is_some() || ply_attr.optional || ply_attr.default_expr.is_some() {
             quote! { #ply_rs::ply::Requiredness::Optional }
         } else {
             quote! { #ply_rs::ply::Requiredness::Required }
@@ -390,6 +805,29 @@ pub fn derive_ply_read(input: TokenStream) -> TokenStream {
         schema_entries.push(quote! {
             (#ply_name_lit.to_string(), #requiredness)
         });
+        let expects_list = is_vec(conversion_type).is_some();
+        for ply_name_lit in &ply_name_lits {
+            strict_list_entries.push(quote! { (#ply_name_lit, #expects_list) });
+        }
+        // The on-disk scalar type the header should declare for this field - `None` for a
+        // generic `PlyScalar` field, which isn't concrete until monomorphization.
+        let declared_kind = if let Some(et) = ply_attr.explicit_type.as_deref() {
+            scalar_kind_from_str(et)
+        } else if let Some(inner) = is_vec(conversion_type) {
+            scalar_ident(inner)
+        } else {
+            scalar_ident(conversion_type)
+        };
+        let expected_type_tokens = match &declared_kind {
+            Some(kind) => {
+                let expected_type_str = kind.to_string();
+                quote! { Some(#expected_type_str) }
+            }
+            None => quote! { None },
+        };
+        for ply_name_lit in &ply_name_lits {
+            strict_type_entries.push(quote! { (#ply_name_lit, #expected_type_tokens) });
+        }
 
         // Getter logic
         let effective_kind = if let Some(et) = ply_attr.explicit_type.as_deref() {
@@ -403,10 +841,8 @@ pub fn derive_ply_read(input: TokenStream) -> TokenStream {
                     "uint" | "u32" => Some(ScalarKind::U32),
                     "float" | "f32" => Some(ScalarKind::F32),
                     "double" | "f64" => Some(ScalarKind::F64),
-
-
-
-
+                    "long" | "i64" => Some(ScalarKind::I64),
+                    "ulong" | "u64" => Some(ScalarKind::U64),
 
 
                     _ => None,
@@ -418,7 +854,11 @@ pub fn derive_ply_read(input: TokenStream) -> TokenStream {
         };
 
         if let Some(inner_vec_type) = is_vec(conversion_type) {
-             // List type
+             // List type. `Vec<S>` for a generic `S: PlyScalar` reads fine (`generate_conversion`
+             // handles it above), but no `get_list_*` arm is generated here: the getter has to
+             // return a borrowed `&[f32]`/`&[f64]`/... slice, and there's no sound way to
+             // reinterpret the field's actual `&[S]` storage as that without knowing `S`
+             // concretely at macro-expansion time. `PropertyAccess::get_list::<S>` stays `None`.
              let inner_kind = if let Some(et) = ply_attr.explicit_type.as_deref() {
                 let scalar_type_from_str_kind = |s: &str| -> Option<ScalarKind> {
                     match s {
@@ -431,11 +871,8 @@ pub fn derive_ply_read(input: TokenStream) -> TokenStream {
                         "float" | "f32" => Some(ScalarKind::F32),
                         "double" | "f64" => Some(ScalarKind::F64),
 
-
-
-
-
-
+                        "long" | "i64" => Some(ScalarKind::I64),
+                        "ulong" | "u64" => Some(ScalarKind::U64),
                         _ => None,
                     }
                 };
@@ -462,14 +899,24 @@ pub fn derive_ply_read(input: TokenStream) -> TokenStream {
                     U32 => get_list_uint_arms.push(arm),
                     F32 => get_list_float_arms.push(arm),
                     F64 => get_list_double_arms.push(arm),
-                    I64 | U64 | I128 | U128 => {},
+                    I64 => get_list_long_arms.push(arm),
+                    U64 => get_list_ulong_arms.push(arm),
                  }
              }
         } else if let Some(kind) = effective_kind {
              // Scalar type
              use ScalarKind::*;
              let (_, cast_ty) = scalar_type_tokens(&kind, &ply_rs);
-             let field_access_scalar = quote! { #ply_rs::ply::GetProperty::<#cast_ty>::get(&self.#field_name) };
+             let field_access_scalar = if ply_attr.is_enum {
+                 quote! { Some(#ply_rs::ply::PlyEnum::to_discriminant(&self.#field_name) as #cast_ty) }
+             } else {
+                 match &ply_attr.map_write {
+                     // `map_write` runs the inverse of `map_read`, turning the field's own type
+                     // back into the on-disk scalar so writing round-trips the conversion.
+                     Some(path) => quote! { Some(#path(&self.#field_name)) },
+                     None => quote! { #ply_rs::ply::GetProperty::<#cast_ty>::get(&self.#field_name) },
+                 }
+             };
              let arm = quote! { #( #ply_name_lits )|* => #field_access_scalar, };
              match kind {
                 I8 => get_char_arms.push(arm),
@@ -480,7 +927,50 @@ pub fn derive_ply_read(input: TokenStream) -> TokenStream {
                 U32 => get_uint_arms.push(arm),
                 F32 => get_float_arms.push(arm),
                 F64 => get_double_arms.push(arm),
-                I64 | U64 | I128 | U128 => {},
+                I64 => get_long_arms.push(arm),
+                U64 => get_ulong_arms.push(arm),
+             }
+        } else if generic_scalar_param(conversion_type, &generic_params).is_some() {
+             // `T` isn't a concrete scalar until monomorphization, so every `get_*` bucket
+             // gets an arm that round-trips `self.field` through `PlyScalar::to_property` and
+             // re-matches it against that bucket's own cast - only the bucket matching `T`'s
+             // actual `SCALAR_TYPE` ever produces `Some`, mirroring how `generate_conversion`
+             // dispatches `set_property` through `PlyScalar::from_property` for the same field.
+             let value_expr = if is_opt.is_some() {
+                 quote! { self.#field_name.as_ref().map(#ply_rs::ply::PlyScalar::to_property) }
+             } else {
+                 quote! { Some(#ply_rs::ply::PlyScalar::to_property(&self.#field_name)) }
+             };
+             let buckets: [(ScalarKind, proc_macro2::TokenStream); 10] = [
+                 (ScalarKind::I8, quote! { i8 }),
+                 (ScalarKind::U8, quote! { u8 }),
+                 (ScalarKind::I16, quote! { i16 }),
+                 (ScalarKind::U16, quote! { u16 }),
+                 (ScalarKind::I32, quote! { i32 }),
+                 (ScalarKind::U32, quote! { u32 }),
+                 (ScalarKind::F32, quote! { f32 }),
+                 (ScalarKind::F64, quote! { f64 }),
+                 (ScalarKind::I64, quote! { i64 }),
+                 (ScalarKind::U64, quote! { u64 }),
+             ];
+             use ScalarKind::*;
+             for (kind, cast_ty) in buckets {
+                 let (scalar_variants, _) = scalar_match_and_cast_tokens_with_ty(&cast_ty, &ply_rs);
+                 let arm = quote! {
+                     #( #ply_name_lits )|* => (#value_expr).and_then(|property| match property { #(#scalar_variants)* _ => None }),
+                 };
+                 match kind {
+                    I8 => get_char_arms.push(arm),
+                    U8 => get_uchar_arms.push(arm),
+                    I16 => get_short_arms.push(arm),
+                    U16 => get_ushort_arms.push(arm),
+                    I32 => get_int_arms.push(arm),
+                    U32 => get_uint_arms.push(arm),
+                    F32 => get_float_arms.push(arm),
+                    F64 => get_double_arms.push(arm),
+                    I64 => get_long_arms.push(arm),
+                    U64 => get_ulong_arms.push(arm),
+                 }
              }
         }
     }
@@ -488,34 +978,187 @@ pub fn derive_ply_read(input: TokenStream) -> TokenStream {
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
     let expanded = quote! {
         impl #impl_generics #ply_rs::ply::PropertyAccess for #name #ty_generics #where_clause {
-            fn new() -> Self { Default::default() }
+            fn new() -> Self {
+                Self {
+                    #( #new_field_inits, )*
+                }
+            }
             fn set_property(&mut self, key: &str, property: #ply_rs::ply::Property) {
+                #(
+                    if <#flatten_tys as #ply_rs::ply::ReadSchema>::schema().iter().any(|(n, _)| n == key) {
+                        #ply_rs::ply::PropertyAccess::set_property(&mut self.#flatten_fields, key, property);
+                        return;
+                    }
+                )*
                 match key {
                     #( #set_arms ),*
                     _ => {},
                 }
             }
-            fn get_char(&self, key: &str) -> Option<i8> { match key { #( #get_char_arms )* _ => None } }
-            fn get_uchar(&self, key: &str) -> Option<u8> { match key { #( #get_uchar_arms )* _ => None } }
-            fn get_short(&self, key: &str) -> Option<i16> { match key { #( #get_short_arms )* _ => None } }
-            fn get_ushort(&self, key: &str) -> Option<u16> { match key { #( #get_ushort_arms )* _ => None } }
-            fn get_int(&self, key: &str) -> Option<i32> { match key { #( #get_int_arms )* _ => None } }
-            fn get_uint(&self, key: &str) -> Option<u32> { match key { #( #get_uint_arms )* _ => None } }
-            fn get_float(&self, key: &str) -> Option<f32> { match key { #( #get_float_arms )* _ => None } }
-            fn get_double(&self, key: &str) -> Option<f64> { match key { #( #get_double_arms )* _ => None } }
-            
-            fn get_list_char(&self, key: &str) -> Option<&[i8]> { match key { #( #get_list_char_arms )* _ => None } }
-            fn get_list_uchar(&self, key: &str) -> Option<&[u8]> { match key { #( #get_list_uchar_arms )* _ => None } }
-            fn get_list_short(&self, key: &str) -> Option<&[i16]> { match key { #( #get_list_short_arms )* _ => None } }
-            fn get_list_ushort(&self, key: &str) -> Option<&[u16]> { match key { #( #get_list_ushort_arms )* _ => None } }
-            fn get_list_int(&self, key: &str) -> Option<&[i32]> { match key { #( #get_list_int_arms )* _ => None } }
-            fn get_list_uint(&self, key: &str) -> Option<&[u32]> { match key { #( #get_list_uint_arms )* _ => None } }
-            fn get_list_float(&self, key: &str) -> Option<&[f32]> { match key { #( #get_list_float_arms )* _ => None } }
-            fn get_list_double(&self, key: &str) -> Option<&[f64]> { match key { #( #get_list_double_arms )* _ => None } }
+            fn get_char(&self, key: &str) -> Option<i8> { if let Some(v) = match key { #( #get_char_arms )* _ => None } { return Some(v); } #( if let Some(v) = #ply_rs::ply::PropertyAccess::get_char(&self.#flatten_fields, key) { return Some(v); } )* None }
+            fn get_uchar(&self, key: &str) -> Option<u8> { if let Some(v) = match key { #( #get_uchar_arms )* _ => None } { return Some(v); } #( if let Some(v) = #ply_rs::ply::PropertyAccess::get_uchar(&self.#flatten_fields, key) { return Some(v); } )* None }
+            fn get_short(&self, key: &str) -> Option<i16> { if let Some(v) = match key { #( #get_short_arms )* _ => None } { return Some(v); } #( if let Some(v) = #ply_rs::ply::PropertyAccess::get_short(&self.#flatten_fields, key) { return Some(v); } )* None }
+            fn get_ushort(&self, key: &str) -> Option<u16> { if let Some(v) = match key { #( #get_ushort_arms )* _ => None } { return Some(v); } #( if let Some(v) = #ply_rs::ply::PropertyAccess::get_ushort(&self.#flatten_fields, key) { return Some(v); } )* None }
+            fn get_int(&self, key: &str) -> Option<i32> { if let Some(v) = match key { #( #get_int_arms )* _ => None } { return Some(v); } #( if let Some(v) = #ply_rs::ply::PropertyAccess::get_int(&self.#flatten_fields, key) { return Some(v); } )* None }
+            fn get_uint(&self, key: &str) -> Option<u32> { if let Some(v) = match key { #( #get_uint_arms )* _ => None } { return Some(v); } #( if let Some(v) = #ply_rs::ply::PropertyAccess::get_uint(&self.#flatten_fields, key) { return Some(v); } )* None }
+            fn get_float(&self, key: &str) -> Option<f32> { if let Some(v) = match key { #( #get_float_arms )* _ => None } { return Some(v); } #( if let Some(v) = #ply_rs::ply::PropertyAccess::get_float(&self.#flatten_fields, key) { return Some(v); } )* None }
+            fn get_double(&self, key: &str) -> Option<f64> { if let Some(v) = match key { #( #get_double_arms )* _ => None } { return Some(v); } #( if let Some(v) = #ply_rs::ply::PropertyAccess::get_double(&self.#flatten_fields, key) { return Some(v); } )* None }
+            fn get_long(&self, key: &str) -> Option<i64> { if let Some(v) = match key { #( #get_long_arms )* _ => None } { return Some(v); } #( if let Some(v) = #ply_rs::ply::PropertyAccess::get_long(&self.#flatten_fields, key) { return Some(v); } )* None }
+            fn get_ulong(&self, key: &str) -> Option<u64> { if let Some(v) = match key { #( #get_ulong_arms )* _ => None } { return Some(v); } #( if let Some(v) = #ply_rs::ply::PropertyAccess::get_ulong(&self.#flatten_fields, key) { return Some(v); } )* None }
+
+            fn get_list_char(&self, key: &str) -> Option<&[i8]> { if let Some(v) = match key { #( #get_list_char_arms )* _ => None } { return Some(v); } #( if let Some(v) = #ply_rs::ply::PropertyAccess::get_list_char(&self.#flatten_fields, key) { return Some(v); } )* None }
+            fn get_list_uchar(&self, key: &str) -> Option<&[u8]> { if let Some(v) = match key { #( #get_list_uchar_arms )* _ => None } { return Some(v); } #( if let Some(v) = #ply_rs::ply::PropertyAccess::get_list_uchar(&self.#flatten_fields, key) { return Some(v); } )* None }
+            fn get_list_short(&self, key: &str) -> Option<&[i16]> { if let Some(v) = match key { #( #get_list_short_arms )* _ => None } { return Some(v); } #( if let Some(v) = #ply_rs::ply::PropertyAccess::get_list_short(&self.#flatten_fields, key) { return Some(v); } )* None }
+            fn get_list_ushort(&self, key: &str) -> Option<&[u16]> { if let Some(v) = match key { #( #get_list_ushort_arms )* _ => None } { return Some(v); } #( if let Some(v) = #ply_rs::ply::PropertyAccess::get_list_ushort(&self.#flatten_fields, key) { return Some(v); } )* None }
+            fn get_list_int(&self, key: &str) -> Option<&[i32]> { if let Some(v) = match key { #( #get_list_int_arms )* _ => None } { return Some(v); } #( if let Some(v) = #ply_rs::ply::PropertyAccess::get_list_int(&self.#flatten_fields, key) { return Some(v); } )* None }
+            fn get_list_uint(&self, key: &str) -> Option<&[u32]> { if let Some(v) = match key { #( #get_list_uint_arms )* _ => None } { return Some(v); } #( if let Some(v) = #ply_rs::ply::PropertyAccess::get_list_uint(&self.#flatten_fields, key) { return Some(v); } )* None }
+            fn get_list_float(&self, key: &str) -> Option<&[f32]> { if let Some(v) = match key { #( #get_list_float_arms )* _ => None } { return Some(v); } #( if let Some(v) = #ply_rs::ply::PropertyAccess::get_list_float(&self.#flatten_fields, key) { return Some(v); } )* None }
+            fn get_list_double(&self, key: &str) -> Option<&[f64]> { if let Some(v) = match key { #( #get_list_double_arms )* _ => None } { return Some(v); } #( if let Some(v) = #ply_rs::ply::PropertyAccess::get_list_double(&self.#flatten_fields, key) { return Some(v); } )* None }
+            fn get_list_long(&self, key: &str) -> Option<&[i64]> { if let Some(v) = match key { #( #get_list_long_arms )* _ => None } { return Some(v); } #( if let Some(v) = #ply_rs::ply::PropertyAccess::get_list_long(&self.#flatten_fields, key) { return Some(v); } )* None }
+            fn get_list_ulong(&self, key: &str) -> Option<&[u64]> { if let Some(v) = match key { #( #get_list_ulong_arms )* _ => None } { return Some(v); } #( if let Some(v) = #ply_rs::ply::PropertyAccess::get_list_ulong(&self.#flatten_fields, key) { return Some(v); } )* None }
         }
         impl #impl_generics #ply_rs::ply::ReadSchema for #name #ty_generics #where_clause {
             fn schema() -> Vec<(String, #ply_rs::ply::Requiredness)> {
-                vec![ #( #schema_entries ),* ]
+                let mut schema = vec![ #( #schema_entries ),* ];
+                #( schema.extend(<#flatten_tys as #ply_rs::ply::ReadSchema>::schema()); )*
+                #[cfg(debug_assertions)]
+                {
+                    let mut seen = ::std::collections::HashSet::new();
+                    for (name, _) in &schema {
+                        assert!(seen.insert(name.clone()), "duplicate ply property name \"{}\" - check for a #[ply(flatten)] field overlapping a sibling property", name);
+                    }
+                }
+                schema
+            }
+        }
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Streams a PLY file element-by-element, decoding every element whose name is in
+            /// `element_names` (e.g. `&["vertex", "vert"]` to follow a synonym) as `Self` and
+            /// invoking `on_element(name, &value)` once per record - other elements are parsed
+            /// and discarded without ever being collected into a `Vec`. Unlike the
+            /// `read_ply_streaming` method `#[derive(FromPly)]` generates, this works for a single
+            /// `PlyRead` struct with no surrounding container, so arbitrarily large files can be
+            /// folded/accumulated over without materializing anything beyond one row at a time.
+            pub fn read_streaming<_T_READER: std::io::Read>(
+                reader: &mut _T_READER,
+                element_names: &[&str],
+                mut on_element: impl FnMut(&str, &Self) -> #ply_rs::PlyResult<()>,
+            ) -> #ply_rs::PlyResult<()> {
+                struct IgnoredElement;
+                impl #ply_rs::ply::PropertyAccess for IgnoredElement {
+                    fn new() -> Self { IgnoredElement }
+                }
+
+                let mut reader = std::io::BufReader::new(reader);
+                let parser = #ply_rs::parser::Parser::<#ply_rs::ply::DefaultElement>::new();
+                let header = parser.read_header(&mut reader)?;
+
+                for (name, element_def) in &header.elements {
+                    if element_names.contains(&name.as_str()) {
+                        let p = #ply_rs::parser::Parser::<Self>::new();
+                        for item in p.element_iter(&mut reader, element_def, &header) {
+                            on_element(name, &item?)?;
+                        }
+                    } else {
+                        let p = #ply_rs::parser::Parser::<IgnoredElement>::new();
+                        for item in p.element_iter(&mut reader, element_def, &header) {
+                            item?;
+                        }
+                    }
+                }
+
+                Ok(())
+            }
+
+            /// Strict counterpart of [`FromPly::read_ply`](#ply_rs::parser::FromPly): before
+            /// decoding `element_name`'s payload, checks the header against
+            /// [`ReadSchema::schema`](#ply_rs::ply::ReadSchema) and fails loudly instead of
+            /// silently defaulting a field, so e.g. a file that calls its color channels
+            /// `red`/`green`/`blue` when this struct expects `r`/`g`/`b` is rejected up front
+            /// instead of producing an all-default-valued struct, and unlike `read_ply`'s lenient
+            /// `as`-cast conversion, a property whose on-disk scalar type doesn't match the
+            /// field it would decode into (e.g. a `double` column read into an `i8` field) is
+            /// rejected rather than silently truncated. Checks, in order: the element is
+            /// declared, every required property is present, every declared property maps to a
+            /// known field, every declared property agrees with the field on scalar vs. list
+            /// shape, and every declared property's scalar type matches the field's.
+            pub fn read_strict<_T_READER: std::io::Read>(
+                reader: &mut _T_READER,
+                element_name: &str,
+            ) -> #ply_rs::PlyResult<Vec<Self>> {
+                let mut reader = std::io::BufReader::new(reader);
+                let parser = #ply_rs::parser::Parser::<#ply_rs::ply::DefaultElement>::new();
+                let header = parser.read_header(&mut reader)?;
+
+                let element_def = header.elements.get(element_name).ok_or_else(|| {
+                    #ply_rs::PlyError::Schema(#ply_rs::SchemaError {
+                        element: element_name.to_string(),
+                        property: String::new(),
+                        expected: "a declared element".to_string(),
+                        found: "no such element in the header".to_string(),
+                    })
+                })?;
+
+                let schema = <Self as #ply_rs::ply::ReadSchema>::schema();
+                let known: std::collections::HashSet<&str> = schema.iter().map(|(name, _)| name.as_str()).collect();
+
+                for (name, requiredness) in &schema {
+                    if matches!(requiredness, #ply_rs::ply::Requiredness::Required) && !element_def.properties.contains_key(name) {
+                        return Err(#ply_rs::PlyError::Schema(#ply_rs::SchemaError {
+                            element: element_name.to_string(),
+                            property: name.clone(),
+                            expected: "present".to_string(),
+                            found: "missing".to_string(),
+                        }));
+                    }
+                }
+
+                for prop_name in element_def.properties.keys() {
+                    if !known.contains(prop_name.as_str()) {
+                        return Err(#ply_rs::PlyError::Schema(#ply_rs::SchemaError {
+                            element: element_name.to_string(),
+                            property: prop_name.clone(),
+                            expected: "a struct field mapped to this property".to_string(),
+                            found: "no matching field".to_string(),
+                        }));
+                    }
+                }
+
+                let expected_list: &[(&str, bool)] = &[ #(#strict_list_entries),* ];
+                for (name, expect_list) in expected_list {
+                    if let Some(prop_def) = element_def.properties.get(*name) {
+                        if prop_def.data_type.is_list() != *expect_list {
+                            return Err(#ply_rs::PlyError::Schema(#ply_rs::SchemaError {
+                                element: element_name.to_string(),
+                                property: (*name).to_string(),
+                                expected: if *expect_list { "a list property".to_string() } else { "a scalar property".to_string() },
+                                found: if prop_def.data_type.is_list() { "a list property".to_string() } else { "a scalar property".to_string() },
+                            }));
+                        }
+                    }
+                }
+
+                // Unlike `read_ply`'s lenient `as`-cast conversion, `read_strict` also rejects a
+                // declared scalar type that doesn't match the field it would decode into (e.g. a
+                // `double` property read into an `i8` field), instead of silently truncating it.
+                let expected_types: &[(&str, Option<&str>)] = &[ #(#strict_type_entries),* ];
+                for (name, expected) in expected_types {
+                    let Some(expected) = expected else { continue };
+                    if let Some(prop_def) = element_def.properties.get(*name) {
+                        let found = prop_def.data_type.element_type().rust_type_name();
+                        if found != *expected {
+                            return Err(#ply_rs::PlyError::Schema(#ply_rs::SchemaError {
+                                element: element_name.to_string(),
+                                property: (*name).to_string(),
+                                expected: (*expected).to_string(),
+                                found: found.to_string(),
+                            }));
+                        }
+                    }
+                }
+
+                let p = #ply_rs::parser::Parser::<Self>::new();
+                p.read_payload_for_element(&mut reader, element_def, &header)
             }
         }
     };
@@ -545,7 +1188,13 @@ pub fn derive_ply_write(input: TokenStream) -> TokenStream {
 
     let mut type_schema_entries = Vec::new();
     let mut seen_names = std::collections::HashSet::new();
+    let mut seen_write_names = std::collections::HashSet::new();
     let ply_rs = get_crate_name();
+    let generic_params: Vec<syn::Ident> = input.generics.type_params().map(|tp| tp.ident.clone()).collect();
+    // `#[ply(flatten)]` fields contribute their own `property_type_schema()` at runtime
+    // rather than a literal entry, for the same reason as the `PlyRead` side - see the
+    // `flatten_tys` comment in `derive_ply_read`.
+    let mut flatten_write_tys: Vec<&Type> = Vec::new();
 
     for field in fields {
         let field_type = &field.ty;
@@ -553,11 +1202,56 @@ pub fn derive_ply_write(input: TokenStream) -> TokenStream {
             Ok(attr) => attr,
             Err(err) => return TokenStream::from(err.to_compile_error()),
         };
+        if ply_attr.skip {
+            continue;
+        }
+        if ply_attr.flatten {
+            if ply_attr.rename.is_some() || ply_attr.is_enum || ply_attr.list
+                || ply_attr.count_type.is_some() || ply_attr.explicit_type.is_some()
+            {
+                return TokenStream::from(syn::Error::new_spanned(field, "ply(flatten) can't be combined with type/list/count/enum/rename").to_compile_error());
+            }
+            flatten_write_tys.push(field_type);
+            continue;
+        }
         let ply_names = match validate_and_dedupe_ply_names(field, ply_attr.names, &mut seen_names) {
             Ok(names) => names,
             Err(err) => return TokenStream::from(err.to_compile_error()),
         };
-        let ply_name = ply_names[0].clone();
+
+        if let Some((_, elem_ty, len)) = array_like(field_type) {
+            if ply_attr.rename.is_some() {
+                return TokenStream::from(syn::Error::new_spanned(field, "ply(rename = \"...\") is not supported on array/tuple fields").to_compile_error());
+            }
+            if ply_attr.is_enum {
+                return TokenStream::from(syn::Error::new_spanned(field, "ply(enum) is not supported on array/tuple fields").to_compile_error());
+            }
+            if ply_names.len() != len {
+                return TokenStream::from(syn::Error::new_spanned(field, format!(
+                    "ply(name = \"...\") lists {} name(s), but the array/tuple field has {} element(s) - they must match",
+                    ply_names.len(), len
+                )).to_compile_error());
+            }
+            let prop_type_token = match get_property_type_tokens(elem_ty, None, ply_attr.explicit_type.as_deref(), false, Some(field), &generic_params) {
+                Ok(tokens) => tokens,
+                Err(err) => return TokenStream::from(err.to_compile_error()),
+            };
+            for ply_name in &ply_names {
+                if !seen_write_names.insert(ply_name.clone()) {
+                    return TokenStream::from(syn::Error::new_spanned(field, format!("duplicate ply write name: {ply_name}")).to_compile_error());
+                }
+                let ply_name_lit = syn::LitStr::new(ply_name, proc_macro2::Span::call_site());
+                type_schema_entries.push(quote! {
+                    (#ply_name_lit.to_string(), #prop_type_token)
+                });
+            }
+            continue;
+        }
+
+        let ply_name = ply_attr.rename.clone().unwrap_or_else(|| ply_names[0].clone());
+        if !seen_write_names.insert(ply_name.clone()) {
+            return TokenStream::from(syn::Error::new_spanned(field, format!("duplicate ply write name: {ply_name}")).to_compile_error());
+        }
 
         let ply_name_lit = syn::LitStr::new(&ply_name, proc_macro2::Span::call_site());
 
@@ -565,11 +1259,24 @@ pub fn derive_ply_write(input: TokenStream) -> TokenStream {
              return TokenStream::from(syn::Error::new_spanned(field, "ply parameter 'count' is only valid for Vec<T> fields").to_compile_error());
         }
 
+        if ply_attr.list && is_vec(field_type).is_none() {
+             return TokenStream::from(syn::Error::new_spanned(field, "ply(list) is only valid for Vec<T> fields").to_compile_error());
+        }
+
         if is_option(field_type).is_some() {
              return TokenStream::from(syn::Error::new_spanned(field_type, "optional properties are only supported by the reader. PlyWrite does not support Option<T>.").to_compile_error());
         }
 
-        let prop_type_token = match get_property_type_tokens(field_type, ply_attr.count_type.as_deref(), ply_attr.explicit_type.as_deref(), Some(field)) {
+        if ply_attr.is_enum {
+            if ply_attr.explicit_type.is_none() {
+                return TokenStream::from(syn::Error::new_spanned(field, "ply(enum) also needs ply(type = \"...\") to name the on-disk scalar the discriminant is encoded as").to_compile_error());
+            }
+            if is_vec(field_type).is_some() {
+                return TokenStream::from(syn::Error::new_spanned(field, "ply(enum) is only supported on plain scalar fields, not Vec<T>").to_compile_error());
+            }
+        }
+
+        let prop_type_token = match get_property_type_tokens(field_type, ply_attr.count_type.as_deref(), ply_attr.explicit_type.as_deref(), ply_attr.is_enum, Some(field), &generic_params) {
             Ok(tokens) => tokens,
             Err(err) => return TokenStream::from(err.to_compile_error()),
         };
@@ -582,7 +1289,16 @@ pub fn derive_ply_write(input: TokenStream) -> TokenStream {
     let expanded = quote! {
         impl #impl_generics #ply_rs::ply::WriteSchema for #name #ty_generics #where_clause {
             fn property_type_schema() -> Vec<(String, #ply_rs::ply::PropertyType)> {
-                vec![ #( #type_schema_entries ),* ]
+                let mut schema = vec![ #( #type_schema_entries ),* ];
+                #( schema.extend(<#flatten_write_tys as #ply_rs::ply::WriteSchema>::property_type_schema()); )*
+                #[cfg(debug_assertions)]
+                {
+                    let mut seen = ::std::collections::HashSet::new();
+                    for (name, _) in &schema {
+                        assert!(seen.insert(name.clone()), "duplicate ply property name \"{}\" - check for a #[ply(flatten)] field overlapping a sibling property", name);
+                    }
+                }
+                schema
             }
         }
     };
@@ -593,7 +1309,27 @@ pub fn derive_ply_write(input: TokenStream) -> TokenStream {
 /// Procedural macro to derive the `FromPly` trait.
 ///
 /// This macro allows a struct to be read directly from a PLY file by mapping
-/// element names to `Vec<T>` fields.
+/// element names to `Vec<T>` fields - `T` is always a single PLY element, whether its own
+/// `#[derive(PlyRead)]` exposes it as several flat scalar properties (e.g. a
+/// `position: [f32; 3]` field, see `#[ply(name = "...")]` on arrays/tuples above) or one
+/// property per field. It also derives `FromPlyWithMask`, whose
+/// `read_ply_with_mask` reports, per field, whether the element was present under its
+/// primary name, present under a `#[ply(name = "a, b")]` synonym, or (for a field marked
+/// `#[ply(optional)]`) missing and left at its `Default` value. An element missing for a
+/// field that isn't marked `#[ply(optional)]` is a hard error.
+///
+/// Each `Vec<T>` field's `T` must also implement [`ply::ReadSchema`](crate::ply::ReadSchema) -
+/// derive it directly, or get it for free from `#[derive(PlyRead)]` - so that once an element
+/// name matches, [`ReadSchema::validate_required`](crate::ply::ReadSchema::validate_required)
+/// can check the header against `T::schema()` before any property is decoded. This turns a
+/// file that omits a required property into a [`PlyError::Schema`](crate::PlyError::Schema)
+/// naming the element and property, instead of silently decoding every record of that element
+/// with the field left at its `Default`/`#[ply(default = ...)]` value.
+///
+/// A field of type [`ply::PlyHeaderMeta`](crate::ply::PlyHeaderMeta) annotated `#[ply(header)]`
+/// is populated with a clone of the parsed header instead of mapping to an element; pair it
+/// with the same attribute on a `#[derive(ToPly)]` struct to round-trip comments, `obj_info`,
+/// element order, and original property types byte-faithfully.
 #[proc_macro_derive(FromPly, attributes(ply))]
 pub fn derive_from_ply(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -608,19 +1344,35 @@ pub fn derive_from_ply(input: TokenStream) -> TokenStream {
     };
 
     let mut field_names = Vec::new();
+    let mut field_name_lits = Vec::new();
     let mut inner_tys = Vec::new();
     let mut ply_names_pats = Vec::new();
+    let mut ply_name_lit_lists = Vec::new();
+    let mut primary_name_lits = Vec::new();
+    let mut optional_flags = Vec::new();
+    let mut triangulate_flags = Vec::new();
+    let mut mask_idents = Vec::new();
+    let mut on_field_idents = Vec::new();
+    let mut seen_idents = Vec::new();
     let mut seen_names = std::collections::HashSet::new();
+    // `#[ply(header)]` field(s): populated from the parsed `Header` once, outside the regular
+    // per-element `Vec<T>` machinery above - see `ply::PlyHeaderMeta`.
+    let mut header_field_idents: Vec<&syn::Ident> = Vec::new();
 
     for field in fields {
         let field_name = field.ident.as_ref().unwrap();
         let field_type = &field.ty;
-        let ply_names = match parse_ply_name(field) {
-            Ok(names) => names,
+        let ply_attr = match parse_ply_attr(field) {
+            Ok(attr) => attr,
             Err(err) => return TokenStream::from(err.to_compile_error()),
         };
 
-        let ply_names = match validate_and_dedupe_ply_names(field, ply_names, &mut seen_names) {
+        if ply_attr.header {
+            header_field_idents.push(field_name);
+            continue;
+        }
+
+        let ply_names = match validate_and_dedupe_ply_names(field, ply_attr.names, &mut seen_names) {
             Ok(names) => names,
             Err(err) => return TokenStream::from(err.to_compile_error()),
         };
@@ -629,19 +1381,57 @@ pub fn derive_from_ply(input: TokenStream) -> TokenStream {
             Some(ty) => ty,
             None => return TokenStream::from(syn::Error::new_spanned(field_type, "FromPly currently only supports Vec<T> fields").to_compile_error()),
         };
-        
+
         let ply_name_lits: Vec<_> = ply_names.iter().map(|n| syn::LitStr::new(n, proc_macro2::Span::call_site())).collect();
         ply_names_pats.push(quote! { #( #ply_name_lits )|* });
-
+        ply_name_lit_lists.push(quote! { [ #( #ply_name_lits ),* ] });
+        primary_name_lits.push(ply_name_lits[0].clone());
+
+        field_name_lits.push(syn::LitStr::new(&field_name.to_string(), proc_macro2::Span::call_site()));
+        mask_idents.push(syn::Ident::new(&format!("__mask_{}", field_name), proc_macro2::Span::call_site()));
+        on_field_idents.push(syn::Ident::new(&format!("on_{}", field_name), proc_macro2::Span::call_site()));
+        seen_idents.push(syn::Ident::new(&format!("__seen_{}", field_name), proc_macro2::Span::call_site()));
+        optional_flags.push(ply_attr.optional);
+        triangulate_flags.push(ply_attr.triangulate);
         field_names.push(field_name);
         inner_tys.push(inner_ty);
     }
 
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
     let ply_rs = get_crate_name();
+
+    // `#[ply(triangulate)]` fan-triangulates each record of a flagged field right after
+    // its element group is read, so downstream code never has to special-case n-gons.
+    let triangulate_stmts: Vec<_> = field_names.iter().zip(inner_tys.iter())
+        .zip(triangulate_flags.iter())
+        .filter(|(_, &triangulate)| triangulate)
+        .map(|((field_name, inner_ty), _)| {
+            quote! {
+                #field_name = #field_name.into_iter().flat_map(|face| {
+                    let indices = #ply_rs::ply::Polygon::polygon_indices(&face).to_vec();
+                    let mut triangles = Vec::new();
+                    if indices.len() >= 3 {
+                        for i in 1..indices.len() - 1 {
+                            triangles.push(<#inner_ty as #ply_rs::ply::Polygon>::from_triangle(
+                                indices[0], indices[i], indices[i + 1],
+                            ));
+                        }
+                    }
+                    triangles
+                }).collect();
+            }
+        })
+        .collect();
+
     let expanded = quote! {
         impl #impl_generics #ply_rs::parser::FromPly for #name #ty_generics #where_clause {
             fn read_ply<_T_READER: std::io::Read>(reader: &mut _T_READER) -> #ply_rs::PlyResult<Self> {
+                let (value, _mask) = <Self as #ply_rs::parser::FromPlyWithMask>::read_ply_with_mask(reader)?;
+                Ok(value)
+            }
+        }
+        impl #impl_generics #ply_rs::parser::FromPlyWithMask for #name #ty_generics #where_clause {
+            fn read_ply_with_mask<_T_READER: std::io::Read>(reader: &mut _T_READER) -> #ply_rs::PlyResult<(Self, #ply_rs::parser::ReadMask)> {
                 struct IgnoredElement;
                 impl #ply_rs::ply::PropertyAccess for IgnoredElement {
                     fn new() -> Self { IgnoredElement }
@@ -651,15 +1441,27 @@ pub fn derive_from_ply(input: TokenStream) -> TokenStream {
                 // We need a parser to read the header. Any element type will do.
                 let parser = #ply_rs::parser::Parser::<#ply_rs::ply::DefaultElement>::new();
                 let header = parser.read_header(&mut reader)?;
+                #(
+                    let #header_field_idents: #ply_rs::ply::PlyHeaderMeta = header.clone().into();
+                )*
 
                 #(
                     let mut #field_names = Vec::new();
                 )*
+                #(
+                    let mut #mask_idents: Option<#ply_rs::parser::ElementPresence> = None;
+                )*
 
                 for (name, element_def) in &header.elements {
                     match name.as_str() {
                         #(
                             #ply_names_pats => {
+                                #mask_idents = Some(if name.as_str() == #primary_name_lits {
+                                    #ply_rs::parser::ElementPresence::Present
+                                } else {
+                                    #ply_rs::parser::ElementPresence::Synonym(name.clone())
+                                });
+                                <#inner_tys as #ply_rs::ply::ReadSchema>::validate_required(name, element_def)?;
                                 let p = #ply_rs::parser::Parser::<#inner_tys>::new();
                                 #field_names = p.read_payload_for_element(&mut reader, element_def, &header)?;
                             }
@@ -672,9 +1474,95 @@ pub fn derive_from_ply(input: TokenStream) -> TokenStream {
                     }
                 }
 
-                Ok(#name {
+                #(
+                    if #mask_idents.is_none() {
+                        if #optional_flags {
+                            #mask_idents = Some(#ply_rs::parser::ElementPresence::Missing);
+                        } else {
+                            return Err(#ply_rs::PlyError::Parse(format!(
+                                "Missing required element for field '{}': looked for {:?}, found none. \
+                                 Mark it `#[ply(optional)]` if the element may legitimately be absent.",
+                                #field_name_lits, #ply_name_lit_lists
+                            )));
+                        }
+                    }
+                )*
+
+                let mut mask = #ply_rs::parser::ReadMask::new();
+                #(
+                    mask.insert(#field_name_lits, #mask_idents.unwrap());
+                )*
+
+                #( #triangulate_stmts )*
+
+                Ok((#name {
                     #( #field_names, )*
-                })
+                    #( #header_field_idents, )*
+                }, mask))
+            }
+        }
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Streams the file element-by-element instead of collecting each group into a
+            /// `Vec` field like [`FromPly::read_ply`] does: calls `on_<field>` once per decoded
+            /// record of that field's element group and never holds more than one record (plus
+            /// the reader's buffer) in memory, so a caller can accumulate a bounding box,
+            /// downsample, or otherwise reduce a point cloud too large to collect wholesale.
+            ///
+            /// Each callback borrows its record rather than taking ownership, and returns a
+            /// [`PlyResult`](#ply_rs::PlyResult) so a sink that can fail (e.g. writing to disk)
+            /// or a caller that wants to abort early can stop the read; the first `Err` returned
+            /// by a callback is propagated out of `read_ply_streaming` immediately, leaving the
+            /// rest of the file unread.
+            ///
+            /// Presence follows [`FromPlyWithMask::read_ply_with_mask`]: a `#[ply(optional)]`
+            /// field whose element is absent simply has its callback never invoked; a required
+            /// field's missing element is still an error.
+            pub fn read_ply_streaming<_T_READER: std::io::Read>(
+                reader: &mut _T_READER,
+                #( mut #on_field_idents: impl FnMut(&#inner_tys) -> #ply_rs::PlyResult<()>, )*
+            ) -> #ply_rs::PlyResult<()> {
+                struct IgnoredElement;
+                impl #ply_rs::ply::PropertyAccess for IgnoredElement {
+                    fn new() -> Self { IgnoredElement }
+                }
+
+                let mut reader = std::io::BufReader::new(reader);
+                let parser = #ply_rs::parser::Parser::<#ply_rs::ply::DefaultElement>::new();
+                let header = parser.read_header(&mut reader)?;
+
+                #( let mut #seen_idents = false; )*
+
+                for (name, element_def) in &header.elements {
+                    match name.as_str() {
+                        #(
+                            #ply_names_pats => {
+                                #seen_idents = true;
+                                let p = #ply_rs::parser::Parser::<#inner_tys>::new();
+                                for item in p.element_iter(&mut reader, element_def, &header) {
+                                    #on_field_idents(&item?)?;
+                                }
+                            }
+                        ),*
+                        _ => {
+                            let p = #ply_rs::parser::Parser::<IgnoredElement>::new();
+                            for item in p.element_iter(&mut reader, element_def, &header) {
+                                item?;
+                            }
+                        }
+                    }
+                }
+
+                #(
+                    if !#seen_idents && !#optional_flags {
+                        return Err(#ply_rs::PlyError::Parse(format!(
+                            "Missing required element for field '{}': looked for {:?}, found none. \
+                             Mark it `#[ply(optional)]` if the element may legitimately be absent.",
+                            #field_name_lits, #ply_name_lit_lists
+                        )));
+                    }
+                )*
+
+                Ok(())
             }
         }
     };
@@ -697,7 +1585,7 @@ fn is_option(ty: &Type) -> Option<&Type> {
 /// Generates the conversion logic from a `Property` to a specific Rust type.
 ///
 /// Handles both scalar types and `Vec<T>` for list properties.
-fn generate_conversion(ty: &Type) -> Result<proc_macro2::TokenStream, syn::Error> {
+fn generate_conversion(ty: &Type, generic_params: &[syn::Ident]) -> Result<proc_macro2::TokenStream, syn::Error> {
     let ply_rs = get_crate_name();
 
     // Recognize scalars and Vec<scalar>
@@ -712,6 +1600,25 @@ fn generate_conversion(ty: &Type) -> Result<proc_macro2::TokenStream, syn::Error
                 }
             });
         }
+
+        // `Vec<S>` where `S` is one of the struct's own generic parameters: convert each
+        // raw list element through `PlyScalar::from_property` instead of an `as`-cast, the
+        // list counterpart of the bare-generic-scalar case below.
+        if generic_scalar_param(inner, generic_params).is_some() {
+            return Ok(quote! {
+                match property {
+                    #ply_rs::ply::Property::ListChar(v) => v.into_iter().map(|x| #ply_rs::ply::PlyScalar::from_property(&#ply_rs::ply::Property::Char(x))).collect::<Option<Vec<_>>>(),
+                    #ply_rs::ply::Property::ListUChar(v) => v.into_iter().map(|x| #ply_rs::ply::PlyScalar::from_property(&#ply_rs::ply::Property::UChar(x))).collect::<Option<Vec<_>>>(),
+                    #ply_rs::ply::Property::ListShort(v) => v.into_iter().map(|x| #ply_rs::ply::PlyScalar::from_property(&#ply_rs::ply::Property::Short(x))).collect::<Option<Vec<_>>>(),
+                    #ply_rs::ply::Property::ListUShort(v) => v.into_iter().map(|x| #ply_rs::ply::PlyScalar::from_property(&#ply_rs::ply::Property::UShort(x))).collect::<Option<Vec<_>>>(),
+                    #ply_rs::ply::Property::ListInt(v) => v.into_iter().map(|x| #ply_rs::ply::PlyScalar::from_property(&#ply_rs::ply::Property::Int(x))).collect::<Option<Vec<_>>>(),
+                    #ply_rs::ply::Property::ListUInt(v) => v.into_iter().map(|x| #ply_rs::ply::PlyScalar::from_property(&#ply_rs::ply::Property::UInt(x))).collect::<Option<Vec<_>>>(),
+                    #ply_rs::ply::Property::ListFloat(v) => v.into_iter().map(|x| #ply_rs::ply::PlyScalar::from_property(&#ply_rs::ply::Property::Float(x))).collect::<Option<Vec<_>>>(),
+                    #ply_rs::ply::Property::ListDouble(v) => v.into_iter().map(|x| #ply_rs::ply::PlyScalar::from_property(&#ply_rs::ply::Property::Double(x))).collect::<Option<Vec<_>>>(),
+                    _ => None,
+                }
+            });
+        }
     }
 
     if let Some(s) = scalar_ident(ty) {
@@ -724,8 +1631,72 @@ fn generate_conversion(ty: &Type) -> Result<proc_macro2::TokenStream, syn::Error
         });
     }
 
+    // A bare generic type parameter (e.g. `S` in `struct Vertex<S: PlyScalar> { x: S }`)
+    // doesn't resolve to a concrete scalar until monomorphization, so dispatch through
+    // `PlyScalar::from_property` instead of the `as`-cast match arms above.
+    if generic_scalar_param(ty, generic_params).is_some() {
+        return Ok(quote! {
+            #ply_rs::ply::PlyScalar::from_property(&property)
+        });
+    }
+
     // Fallback: not recognized
-    Err(syn::Error::new_spanned(ty, "Unsupported field type for PlyAccess. Supported types: i8, u8, i16, u16, i32, u32, f32, f64, and Vec<T> of these."))
+    Err(syn::Error::new_spanned(ty, "Unsupported field type for PlyAccess. Supported types: i8, u8, i16, u16, i32, u32, i64, u64, f32, f64, and Vec<T> of these."))
+}
+
+/// Checks if a type is a fixed-size array `[T; N]` and returns its element type and length.
+fn is_array(ty: &Type) -> Option<(&Type, usize)> {
+    if let Type::Array(arr) = ty
+        && let syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(n), .. }) = &arr.len
+            && let Ok(len) = n.base10_parse::<usize>() {
+                return Some((&arr.elem, len));
+            }
+    None
+}
+
+/// Checks if a type is an N-tuple whose elements are all the same type, e.g. `(f32, f32, f32)`,
+/// and returns that common element type alongside N. Mixed-type tuples aren't PLY vector
+/// attributes (`x, y, z` share a scalar kind), so they're left to `generate_conversion` to
+/// reject the way any other unsupported field type is.
+fn is_homogeneous_tuple(ty: &Type) -> Option<(&Type, usize)> {
+    if let Type::Tuple(tup) = ty {
+        let len = tup.elems.len();
+        let first = tup.elems.first()?;
+        let first_tokens = quote! { #first }.to_string();
+        if len >= 2 && tup.elems.iter().all(|elem| quote! { #elem }.to_string() == first_tokens) {
+            return Some((first, len));
+        }
+    }
+    None
+}
+
+/// Fixed-size arrays and homogeneous tuples both map to N consecutive scalar PLY properties;
+/// this unifies their shape so `derive_ply_read`/`derive_ply_write`/`derive_read_schema` only
+/// need to branch on how to index into the field, not on whether it's `[T; N]` or `(T, T, T)`.
+enum ArrayLike {
+    Array,
+    Tuple,
+}
+
+impl ArrayLike {
+    /// Builds `self.field[i]` for arrays or `self.field.i` for tuples.
+    fn element_expr(&self, field_name: &proc_macro2::Ident, idx: usize) -> proc_macro2::TokenStream {
+        let index = syn::Index::from(idx);
+        match self {
+            ArrayLike::Array => quote! { self.#field_name[#index] },
+            ArrayLike::Tuple => quote! { self.#field_name.#index },
+        }
+    }
+}
+
+fn array_like(ty: &Type) -> Option<(ArrayLike, &Type, usize)> {
+    if let Some((elem, len)) = is_array(ty) {
+        return Some((ArrayLike::Array, elem, len));
+    }
+    if let Some((elem, len)) = is_homogeneous_tuple(ty) {
+        return Some((ArrayLike::Tuple, elem, len));
+    }
+    None
 }
 
 /// Checks if a type is `Vec<T>` and returns the inner type `T`.
@@ -741,7 +1712,7 @@ fn is_vec(ty: &Type) -> Option<&Type> {
 }
 
 #[derive(PartialEq, Eq, Debug)]
-enum ScalarKind { I8, U8, I16, U16, I32, U32, I64, U64, I128, U128, F32, F64 }
+enum ScalarKind { I8, U8, I16, U16, I32, U32, I64, U64, F32, F64 }
 
 impl std::fmt::Display for ScalarKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -756,8 +1727,6 @@ impl std::fmt::Display for ScalarKind {
             ScalarKind::F64 => write!(f, "f64"),
             ScalarKind::I64 => write!(f, "i64"),
             ScalarKind::U64 => write!(f, "u64"),
-            ScalarKind::I128 => write!(f, "i128"),
-            ScalarKind::U128 => write!(f, "u128"),
         }
     }
 }
@@ -774,16 +1743,25 @@ fn scalar_kind_from_str(s: &str) -> Option<ScalarKind> {
         "double" | "f64" => Some(ScalarKind::F64),
         "long" | "i64" => Some(ScalarKind::I64),
         "ulong" | "u64" => Some(ScalarKind::U64),
-        "i128" => Some(ScalarKind::I128),
-        "u128" => Some(ScalarKind::U128),
-
-
-
-
         _ => None,
     }
 }
 
+/// Returns the matching identifier if `ty` is literally one of the derive target's own
+/// generic type parameters (e.g. `S` in `struct Vertex<S> { x: S }`), so a field typed that
+/// way can resolve its PLY scalar type from `S: PlyScalar` at monomorphization instead of
+/// requiring `#[ply(type = "...")]`.
+fn generic_scalar_param<'a>(ty: &Type, generic_params: &'a [syn::Ident]) -> Option<&'a syn::Ident> {
+    if let Type::Path(tp) = ty
+        && tp.qself.is_none()
+        && tp.path.segments.len() == 1
+        && let Some(seg) = tp.path.segments.last()
+        && seg.arguments.is_empty() {
+            return generic_params.iter().find(|p| **p == seg.ident);
+        }
+    None
+}
+
 /// Identifies supported scalar types.
 fn scalar_ident(ty: &Type) -> Option<ScalarKind> {
     if let Type::Path(tp) = ty
@@ -802,10 +1780,6 @@ fn scalar_ident(ty: &Type) -> Option<ScalarKind> {
                 "f64" => Some(ScalarKind::F64),
                 "i64" | "isize" => Some(ScalarKind::I64),
                 "u64" | "usize" => Some(ScalarKind::U64),
-                "i128" => Some(ScalarKind::I128),
-                "u128" => Some(ScalarKind::U128),
-
-
                 _ => None,
             };
         }
@@ -826,8 +1800,6 @@ fn scalar_match_and_cast_tokens(kind: &ScalarKind, ply_rs: &proc_macro2::TokenSt
         F64 => quote!{ f64 },
         I64 => quote!{ i64 },
         U64 => quote!{ u64 },
-        I128 => quote!{ i128 },
-        U128 => quote!{ u128 },
     };
     scalar_match_and_cast_tokens_with_ty(&cast_ty, ply_rs)
 }
@@ -842,6 +1814,8 @@ fn scalar_match_and_cast_tokens_with_ty(cast_ty: &proc_macro2::TokenStream, ply_
         quote!{ #ply_rs::ply::Property::UInt(v) => Some(v as #cast_ty), },
         quote!{ #ply_rs::ply::Property::Float(v) => Some(v as #cast_ty), },
         quote!{ #ply_rs::ply::Property::Double(v) => Some(v as #cast_ty), },
+        quote!{ #ply_rs::ply::Property::Long(v) => Some(v as #cast_ty), },
+        quote!{ #ply_rs::ply::Property::ULong(v) => Some(v as #cast_ty), },
     ];
     (arms, cast_ty.clone())
 }
@@ -860,8 +1834,6 @@ fn list_match_and_cast_tokens(kind: &ScalarKind, ply_rs: &proc_macro2::TokenStre
         F64 => quote!{ f64 },
         I64 => quote!{ i64 },
         U64 => quote!{ u64 },
-        I128 => quote!{ i128 },
-        U128 => quote!{ u128 },
     };
     list_match_and_cast_tokens_with_ty(&cast_ty, ply_rs)
 }
@@ -876,14 +1848,77 @@ fn list_match_and_cast_tokens_with_ty(cast_ty: &proc_macro2::TokenStream, ply_rs
         quote!{ #ply_rs::ply::Property::ListUInt(v) => Some(v.into_iter().map(|x| x as #cast_ty).collect::<Vec<#cast_ty>>()), },
         quote!{ #ply_rs::ply::Property::ListFloat(v) => Some(v.into_iter().map(|x| x as #cast_ty).collect::<Vec<#cast_ty>>()), },
         quote!{ #ply_rs::ply::Property::ListDouble(v) => Some(v.into_iter().map(|x| x as #cast_ty).collect::<Vec<#cast_ty>>()), },
+        quote!{ #ply_rs::ply::Property::ListLong(v) => Some(v.into_iter().map(|x| x as #cast_ty).collect::<Vec<#cast_ty>>()), },
+        quote!{ #ply_rs::ply::Property::ListULong(v) => Some(v.into_iter().map(|x| x as #cast_ty).collect::<Vec<#cast_ty>>()), },
     ];
     (arms, cast_ty.clone())
 }
 
+/// Generates the `#[ply(coerce = "strict")]` conversion for a scalar field: unlike the
+/// blind `as` cast `scalar_match_and_cast_tokens` produces, this rejects (by panicking,
+/// the same trade-off `#[ply(assert = ...)]` makes) a value that doesn't survive the
+/// round trip to `kind`'s Rust type - an out-of-range or negative-into-unsigned integer,
+/// or a `double` that can't be represented exactly as `f32`.
+fn strict_scalar_conversion_tokens(kind: &ScalarKind, ply_rs: &proc_macro2::TokenStream, name_lit: &syn::LitStr) -> proc_macro2::TokenStream {
+    use ScalarKind::*;
+    let (_, cast_ty) = scalar_match_and_cast_tokens(kind, ply_rs);
+    let is_float_target = matches!(kind, F32 | F64);
+
+    let body = if is_float_target {
+        quote! {
+            let math = #ply_rs::ply::Property::as_f64(&property);
+            let narrowed = math as #cast_ty;
+            if !narrowed.is_nan() && (narrowed as f64) != math {
+                panic!(
+                    "ply(coerce = \"strict\") rejected precision-losing value {} for property '{}' (target type {})",
+                    math, #name_lit, stringify!(#cast_ty)
+                );
+            }
+            Some(narrowed)
+        }
+    } else {
+        quote! {
+            match #ply_rs::ply::Property::try_as::<#cast_ty>(&property) {
+                Some(v) => Some(v),
+                None => panic!(
+                    "ply(coerce = \"strict\") rejected out-of-range value {:?} for property '{}' (target type {})",
+                    property, #name_lit, stringify!(#cast_ty)
+                ),
+            }
+        }
+    };
+
+    quote! {
+        match property {
+            #ply_rs::ply::Property::Char(_)
+            | #ply_rs::ply::Property::UChar(_)
+            | #ply_rs::ply::Property::Short(_)
+            | #ply_rs::ply::Property::UShort(_)
+            | #ply_rs::ply::Property::Int(_)
+            | #ply_rs::ply::Property::UInt(_)
+            | #ply_rs::ply::Property::Float(_)
+            | #ply_rs::ply::Property::Double(_)
+            | #ply_rs::ply::Property::Long(_)
+            | #ply_rs::ply::Property::ULong(_) => { #body }
+            _ => None,
+        }
+    }
+}
+
 /// Procedural macro to derive the `ToPly` trait.
 ///
 /// This macro allows a struct to be written directly to a PLY file by mapping
-/// `Vec<T>` fields to PLY elements.
+/// `Vec<T>` fields to PLY elements, the mirror image of `#[derive(FromPly)]` - see its docs
+/// for how `T`'s own `#[derive(PlyWrite)]` decides whether it writes one property per field or
+/// expands an array/tuple field into several.
+///
+/// A field of type [`ply::PlyHeaderMeta`](crate::ply::PlyHeaderMeta) annotated `#[ply(header)]`
+/// - normally one populated by the matching `#[derive(FromPly)]` field of the same name - makes
+/// the generated `write_ply_with_encoding` reuse the original header's comments, `obj_info`,
+/// element declaration order, and each property's on-disk type instead of rebuilding a header
+/// from `WriteSchema`; only the element `count`s and the requested `encoding` are refreshed.
+/// When the field holds `None` (the value was built in code, not read from a file), it falls
+/// back to the usual freshly-built header.
 #[proc_macro_derive(ToPly, attributes(ply))]
 pub fn derive_to_ply(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -900,15 +1935,27 @@ pub fn derive_to_ply(input: TokenStream) -> TokenStream {
     let mut element_defs = Vec::new();
     let mut payload_writes = Vec::new();
     let mut seen_names = std::collections::HashSet::new();
+    let mut ply_name_lits = Vec::new();
+    let mut element_field_names = Vec::new();
+    let mut header_field: Option<&syn::Ident> = None;
 
     for field in fields {
-        let field_name = &field.ident;
+        let field_name = field.ident.as_ref().unwrap();
         let field_type = &field.ty;
-        let ply_names = match parse_ply_name(field) {
-            Ok(names) => names,
+        let ply_attr = match parse_ply_attr(field) {
+            Ok(attr) => attr,
             Err(err) => return TokenStream::from(err.to_compile_error()),
         };
-        let ply_names = match validate_and_dedupe_ply_names(field, ply_names, &mut seen_names) {
+
+        if ply_attr.header {
+            if header_field.is_some() {
+                return TokenStream::from(syn::Error::new_spanned(field, "at most one #[ply(header)] field is supported").to_compile_error());
+            }
+            header_field = Some(field_name);
+            continue;
+        }
+
+        let ply_names = match validate_and_dedupe_ply_names(field, ply_attr.names, &mut seen_names) {
             Ok(names) => names,
             Err(err) => return TokenStream::from(err.to_compile_error()),
         };
@@ -940,24 +1987,48 @@ pub fn derive_to_ply(input: TokenStream) -> TokenStream {
                 written += w.write_payload_of_element(writer, &self.#field_name, element_def, &header)?;
             }
         });
+
+        ply_name_lits.push(ply_name_lit);
+        element_field_names.push(field_name);
     }
 
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
     let ply_rs = get_crate_name();
+    let header_init = match header_field {
+        Some(header_field) => quote! {
+            let mut header = match &self.#header_field.0 {
+                Some(original) => {
+                    let mut h = original.clone();
+                    h.encoding = encoding;
+                    #( if let Some(el) = h.elements.get_mut(#ply_name_lits) { el.count = self.#element_field_names.len(); } )*
+                    h
+                }
+                None => {
+                    let mut h = #ply_rs::ply::Header::new();
+                    h.encoding = encoding;
+                    #( #element_defs )*
+                    h
+                }
+            };
+        },
+        None => quote! {
+            let mut header = #ply_rs::ply::Header::new();
+            header.encoding = encoding;
+            #( #element_defs )*
+        },
+    };
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
     let expanded = quote! {
         impl #impl_generics #ply_rs::writer::ToPly for #name #ty_generics #where_clause {
             fn write_ply<W: std::io::Write>(&self, writer: &mut W) -> #ply_rs::PlyResult<usize> {
                 self.write_ply_with_encoding(writer, #ply_rs::ply::Encoding::Ascii)
             }
             fn write_ply_with_encoding<W: std::io::Write>(&self, writer: &mut W, encoding: #ply_rs::ply::Encoding) -> #ply_rs::PlyResult<usize> {
-                let mut header = #ply_rs::ply::Header::new();
-                header.encoding = encoding;
-                
-                #( #element_defs )*
-                
+                #header_init
+
                 let w = #ply_rs::writer::Writer::<#ply_rs::ply::DefaultElement>::new();
                 let mut written = w.write_header(writer, &header)?;
-                
+
                 #( #payload_writes )*
                 
                 Ok(written)
@@ -968,9 +2039,168 @@ pub fn derive_to_ply(input: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
-fn get_property_type_tokens(ty: &Type, count_type: Option<&str>, explicit_type: Option<&str>, field_span: Option<&syn::Field>) -> Result<proc_macro2::TokenStream, syn::Error> {
+/// Parses `#[ply(repr = "...")]` off an enum's own attributes (not a field's).
+fn parse_ply_enum_repr(attrs: &[syn::Attribute]) -> Result<Option<String>, syn::Error> {
+    let mut repr = None;
+    for attr in attrs {
+        if attr.path().is_ident("ply") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("repr") {
+                    let value = meta.value()?;
+                    let s: syn::LitStr = value.parse()?;
+                    repr = Some(s.value());
+                    Ok(())
+                } else {
+                    Err(meta.error(format!("unknown ply attribute: {}", meta.path.to_token_stream().to_string())))
+                }
+            })?;
+        }
+    }
+    Ok(repr)
+}
+
+/// Parses `#[ply(value = N)]` off one enum variant's attributes.
+fn parse_ply_enum_value(attrs: &[syn::Attribute]) -> Result<Option<i64>, syn::Error> {
+    let mut value = None;
+    for attr in attrs {
+        if attr.path().is_ident("ply") {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("value") {
+                    let v = meta.value()?;
+                    let n: syn::LitInt = v.parse()?;
+                    value = Some(n.base10_parse::<i64>()?);
+                    Ok(())
+                } else {
+                    Err(meta.error(format!("unknown ply attribute: {}", meta.path.to_token_stream().to_string())))
+                }
+            })?;
+        }
+    }
+    Ok(value)
+}
+
+/// The inclusive discriminant range a `PlyEnum`'s `repr` can hold without truncation.
+fn scalar_kind_int_range(kind: &ScalarKind) -> Option<(i64, i64)> {
+    use ScalarKind::*;
+    match kind {
+        I8 => Some((i8::MIN as i64, i8::MAX as i64)),
+        U8 => Some((0, u8::MAX as i64)),
+        I16 => Some((i16::MIN as i64, i16::MAX as i64)),
+        U16 => Some((0, u16::MAX as i64)),
+        I32 => Some((i32::MIN as i64, i32::MAX as i64)),
+        U32 => Some((0, u32::MAX as i64)),
+        I64 => Some((i64::MIN, i64::MAX)),
+        U64 => Some((0, i64::MAX)),
+        F32 | F64 => None,
+    }
+}
+
+/// Procedural macro to derive the `PlyEnum` trait for a field-less enum, letting it be used
+/// directly as a field type inside `#[derive(PlyRead)]`/`#[derive(PlyWrite)]` structs via
+/// `#[ply(enum, type = "...")]` on the field (the `type` must match this enum's `repr`'s PLY
+/// scalar name so the surrounding derive can pick the right getter bucket).
+///
+/// - `#[ply(repr = "uchar")]` on the enum itself (required): the integer PLY scalar the
+///   discriminant is encoded as. Must be one of the integer scalar names (`char`/`uchar`
+///   through `long`/`ulong`) - floating-point reprs aren't supported.
+/// - `#[ply(value = N)]` on a variant (optional): the discriminant it maps to. Variants without
+///   it default to the previous variant's value + 1 (0 for the first variant), i.e. declaration
+///   order - mirroring a plain Rust `enum`'s implicit discriminants.
+///
+/// Variants must be field-less. Discriminants must be unique and fit in `repr`'s range - both
+/// are rejected at compile time, same as the field-level checks the other derives already do
+/// (`ply(name = "...")` collisions, array length mismatches, and so on).
+#[proc_macro_derive(PlyEnum, attributes(ply))]
+pub fn derive_ply_enum(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let ply_rs = get_crate_name();
+
+    let data_enum = match &input.data {
+        Data::Enum(data) => data,
+        _ => return TokenStream::from(syn::Error::new_spanned(&input.ident, "PlyEnum can only be derived for enums").to_compile_error()),
+    };
+
+    let repr = match parse_ply_enum_repr(&input.attrs) {
+        Ok(Some(r)) => r,
+        Ok(None) => return TokenStream::from(syn::Error::new_spanned(&input.ident, "PlyEnum requires #[ply(repr = \"...\")] naming the backing integer scalar type").to_compile_error()),
+        Err(err) => return TokenStream::from(err.to_compile_error()),
+    };
+    let repr_kind = match scalar_kind_from_str(&repr) {
+        Some(k) => k,
+        None => return TokenStream::from(syn::Error::new_spanned(&input.ident, format!(
+            "Unsupported ply(repr = \"{}\") - use one of: char, uchar, short, ushort, int, uint, long, ulong", repr
+        )).to_compile_error()),
+    };
+    let (lo, hi) = match scalar_kind_int_range(&repr_kind) {
+        Some(range) => range,
+        None => return TokenStream::from(syn::Error::new_spanned(&input.ident, format!(
+            "ply(repr = \"{}\") is not an integer scalar - PlyEnum discriminants need one", repr
+        )).to_compile_error()),
+    };
+    let (scalar_type_token, _) = scalar_type_tokens(&repr_kind, &ply_rs);
+
+    let mut variants: Vec<(syn::Ident, i64)> = Vec::new();
+    let mut next_value: i64 = 0;
+    for variant in &data_enum.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return TokenStream::from(syn::Error::new_spanned(variant, "PlyEnum only supports field-less (unit) variants").to_compile_error());
+        }
+        let value = match parse_ply_enum_value(&variant.attrs) {
+            Ok(Some(v)) => v,
+            Ok(None) => next_value,
+            Err(err) => return TokenStream::from(err.to_compile_error()),
+        };
+        if value < lo || value > hi {
+            return TokenStream::from(syn::Error::new_spanned(&variant.ident, format!(
+                "ply(value = {value}) does not fit in repr \"{repr}\" (range {lo}..={hi})"
+            )).to_compile_error());
+        }
+        variants.push((variant.ident.clone(), value));
+        next_value = value + 1;
+    }
+
+    let mut seen_values = std::collections::HashSet::new();
+    for (ident, value) in &variants {
+        if !seen_values.insert(*value) {
+            return TokenStream::from(syn::Error::new_spanned(ident, format!("duplicate ply(value = {value}) - discriminants must be unique")).to_compile_error());
+        }
+    }
+
+    let from_arms = variants.iter().map(|(ident, value)| quote! { #value => Some(#name::#ident), });
+    let to_arms = variants.iter().map(|(ident, value)| quote! { #name::#ident => #value, });
+
+    let expanded = quote! {
+        impl #ply_rs::ply::PlyEnum for #name {
+            const SCALAR_TYPE: #ply_rs::ply::ScalarType = #scalar_type_token;
+
+            fn from_discriminant(value: i64) -> Option<Self> {
+                match value {
+                    #(#from_arms)*
+                    _ => None,
+                }
+            }
+
+            fn to_discriminant(&self) -> i64 {
+                match self {
+                    #(#to_arms)*
+                }
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+fn get_property_type_tokens(ty: &Type, count_type: Option<&str>, explicit_type: Option<&str>, is_enum: bool, field_span: Option<&syn::Field>, generic_params: &[syn::Ident]) -> Result<proc_macro2::TokenStream, syn::Error> {
     let ply_rs = get_crate_name();
 
+    if is_enum {
+        // The on-disk scalar is whatever `#[derive(PlyEnum)]` declared via `#[ply(repr = "...")]`,
+        // not anything derivable from `ty` itself - mirrors `PlyScalar::SCALAR_TYPE` dispatch.
+        return Ok(quote! { #ply_rs::ply::PropertyType::Scalar(<#ty as #ply_rs::ply::PlyEnum>::SCALAR_TYPE) });
+    }
+
     let scalar_type_from_str = |s: &str| -> Option<proc_macro2::TokenStream> {
         match s {
             "char" | "i8" => Some(quote! { #ply_rs::ply::ScalarType::Char }),
@@ -981,6 +2211,8 @@ fn get_property_type_tokens(ty: &Type, count_type: Option<&str>, explicit_type:
             "uint" | "u32" => Some(quote! { #ply_rs::ply::ScalarType::UInt }),
             "float" | "f32" => Some(quote! { #ply_rs::ply::ScalarType::Float }),
             "double" | "f64" => Some(quote! { #ply_rs::ply::ScalarType::Double }),
+            "long" | "i64" => Some(quote! { #ply_rs::ply::ScalarType::Long }),
+            "ulong" | "u64" => Some(quote! { #ply_rs::ply::ScalarType::ULong }),
             _ => None,
         }
     };
@@ -998,13 +2230,15 @@ fn get_property_type_tokens(ty: &Type, count_type: Option<&str>, explicit_type:
         let elem_scalar_type = if let Some(et) = explicit_type {
             scalar_type_from_str(et).ok_or_else(|| {
                 let span = field_span.map(syn::spanned::Spanned::span).unwrap_or_else(|| syn::spanned::Spanned::span(ty));
-                syn::Error::new(span, format!("Unsupported explicit type: {}. Use one of: i8, u8, i16, u16, i32, u32, f32, f64, char, uchar, short, ushort, int, uint, float, double", et))
+                syn::Error::new(span, format!("Unsupported explicit type: {}. Use one of: i8, u8, i16, u16, i32, u32, i64, u64, f32, f64, char, uchar, short, ushort, int, uint, long, ulong, float, double", et))
             })?
         } else if let Some(kind) = scalar_ident(inner) {
             let (scalar_type_token, _) = scalar_type_tokens(&kind, &ply_rs);
             scalar_type_token
+        } else if generic_scalar_param(inner, generic_params).is_some() {
+            quote! { <#inner as #ply_rs::ply::PlyScalar>::SCALAR_TYPE }
         } else {
-            return Err(syn::Error::new_spanned(inner, "Unsupported field type for PlyAccess. Supported types: i8, u8, i16, u16, i32, u32, f32, f64, and Vec<T> of these."));
+            return Err(syn::Error::new_spanned(inner, "Unsupported field type for PlyAccess. Supported types: i8, u8, i16, u16, i32, u32, i64, u64, f32, f64, and Vec<T> of these."));
         };
 
         return Ok(quote! {
@@ -1015,7 +2249,7 @@ fn get_property_type_tokens(ty: &Type, count_type: Option<&str>, explicit_type:
     if let Some(et) = explicit_type {
         let scalar_type_token = scalar_type_from_str(et).ok_or_else(|| {
             let span = field_span.map(syn::spanned::Spanned::span).unwrap_or_else(|| syn::spanned::Spanned::span(ty));
-            syn::Error::new(span, format!("Unsupported explicit type: {}. Use one of: i8, u8, i16, u16, i32, u32, f32, f64, char, uchar, short, ushort, int, uint, float, double", et))
+            syn::Error::new(span, format!("Unsupported explicit type: {}. Use one of: i8, u8, i16, u16, i32, u32, i64, u64, f32, f64, char, uchar, short, ushort, int, uint, long, ulong, float, double", et))
         })?;
         return Ok(quote! {
             #ply_rs::ply::PropertyType::Scalar(#scalar_type_token)
@@ -1029,10 +2263,18 @@ fn get_property_type_tokens(ty: &Type, count_type: Option<&str>, explicit_type:
         });
     }
 
+    // A bare generic type parameter resolves its on-disk scalar type from `PlyScalar` at
+    // monomorphization, the write-side counterpart of `generate_conversion`'s same check.
+    if generic_scalar_param(ty, generic_params).is_some() {
+        return Ok(quote! {
+            #ply_rs::ply::PropertyType::Scalar(<#ty as #ply_rs::ply::PlyScalar>::SCALAR_TYPE)
+        });
+    }
+
     if is_option(ty).is_some() {
         return Err(syn::Error::new_spanned(ty, "optional properties are only supported by the reader"));
     }
-    Err(syn::Error::new_spanned(ty, "Unsupported field type for PlyAccess. Supported types: i8, u8, i16, u16, i32, u32, f32, f64, and Vec<T> of these."))
+    Err(syn::Error::new_spanned(ty, "Unsupported field type for PlyAccess. Supported types: i8, u8, i16, u16, i32, u32, i64, u64, f32, f64, and Vec<T> of these."))
 }
 
 fn scalar_type_tokens(kind: &ScalarKind, ply_rs: &proc_macro2::TokenStream) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
@@ -1046,10 +2288,8 @@ fn scalar_type_tokens(kind: &ScalarKind, ply_rs: &proc_macro2::TokenStream) -> (
         U32 => (quote!{ #ply_rs::ply::ScalarType::UInt }, quote!{ u32 }),
         F32 => (quote!{ #ply_rs::ply::ScalarType::Float }, quote!{ f32 }),
         F64 => (quote!{ #ply_rs::ply::ScalarType::Double }, quote!{ f64 }),
-        I64 => (quote!{ #ply_rs::ply::ScalarType::Int }, quote!{ i64 }),
-        U64 => (quote!{ #ply_rs::ply::ScalarType::UInt }, quote!{ u64 }),
-        I128 => (quote!{ #ply_rs::ply::ScalarType::Int }, quote!{ i128 }),
-        U128 => (quote!{ #ply_rs::ply::ScalarType::UInt }, quote!{ u128 }),
+        I64 => (quote!{ #ply_rs::ply::ScalarType::Long }, quote!{ i64 }),
+        U64 => (quote!{ #ply_rs::ply::ScalarType::ULong }, quote!{ u64 }),
     }
 }
 
@@ -16,3 +16,76 @@ fn test_unknown_ply_attribute_full_path() {
         }
     }
 }
+
+#[test]
+fn test_is_array_reports_element_type_and_length() {
+    let ty: syn::Type = parse_quote! { [f32; 3] };
+    let (elem, len) = is_array(&ty).expect("expected [f32; 3] to be recognized as an array");
+    assert_eq!(len, 3);
+    assert_eq!(scalar_ident(elem), Some(ScalarKind::F32));
+}
+
+#[test]
+fn test_is_array_rejects_non_array_types() {
+    let ty: syn::Type = parse_quote! { Vec<f32> };
+    assert!(is_array(&ty).is_none());
+}
+
+#[test]
+fn test_bare_default_attribute_parses_without_an_expression() {
+    let field: Field = parse_quote! {
+        #[ply(default)]
+        count: u32
+    };
+
+    let attr = parse_ply_attr(&field).expect("bare #[ply(default)] should parse");
+    assert!(attr.default_expr.is_none());
+}
+
+#[test]
+fn test_optional_attribute_is_recorded_independently_of_option_type() {
+    let field: Field = parse_quote! {
+        #[ply(optional)]
+        count: u32
+    };
+
+    let attr = parse_ply_attr(&field).expect("attribute should parse");
+    assert!(attr.optional);
+}
+
+#[test]
+fn test_coerce_attribute_accepts_strict_and_lossy() {
+    let strict: Field = parse_quote! {
+        #[ply(coerce = "strict")]
+        x: f32
+    };
+    assert!(parse_ply_attr(&strict).unwrap().strict_coerce);
+
+    let lossy: Field = parse_quote! {
+        #[ply(coerce = "lossy")]
+        x: f32
+    };
+    assert!(!parse_ply_attr(&lossy).unwrap().strict_coerce);
+}
+
+#[test]
+fn test_coerce_attribute_rejects_unknown_value() {
+    let field: Field = parse_quote! {
+        #[ply(coerce = "rounded")]
+        x: f32
+    };
+    assert!(parse_ply_attr(&field).is_err());
+}
+
+#[test]
+fn test_array_field_name_list_matches_existing_comma_syntax() {
+    let field: Field = parse_quote! {
+        #[ply(name = "x, y, z")]
+        pos: [f32; 3]
+    };
+
+    let attr = parse_ply_attr(&field).expect("attribute should parse");
+    assert_eq!(attr.names, vec!["x", "y", "z"]);
+    let (_, len) = is_array(&field.ty).expect("expected an array field");
+    assert_eq!(attr.names.len(), len);
+}
@@ -17,6 +17,47 @@ pub enum PlyError {
     /// Serialization error
     #[error("Serialization error: {0}")]
     Serialize(String),
+    /// The PLY header doesn't match what a `#[derive(PlyRead)]` struct expects, as checked by
+    /// a `read_strict` call or by `ReadSchema::validate_required`: a required property is missing, a header property has no matching
+    /// field, a property's scalar/list shape disagrees with the field it maps to, or a
+    /// property's declared scalar type disagrees with the field's (e.g. `double` into `i8`).
+    #[error("Schema mismatch: {0}")]
+    Schema(#[from] SchemaError),
+}
+
+/// Details of a single schema mismatch found by `read_strict` or
+/// `ReadSchema::validate_required`, naming the element and
+/// property involved so the message points straight at the offending line of the PLY header.
+#[derive(Debug, Error)]
+#[error("element '{element}', property '{property}': expected {expected}, found {found}")]
+pub struct SchemaError {
+    /// Name of the PLY element being validated.
+    pub element: String,
+    /// Name of the offending property, or empty when the mismatch is about the element itself.
+    pub property: String,
+    /// What the check expected to find.
+    pub expected: String,
+    /// What was actually declared in the header.
+    pub found: String,
+}
+
+impl PlyError {
+    /// True if this error is an unexpected end of the underlying stream, e.g. a binary
+    /// payload cut off mid-record or an ascii payload missing a trailing line.
+    ///
+    /// Streaming readers like [`crate::parser::ElementIter`] and
+    /// [`crate::parser::PayloadStream`] surface a short/truncated read this way; a caller
+    /// processing a file that may have been written incompletely can use this to tell that
+    /// case apart from [`PlyError::is_malformed`].
+    pub fn is_eof(&self) -> bool {
+        matches!(self, PlyError::Io(e) if e.kind() == std::io::ErrorKind::UnexpectedEof)
+    }
+
+    /// True if this error is a decode-time inconsistency rather than a truncated stream:
+    /// invalid grammar, a non-integer list length, or any other [`PlyError::Parse`].
+    pub fn is_malformed(&self) -> bool {
+        matches!(self, PlyError::Parse(_))
+    }
 }
 
 impl de::Error for PlyError {
@@ -0,0 +1,370 @@
+//! Spatial acceleration (axis-aligned bounding boxes and a bounding-volume hierarchy) over a
+//! decoded triangle mesh.
+//!
+//! Takes the positions and `vertex_indices` a `Vertex`/`Face`/`Mesh` container (see the crate's
+//! top-level example) already decoded, and builds a [`Bvh`] for ray and point queries, so PLY
+//! users get picking/collision/closest-point utilities without reaching for a separate geometry
+//! crate.
+//!
+//! Gated behind the `geometry` feature; enable it in `Cargo.toml` to use [`Bvh::build`].
+
+use crate::errors::{PlyError, PlyResult};
+
+type Result<T> = PlyResult<T>;
+
+/// Number of faces at or below which [`Bvh::build`] stops splitting and emits a leaf.
+const MAX_LEAF_FACES: usize = 4;
+
+/// An axis-aligned bounding box, stored as its per-axis min and max corners.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aabb {
+    pub min: [f32; 3],
+    pub max: [f32; 3],
+}
+
+impl Aabb {
+    /// An empty box that contains nothing; the first [`Aabb::extend`] call replaces both corners.
+    pub fn empty() -> Self {
+        Aabb {
+            min: [f32::INFINITY; 3],
+            max: [f32::NEG_INFINITY; 3],
+        }
+    }
+
+    /// Grows the box, if necessary, so it also contains `point`.
+    pub fn extend(&mut self, point: [f32; 3]) {
+        for axis in 0..3 {
+            self.min[axis] = self.min[axis].min(point[axis]);
+            self.max[axis] = self.max[axis].max(point[axis]);
+        }
+    }
+
+    /// The union of `self` and `other`.
+    pub fn union(&self, other: &Aabb) -> Aabb {
+        let mut result = *self;
+        result.extend(other.min);
+        result.extend(other.max);
+        result
+    }
+
+    /// The box's center point, used to pick a BVH split axis/value.
+    pub fn centroid(&self) -> [f32; 3] {
+        std::array::from_fn(|axis| (self.min[axis] + self.max[axis]) * 0.5)
+    }
+
+    /// The axis (0 = x, 1 = y, 2 = z) along which the box is longest.
+    fn longest_axis(&self) -> usize {
+        let extent: [f32; 3] = std::array::from_fn(|axis| self.max[axis] - self.min[axis]);
+        if extent[0] >= extent[1] && extent[0] >= extent[2] {
+            0
+        } else if extent[1] >= extent[2] {
+            1
+        } else {
+            2
+        }
+    }
+
+    /// The two `t` values (near, far) at which the ray `origin + t * dir` crosses this box's
+    /// slabs, or `None` if it misses. Doesn't check `t >= 0`, so a hit behind `origin` is still
+    /// reported - callers combine this with their own `t` range.
+    fn ray_slab_intersect(&self, origin: [f32; 3], dir: [f32; 3]) -> Option<(f32, f32)> {
+        let mut tmin = f32::NEG_INFINITY;
+        let mut tmax = f32::INFINITY;
+
+        for axis in 0..3 {
+            if dir[axis] == 0.0 {
+                if origin[axis] < self.min[axis] || origin[axis] > self.max[axis] {
+                    return None;
+                }
+                continue;
+            }
+            let inv_d = 1.0 / dir[axis];
+            let mut t0 = (self.min[axis] - origin[axis]) * inv_d;
+            let mut t1 = (self.max[axis] - origin[axis]) * inv_d;
+            if t0 > t1 {
+                std::mem::swap(&mut t0, &mut t1);
+            }
+            tmin = tmin.max(t0);
+            tmax = tmax.min(t1);
+            if tmin > tmax {
+                return None;
+            }
+        }
+
+        Some((tmin, tmax))
+    }
+}
+
+/// One triangular face, as three indices into the mesh's position slice.
+pub type Triangle = [u32; 3];
+
+/// The nearest ray/triangle hit found by [`Bvh::ray_intersect`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hit {
+    /// Index into the `faces` slice [`Bvh::build`] was given.
+    pub face_index: usize,
+    /// Distance along the ray, i.e. the hit point is `origin + t * dir`.
+    pub t: f32,
+    /// Barycentric coordinates of the hit point with respect to the triangle's second and
+    /// third vertices (the first vertex's weight is `1.0 - u - v`).
+    pub u: f32,
+    pub v: f32,
+}
+
+enum Node {
+    Leaf { aabb: Aabb, faces: Vec<usize> },
+    Internal { aabb: Aabb, left: Box<Node>, right: Box<Node> },
+}
+
+impl Node {
+    fn aabb(&self) -> &Aabb {
+        match self {
+            Node::Leaf { aabb, .. } => aabb,
+            Node::Internal { aabb, .. } => aabb,
+        }
+    }
+}
+
+/// A bounding-volume hierarchy over a triangle mesh's faces, for ray and point queries that
+/// don't need to test every triangle.
+pub struct Bvh<'a> {
+    positions: &'a [[f32; 3]],
+    faces: &'a [Triangle],
+    root: Node,
+}
+
+impl<'a> Bvh<'a> {
+    /// Builds a BVH over `faces`, whose indices are into `positions`.
+    ///
+    /// Recursively splits the current face set along the longest axis of its triangles'
+    /// centroid bounds, sorting by centroid and splitting at the middle index (an even split,
+    /// so a set whose centroids all coincide still divides instead of leaving one side empty), and stops once a
+    /// node holds `MAX_LEAF_FACES` (4) or fewer faces.
+    pub fn build(positions: &'a [[f32; 3]], faces: &'a [Triangle]) -> Result<Self> {
+        for face in faces {
+            for &index in face {
+                if index as usize >= positions.len() {
+                    return Err(PlyError::Inconsistent(format!(
+                        "Face references vertex index {}, but only {} positions were given.",
+                        index,
+                        positions.len()
+                    )));
+                }
+            }
+        }
+
+        let face_indices: Vec<usize> = (0..faces.len()).collect();
+        let root = Self::build_node(positions, faces, face_indices);
+        Ok(Bvh { positions, faces, root })
+    }
+
+    fn face_aabb(positions: &[[f32; 3]], face: &Triangle) -> Aabb {
+        let mut aabb = Aabb::empty();
+        for &index in face {
+            aabb.extend(positions[index as usize]);
+        }
+        aabb
+    }
+
+    fn build_node(positions: &[[f32; 3]], faces: &[Triangle], mut face_indices: Vec<usize>) -> Node {
+        let mut aabb = Aabb::empty();
+        for &i in &face_indices {
+            aabb = aabb.union(&Self::face_aabb(positions, &faces[i]));
+        }
+
+        if face_indices.len() <= MAX_LEAF_FACES {
+            return Node::Leaf { aabb, faces: face_indices };
+        }
+
+        let mut centroid_bounds = Aabb::empty();
+        for &i in &face_indices {
+            centroid_bounds.extend(Self::face_aabb(positions, &faces[i]).centroid());
+        }
+        let axis = centroid_bounds.longest_axis();
+
+        face_indices.sort_by(|&a, &b| {
+            let ca = Self::face_aabb(positions, &faces[a]).centroid()[axis];
+            let cb = Self::face_aabb(positions, &faces[b]).centroid()[axis];
+            ca.partial_cmp(&cb).unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let mid = face_indices.len() / 2;
+        // An even split guards against every centroid landing on the same point (e.g. a
+        // degenerate, coplanar face set), where a median-value partition would put everything
+        // on one side and recurse forever.
+        let right_faces = face_indices.split_off(mid);
+        let left_faces = face_indices;
+
+        Node::Internal {
+            aabb,
+            left: Box::new(Self::build_node(positions, faces, left_faces)),
+            right: Box::new(Self::build_node(positions, faces, right_faces)),
+        }
+    }
+
+    /// The bounding box of the whole mesh.
+    pub fn aabb(&self) -> &Aabb {
+        self.root.aabb()
+    }
+
+    /// Finds the nearest triangle `origin + t * dir` (`t >= 0`) hits, pruning subtrees whose
+    /// box the ray misses via the slab test before testing any triangle.
+    pub fn ray_intersect(&self, origin: [f32; 3], dir: [f32; 3]) -> Option<Hit> {
+        let mut best: Option<Hit> = None;
+        self.ray_intersect_node(&self.root, origin, dir, &mut best);
+        best
+    }
+
+    fn ray_intersect_node(&self, node: &Node, origin: [f32; 3], dir: [f32; 3], best: &mut Option<Hit>) {
+        let Some((tmin, tmax)) = node.aabb().ray_slab_intersect(origin, dir) else {
+            return;
+        };
+        if tmax < 0.0 {
+            return;
+        }
+        if let Some(hit) = best {
+            if tmin > hit.t {
+                return;
+            }
+        }
+
+        match node {
+            Node::Leaf { faces, .. } => {
+                for &face_index in faces {
+                    if let Some(hit) = self.ray_intersect_triangle(face_index, origin, dir) {
+                        let better = match best {
+                            Some(existing) => hit.t < existing.t,
+                            None => true,
+                        };
+                        if better {
+                            *best = Some(hit);
+                        }
+                    }
+                }
+            }
+            Node::Internal { left, right, .. } => {
+                self.ray_intersect_node(left, origin, dir, best);
+                self.ray_intersect_node(right, origin, dir, best);
+            }
+        }
+    }
+
+    /// Möller-Trumbore ray/triangle intersection for a single face, `t >= 0` only.
+    fn ray_intersect_triangle(&self, face_index: usize, origin: [f32; 3], dir: [f32; 3]) -> Option<Hit> {
+        let face = &self.faces[face_index];
+        let v0 = self.positions[face[0] as usize];
+        let v1 = self.positions[face[1] as usize];
+        let v2 = self.positions[face[2] as usize];
+
+        let edge1 = sub(v1, v0);
+        let edge2 = sub(v2, v0);
+        let pvec = cross(dir, edge2);
+        let det = dot(edge1, pvec);
+        if det.abs() < f32::EPSILON {
+            return None;
+        }
+        let inv_det = 1.0 / det;
+
+        let tvec = sub(origin, v0);
+        let u = dot(tvec, pvec) * inv_det;
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+
+        let qvec = cross(tvec, edge1);
+        let v = dot(dir, qvec) * inv_det;
+        if v < 0.0 || u + v > 1.0 {
+            return None;
+        }
+
+        let t = dot(edge2, qvec) * inv_det;
+        if t < 0.0 {
+            return None;
+        }
+
+        Some(Hit { face_index, t, u, v })
+    }
+}
+
+fn sub(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    std::array::from_fn(|i| a[i] - b[i])
+}
+
+fn cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn dot(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn single_triangle() -> (Vec<[f32; 3]>, Vec<Triangle>) {
+        (
+            vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+            vec![[0, 1, 2]],
+        )
+    }
+
+    #[test]
+    fn aabb_extend_tracks_per_axis_min_max() {
+        let mut aabb = Aabb::empty();
+        aabb.extend([1.0, -2.0, 3.0]);
+        aabb.extend([-1.0, 5.0, 0.0]);
+        assert_eq!(aabb.min, [-1.0, -2.0, 0.0]);
+        assert_eq!(aabb.max, [1.0, 5.0, 3.0]);
+    }
+
+    #[test]
+    fn build_rejects_out_of_range_face_index() {
+        let positions = vec![[0.0, 0.0, 0.0]];
+        let faces = vec![[0, 1, 2]];
+        assert!(Bvh::build(&positions, &faces).is_err());
+    }
+
+    #[test]
+    fn ray_intersect_hits_triangle_face_on() {
+        let (positions, faces) = single_triangle();
+        let bvh = Bvh::build(&positions, &faces).unwrap();
+
+        let hit = bvh.ray_intersect([0.2, 0.2, 1.0], [0.0, 0.0, -1.0]).unwrap();
+        assert_eq!(hit.face_index, 0);
+        assert!((hit.t - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn ray_intersect_misses_when_ray_points_away() {
+        let (positions, faces) = single_triangle();
+        let bvh = Bvh::build(&positions, &faces).unwrap();
+
+        assert!(bvh.ray_intersect([0.2, 0.2, 1.0], [0.0, 0.0, 1.0]).is_none());
+    }
+
+    #[test]
+    fn build_splits_many_faces_into_a_hierarchy_and_finds_the_nearest_hit() {
+        // A row of separate unit triangles along x, each at a different z depth; the ray
+        // crosses only the first one, so the BVH has to descend to the right leaf to find it.
+        let mut positions = Vec::new();
+        let mut faces = Vec::new();
+        for i in 0..16 {
+            let x = i as f32 * 2.0;
+            let z = i as f32;
+            let base = positions.len() as u32;
+            positions.push([x, 0.0, z]);
+            positions.push([x + 1.0, 0.0, z]);
+            positions.push([x, 1.0, z]);
+            faces.push([base, base + 1, base + 2]);
+        }
+
+        let bvh = Bvh::build(&positions, &faces).unwrap();
+        let hit = bvh.ray_intersect([0.2, 0.2, 10.0], [0.0, 0.0, -1.0]).unwrap();
+        assert_eq!(hit.face_index, 0);
+    }
+}
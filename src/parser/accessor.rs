@@ -0,0 +1,209 @@
+//! Random access to individual elements of a fixed-stride binary PLY payload.
+//!
+//! Unlike [`super::Parser::read_payload`], which decodes every element of every group,
+//! [`PlyAccessor`] seeks directly to a single record given its element group and index.
+//! This requires the payload to be binary (not ascii) and, up to and including the
+//! requested group, free of `PropertyType::List` properties: list lengths are data-dependent,
+//! so the byte offset of anything after a list group can't be computed without decoding it.
+
+use std::io::{Read, Seek, SeekFrom};
+
+use super::fixed_stride;
+use crate::errors::{PlyError, PlyResult};
+use crate::parser::{attach_location, Parser};
+use crate::ply::{Encoding, Header, PropertyAccess};
+use crate::util::Location;
+
+type Result<T> = PlyResult<T>;
+
+/// Seeks to and decodes individual elements of a binary PLY payload without reading
+/// everything before them.
+///
+/// Construct with the byte offset of the payload (i.e. right after `end_header\n`, as
+/// reported by the caller's own header read), then call [`PlyAccessor::get`] with an
+/// element group name and index.
+pub struct PlyAccessor<'a, T, E: PropertyAccess> {
+    reader: &'a mut T,
+    header: &'a Header,
+    payload_offset: u64,
+    parser: Parser<E>,
+}
+
+impl<'a, T: Read + Seek, E: PropertyAccess> PlyAccessor<'a, T, E> {
+    /// Creates an accessor over `reader`, whose current position need not be `payload_offset`;
+    /// [`PlyAccessor::get`] seeks before every read.
+    pub fn new(reader: &'a mut T, header: &'a Header, payload_offset: u64) -> Self {
+        PlyAccessor {
+            reader,
+            header,
+            payload_offset,
+            parser: Parser::new(),
+        }
+    }
+
+    /// Decodes element number `index` of the `element_name` group, without decoding any
+    /// other element.
+    ///
+    /// Walks `header.elements` from the start, accumulating the byte size of each group to
+    /// find the base offset of `element_name`. Fails if the payload isn't binary, if
+    /// `element_name` or `index` is out of range, or if `element_name` or any group before
+    /// it contains a list property (and so has no fixed per-record stride).
+    pub fn get(&mut self, element_name: &str, index: usize) -> Result<E> {
+        if self.header.encoding == Encoding::Ascii {
+            return Err(PlyError::Parse(
+                "Random access requires a binary-encoded payload.".to_string(),
+            ));
+        }
+
+        let mut offset = self.payload_offset;
+        for (name, element_def) in &self.header.elements {
+            let stride = fixed_stride(element_def).ok_or_else(|| {
+                PlyError::Parse(format!(
+                    "Element '{}' has list properties, so its byte size isn't fixed; \
+                     random access can't compute offsets for groups at or after it.",
+                    name
+                ))
+            })?;
+
+            if name == element_name {
+                if index >= element_def.count {
+                    return Err(PlyError::Parse(format!(
+                        "Index {} out of range for element '{}' ({} records).",
+                        index, element_name, element_def.count
+                    )));
+                }
+
+                let record_offset = offset + (index * stride) as u64;
+                self.reader
+                    .seek(SeekFrom::Start(record_offset))
+                    .map_err(PlyError::Io)?;
+
+                let result = match self.header.encoding {
+                    Encoding::BinaryBigEndian => self.parser.read_big_endian_element(self.reader, element_def),
+                    Encoding::BinaryLittleEndian => self.parser.read_little_endian_element(self.reader, element_def),
+                    Encoding::Ascii => unreachable!("checked above"),
+                };
+                return attach_location(
+                    Location::Byte {
+                        offset: record_offset,
+                        element: Some(element_name.to_string()),
+                        index: Some(index),
+                        property: None,
+                    },
+                    result,
+                );
+            }
+
+            offset += (element_def.count * stride) as u64;
+        }
+
+        Err(PlyError::Parse(format!("Unknown element '{}'.", element_name)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ply::{Addable, DefaultElement, ElementDef, KeyMap, PropertyDef, PropertyType, ScalarType, Version};
+    use std::io::Cursor;
+
+    fn header_with(elements: Vec<ElementDef>, encoding: Encoding) -> Header {
+        let mut map = KeyMap::<ElementDef>::new();
+        for e in elements {
+            map.add(e);
+        }
+        Header {
+            encoding,
+            version: Version { major: 1, minor: 0 },
+            obj_infos: Vec::new(),
+            elements: map,
+            comments: Vec::new(),
+        }
+    }
+
+    fn point_element(count: usize) -> ElementDef {
+        let mut props = KeyMap::<PropertyDef>::new();
+        props.add(PropertyDef::new("x".to_string(), PropertyType::Scalar(ScalarType::Int)));
+        props.add(PropertyDef::new("y".to_string(), PropertyType::Scalar(ScalarType::Float)));
+        let mut def = ElementDef::new("point".to_string());
+        def.count = count;
+        def.properties = props;
+        def
+    }
+
+    #[test]
+    fn get_seeks_to_requested_record() {
+        let header = header_with(vec![point_element(3)], Encoding::BinaryLittleEndian);
+
+        let mut bytes = Vec::new();
+        for (x, y) in [(1i32, 1.5f32), (-2, 2.5), (3, -3.5)] {
+            bytes.extend_from_slice(&x.to_le_bytes());
+            bytes.extend_from_slice(&y.to_le_bytes());
+        }
+        let mut cursor = Cursor::new(bytes);
+
+        let mut accessor = PlyAccessor::<_, DefaultElement>::new(&mut cursor, &header, 0);
+        let second = accessor.get("point", 1).unwrap();
+        assert_eq!(second.get_int("x"), Some(-2));
+        assert_eq!(second.get_float("y"), Some(2.5));
+    }
+
+    #[test]
+    fn get_accounts_for_base_offset_and_preceding_groups() {
+        // Give the two groups distinct names so they don't collide in the header map.
+        let mut second_group = point_element(2);
+        second_group.name = "extra".to_string();
+        let header = header_with(
+            vec![point_element(2), second_group],
+            Encoding::BinaryLittleEndian,
+        );
+
+        let mut bytes = vec![0u8; 16]; // unrelated leading bytes before the payload proper
+        for (x, y) in [(10i32, 0.0f32), (20, 0.0)] {
+            bytes.extend_from_slice(&x.to_le_bytes());
+            bytes.extend_from_slice(&y.to_le_bytes());
+        }
+        for (x, y) in [(30i32, 0.0f32), (40, 0.0)] {
+            bytes.extend_from_slice(&x.to_le_bytes());
+            bytes.extend_from_slice(&y.to_le_bytes());
+        }
+        let mut cursor = Cursor::new(bytes);
+
+        let mut accessor = PlyAccessor::<_, DefaultElement>::new(&mut cursor, &header, 16);
+        assert_eq!(accessor.get("point", 1).unwrap().get_int("x"), Some(20));
+        assert_eq!(accessor.get("extra", 0).unwrap().get_int("x"), Some(30));
+    }
+
+    #[test]
+    fn get_rejects_out_of_range_index() {
+        let header = header_with(vec![point_element(1)], Encoding::BinaryLittleEndian);
+        let mut cursor = Cursor::new(vec![0u8; 8]);
+        let mut accessor = PlyAccessor::<_, DefaultElement>::new(&mut cursor, &header, 0);
+        assert!(accessor.get("point", 1).is_err());
+    }
+
+    #[test]
+    fn get_rejects_list_properties_before_target() {
+        let mut list_props = KeyMap::<PropertyDef>::new();
+        list_props.add(PropertyDef::new(
+            "vertex_index".to_string(),
+            PropertyType::List(ScalarType::UChar, ScalarType::Int),
+        ));
+        let mut face = ElementDef::new("face".to_string());
+        face.count = 2;
+        face.properties = list_props;
+
+        let header = header_with(vec![face, point_element(1)], Encoding::BinaryLittleEndian);
+        let mut cursor = Cursor::new(vec![0u8; 32]);
+        let mut accessor = PlyAccessor::<_, DefaultElement>::new(&mut cursor, &header, 0);
+        assert!(accessor.get("point", 0).is_err());
+    }
+
+    #[test]
+    fn get_rejects_ascii_encoding() {
+        let header = header_with(vec![point_element(1)], Encoding::Ascii);
+        let mut cursor = Cursor::new(vec![0u8; 8]);
+        let mut accessor = PlyAccessor::<_, DefaultElement>::new(&mut cursor, &header, 0);
+        assert!(accessor.get("point", 0).is_err());
+    }
+}
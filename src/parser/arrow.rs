@@ -0,0 +1,149 @@
+//! Conversion from decoded PLY elements to an Apache Arrow [`RecordBatch`].
+//!
+//! Each [`ScalarType`] maps to the matching Arrow primitive (`Char` → `Int8`, `UChar` →
+//! `UInt8`, … `Float` → `Float32`, `Double` → `Float64`), and each list property becomes a
+//! [`ListArray`] over the same child primitive. This gives callers zero-friction interop with
+//! the Arrow/Parquet ecosystem - e.g. dumping a point cloud straight to Parquet - without
+//! hand-rolling the type mapping themselves.
+//!
+//! Gated behind the `arrow` feature; enable it in `Cargo.toml` to use [`Parser::to_record_batch`].
+
+use std::sync::Arc;
+
+use arrow::array::{
+    Array, ArrayRef, Float16Array, Float32Array, Float64Array, Int16Array, Int32Array, Int64Array,
+    Int8Array, ListArray, UInt16Array, UInt32Array, UInt64Array, UInt8Array,
+};
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::record_batch::RecordBatch;
+
+use crate::errors::{PlyError, PlyResult};
+use crate::ply::{DefaultElement, ElementDef, PropertyAccess, PropertyType, ScalarType};
+
+use super::Parser;
+
+type Result<T> = PlyResult<T>;
+
+/// The Arrow primitive type a [`ScalarType`] decodes into.
+fn arrow_type(scalar_type: ScalarType) -> DataType {
+    match scalar_type {
+        ScalarType::Char => DataType::Int8,
+        ScalarType::UChar => DataType::UInt8,
+        ScalarType::Short => DataType::Int16,
+        ScalarType::UShort => DataType::UInt16,
+        ScalarType::Int => DataType::Int32,
+        ScalarType::UInt => DataType::UInt32,
+        ScalarType::Half => DataType::Float16,
+        ScalarType::Float => DataType::Float32,
+        ScalarType::Double => DataType::Float64,
+        ScalarType::Long => DataType::Int64,
+        ScalarType::ULong => DataType::UInt64,
+    }
+}
+
+/// Builds one flat (non-list) Arrow column by pulling `property` out of every row via
+/// [`PropertyAccess`], failing if a row is missing it or holds a different scalar type.
+fn build_scalar_column(rows: &[DefaultElement], property: &str, scalar_type: ScalarType) -> Result<ArrayRef> {
+    macro_rules! column {
+        ($getter:ident, $array:ident) => {{
+            let mut values = Vec::with_capacity(rows.len());
+            for row in rows {
+                values.push(row.$getter(property).ok_or_else(|| {
+                    PlyError::Inconsistent(format!(
+                        "Property '{}' is missing or not a {:?} on some row.",
+                        property, scalar_type
+                    ))
+                })?);
+            }
+            Arc::new($array::from(values)) as ArrayRef
+        }};
+    }
+
+    Ok(match scalar_type {
+        ScalarType::Char => column!(get_char, Int8Array),
+        ScalarType::UChar => column!(get_uchar, UInt8Array),
+        ScalarType::Short => column!(get_short, Int16Array),
+        ScalarType::UShort => column!(get_ushort, UInt16Array),
+        ScalarType::Int => column!(get_int, Int32Array),
+        ScalarType::UInt => column!(get_uint, UInt32Array),
+        ScalarType::Half => column!(get_half, Float16Array),
+        ScalarType::Float => column!(get_float, Float32Array),
+        ScalarType::Double => column!(get_double, Float64Array),
+        ScalarType::Long => column!(get_long, Int64Array),
+        ScalarType::ULong => column!(get_ulong, UInt64Array),
+    })
+}
+
+/// Builds one Arrow [`ListArray`] column by pulling `property`'s list out of every row.
+fn build_list_column(rows: &[DefaultElement], property: &str, scalar_type: ScalarType) -> Result<ArrayRef> {
+    macro_rules! list_column {
+        ($getter:ident, $array:ident) => {{
+            let mut values = Vec::new();
+            let mut offsets = Vec::with_capacity(rows.len() + 1);
+            offsets.push(0i32);
+            for row in rows {
+                let list = row.$getter(property).ok_or_else(|| {
+                    PlyError::Inconsistent(format!(
+                        "Property '{}' is missing or not a {:?} list on some row.",
+                        property, scalar_type
+                    ))
+                })?;
+                values.extend_from_slice(&list);
+                offsets.push(values.len() as i32);
+            }
+            let child = Arc::new($array::from(values)) as ArrayRef;
+            Arc::new(ListArray::new(
+                Arc::new(Field::new("item", child.data_type().clone(), false)),
+                arrow::buffer::OffsetBuffer::new(offsets.into()),
+                child,
+                None,
+            )) as ArrayRef
+        }};
+    }
+
+    Ok(match scalar_type {
+        ScalarType::Char => list_column!(get_list_char, Int8Array),
+        ScalarType::UChar => list_column!(get_list_uchar, UInt8Array),
+        ScalarType::Short => list_column!(get_list_short, Int16Array),
+        ScalarType::UShort => list_column!(get_list_ushort, UInt16Array),
+        ScalarType::Int => list_column!(get_list_int, Int32Array),
+        ScalarType::UInt => list_column!(get_list_uint, UInt32Array),
+        ScalarType::Half => list_column!(get_list_half, Float16Array),
+        ScalarType::Float => list_column!(get_list_float, Float32Array),
+        ScalarType::Double => list_column!(get_list_double, Float64Array),
+        ScalarType::Long => list_column!(get_list_long, Int64Array),
+        ScalarType::ULong => list_column!(get_list_ulong, UInt64Array),
+    })
+}
+
+impl Parser<DefaultElement> {
+    /// Converts the decoded rows of one element group into an Arrow [`RecordBatch`], using
+    /// `element_def` (as returned by [`super::Parser::read_ply_header`] or [`Header`](crate::ply::Header))
+    /// to determine each property's column type and order.
+    ///
+    /// Every row in `elements` must carry every property declared in `element_def` with a
+    /// matching scalar type; a missing or mismatched property fails the whole batch rather
+    /// than silently producing a null, since a column shorter than the others would desync
+    /// the batch's row alignment.
+    pub fn to_record_batch(&self, element_def: &ElementDef, elements: &[DefaultElement]) -> Result<RecordBatch> {
+        let mut fields = Vec::with_capacity(element_def.properties.len());
+        let mut columns: Vec<ArrayRef> = Vec::with_capacity(element_def.properties.len());
+
+        for (name, property) in &element_def.properties {
+            match property.data_type {
+                PropertyType::Scalar(scalar_type) => {
+                    fields.push(Field::new(name, arrow_type(scalar_type), false));
+                    columns.push(build_scalar_column(elements, name, scalar_type)?);
+                }
+                PropertyType::List(_, item_type) => {
+                    let item_field = Field::new("item", arrow_type(item_type), false);
+                    fields.push(Field::new(name, DataType::List(Arc::new(item_field)), false));
+                    columns.push(build_list_column(elements, name, item_type)?);
+                }
+            }
+        }
+
+        RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)
+            .map_err(|e| PlyError::Inconsistent(format!("Couldn't assemble Arrow RecordBatch: {}", e)))
+    }
+}
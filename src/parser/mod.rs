@@ -11,24 +11,110 @@ use crate::errors::{PlyError, PlyResult};
 type Result<T> = PlyResult<T>;
 
 mod ply_grammar;
+#[cfg(feature = "tokio")]
+pub mod async_parser;
+pub mod accessor;
+#[cfg(feature = "arrow")]
+pub mod arrow;
 
 use self::ply_grammar::grammar;
 use self::ply_grammar::Line;
-use crate::util::LocationTracker;
+use crate::util::{CountingReader, Location, LocationTracker};
+
+/// Wraps `result`'s error (if any) with `location` context, turning a bare
+/// `PlyError::Io`/`PlyError::Parse` from a binary decode into one that also reports where in
+/// the payload it happened - see [`Location::Byte`].
+pub(crate) fn attach_location<T>(location: Location, result: Result<T>) -> Result<T> {
+    result.map_err(|e| match e {
+        PlyError::Io(io_err) => {
+            let kind = io_err.kind();
+            PlyError::Io(io::Error::new(kind, format!("{}: {}", location, io_err)))
+        }
+        PlyError::Parse(msg) => PlyError::Parse(format!("{}: {}", location, msg)),
+        other => other,
+    })
+}
 
 fn parse_ascii_rethrow<T, E: Debug>(location: &LocationTracker, line_str: &str, e: E, message: &str) -> PlyResult<T> {
     Err(PlyError::Parse(
-        format!("Line {}: {}\n\tString: '{}'\n\tError: {:?}", location.line_index, message, line_str, e)
+        format!("{}: {}\n\tString: '{}'\n\tError: {:?}", location.line_location(), message, line_str, e)
     ))
 }
 fn parse_ascii_error<T>(location: &LocationTracker, line_str: &str, message: &str) -> PlyResult<T> {
     Err(PlyError::Parse(
-        format!("Line {}: {}\n\tString: '{}'", location.line_index, message, line_str)
+        format!("{}: {}\n\tString: '{}'", location.line_location(), message, line_str)
     ))
 }
 
 use std::marker::PhantomData;
 
+const GZIP_MAGIC: [u8; 2] = [0x1F, 0x8B];
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xB5, 0x2F, 0xFD];
+
+/// Default cap on a single list property's declared length (see [`Parser::with_max_list_len`]).
+///
+/// A corrupt or hostile file can declare a list length near `u32::MAX`; left unchecked that
+/// number is fed straight into `Vec::with_capacity`, triggering a multi-gigabyte allocation
+/// before a single list element has actually been read. No legitimate PLY list (face vertex
+/// indices, texture coordinate counts, ...) comes anywhere close to this, so the default
+/// leaves the fast path untouched for well-formed files.
+const DEFAULT_MAX_LIST_LEN: usize = 1 << 24;
+
+/// Chunk size used to grow a list `Vec` incrementally instead of reserving its full declared
+/// length up front; see [`Parser::with_max_list_len`].
+const LIST_GROWTH_CHUNK: usize = 4096;
+
+/// Default cap on a single element group's declared count (see [`Parser::with_max_total_elements`]).
+///
+/// Mirrors [`DEFAULT_MAX_LIST_LEN`]: a corrupt or hostile header can declare an `element ... N`
+/// count near `u32::MAX`, which is fed straight into `Vec::with_capacity` (or, for the
+/// fixed-stride binary path, a `count * stride` byte buffer) before a single element has
+/// actually been read. No legitimate PLY file comes anywhere close to this, so the default
+/// leaves the fast path untouched for well-formed files.
+const DEFAULT_MAX_TOTAL_ELEMENTS: usize = 1 << 24;
+
+/// Peeks the first bytes of `reader` and, if they match a known compressed-container magic,
+/// transparently wraps `reader` in the matching streaming decoder. Falls back to `reader`
+/// itself, untouched, for anything else (including a plain `ply\n` stream).
+///
+/// `fill_buf` only peeks - it doesn't consume - so whichever branch is taken sees the magic
+/// bytes again as the first bytes of the (possibly decompressed) stream, exactly like a
+/// framed decompressor that must not read past its own input expects.
+fn decompress<'a, R: BufRead + 'a>(mut reader: R) -> Result<Box<dyn BufRead + 'a>> {
+    let peeked = reader.fill_buf().map_err(PlyError::Io)?;
+
+    if peeked.starts_with(&GZIP_MAGIC) {
+        #[cfg(feature = "gzip")]
+        {
+            return Ok(Box::new(BufReader::new(flate2::read::MultiGzDecoder::new(reader))));
+        }
+        #[cfg(not(feature = "gzip"))]
+        {
+            return Err(PlyError::Io(io::Error::new(
+                ErrorKind::Unsupported,
+                "Input looks gzip-compressed, but ply-rs-bw was built without the 'gzip' feature.",
+            )));
+        }
+    }
+
+    if peeked.starts_with(&ZSTD_MAGIC) {
+        #[cfg(feature = "zstd")]
+        {
+            let decoder = zstd::Decoder::new(reader).map_err(PlyError::Io)?;
+            return Ok(Box::new(BufReader::new(decoder)));
+        }
+        #[cfg(not(feature = "zstd"))]
+        {
+            return Err(PlyError::Io(io::Error::new(
+                ErrorKind::Unsupported,
+                "Input looks zstd-compressed, but ply-rs-bw was built without the 'zstd' feature.",
+            )));
+        }
+    }
+
+    Ok(Box::new(reader))
+}
+
 /// Reads data given by a `Read` trait into `Ply` components.
 ///
 /// In most cases `read_ply()` should suffice.
@@ -94,11 +180,17 @@ use std::marker::PhantomData;
 #[derive(Debug)]
 pub struct Parser<E: PropertyAccess> {
       phantom: PhantomData<E>,
+      max_list_len: usize,
+      max_total_elements: usize,
 }
 
 impl<E: PropertyAccess> Clone for Parser<E> {
     fn clone(&self) -> Self {
-        Parser { phantom: PhantomData }
+        Parser {
+            phantom: PhantomData,
+            max_list_len: self.max_list_len,
+            max_total_elements: self.max_total_elements,
+        }
     }
 }
 
@@ -121,15 +213,72 @@ impl<E: PropertyAccess> Parser<E> {
     ///
     /// To get started quickly try `DefaultElement` from the `ply` module.
     pub fn new() -> Self {
-        Parser { phantom: PhantomData }
+        Parser {
+            phantom: PhantomData,
+            max_list_len: DEFAULT_MAX_LIST_LEN,
+            max_total_elements: DEFAULT_MAX_TOTAL_ELEMENTS,
+        }
+    }
+
+    /// Caps the declared length of any single list property (e.g. the `vertex_index` list of
+    /// a `face` element) accepted while decoding.
+    ///
+    /// A length over the cap is rejected with [`PlyError::Parse`] before any `Vec` is sized
+    /// by it, guarding against a corrupt or hostile file declaring a list length near
+    /// `u32::MAX` to force a multi-gigabyte allocation. Defaults to [`DEFAULT_MAX_LIST_LEN`];
+    /// call this to raise or lower the bound for your own trust model.
+    pub fn with_max_list_len(mut self, max_list_len: usize) -> Self {
+        self.max_list_len = max_list_len;
+        self
+    }
+
+    /// Caps the element count a single group (the `N` in `element <name> N`) may declare.
+    ///
+    /// A count over the cap is rejected with [`PlyError::Parse`] before any `Vec` or byte
+    /// buffer is sized by it, guarding against a corrupt or hostile file declaring a count
+    /// near `u32::MAX` to force a multi-gigabyte allocation. Defaults to
+    /// [`DEFAULT_MAX_TOTAL_ELEMENTS`]; call this to raise or lower the bound for your own
+    /// trust model.
+    pub fn with_max_total_elements(mut self, max_total_elements: usize) -> Self {
+        self.max_total_elements = max_total_elements;
+        self
+    }
+
+    fn check_list_len(&self, count: usize) -> Result<()> {
+        if count > self.max_list_len {
+            return Err(PlyError::Parse(format!(
+                "List length {} exceeds the configured limit of {} (see Parser::with_max_list_len).",
+                count, self.max_list_len
+            )));
+        }
+        Ok(())
+    }
+
+    fn check_element_count(&self, element_name: &str, count: usize) -> Result<()> {
+        if count > self.max_total_elements {
+            return Err(PlyError::Parse(format!(
+                "Element '{}' declares {} records, exceeding the configured limit of {} \
+                 (see Parser::with_max_total_elements).",
+                element_name, count, self.max_total_elements
+            )));
+        }
+        Ok(())
     }
 
     /// Expects the complete content of a PLY file.
     ///
     /// A PLY file starts with "ply\n". `read_ply` reads until all elements have been read as
     /// defined in the header of the PLY file.
-    pub fn read_ply<T: Read>(&self, source: &mut T) -> Result<Ply<E>> {
-        let mut source = BufReader::new(source);
+    ///
+    /// `source` may also be a gzip- or zstd-compressed PLY stream: before looking for the
+    /// `ply\n` magic, the first bytes are peeked and matched against the gzip (`1F 8B`) and
+    /// zstd (`28 B5 2F FD`) container magics, transparently wrapping `source` in the
+    /// matching streaming decoder (behind the `gzip`/`zstd` cargo features) before handing
+    /// it to the header/payload parsers, which only ever need `BufRead` and don't otherwise
+    /// know or care that decompression happened.
+    pub fn read_ply<'a, T: Read>(&self, source: &'a mut T) -> Result<Ply<E>> {
+        let source = BufReader::new(source);
+        let mut source = decompress(source)?;
         let mut location = LocationTracker::new();
         let header = self.__read_header(&mut source, &mut location)?;
         let payload = self.__read_payload(&mut source, &mut location, &header)?;
@@ -219,8 +368,8 @@ impl<E: PropertyAccess> Parser<E> {
             if reader.read_line(&mut line_str)? == 0 {
                 return Err(PlyError::Parse(
                     format!(
-                        "Line {}: Unexpected end of file while reading header (missing 'end_header').",
-                        location.line_index
+                        "{}: Unexpected end of file while reading header (missing 'end_header').",
+                        location.line_location()
                     )
                 ));
             }
@@ -309,6 +458,239 @@ impl<E: PropertyAccess> Parser<E> {
     }
 }
 
+// //////////////////////
+// # Columnar payload
+// //////////////////////
+use crate::ply::columnar::{Column, ColumnarElement, ColumnarPayload, ListColumn};
+
+impl<E: PropertyAccess> Parser<E> {
+    /// Reads the entire payload into a [`ColumnarPayload`] instead of the default
+    /// row-oriented [`Payload`].
+    ///
+    /// Each property is decoded straight into a contiguous, typed column instead of
+    /// being boxed per-row into `E`, which avoids the per-element allocation/dispatch
+    /// overhead of [`Parser::read_payload`] for large, uniformly-typed meshes and gives
+    /// zero-copy slice access via [`crate::ply::columnar::ColumnarAccess`].
+    ///
+    /// When `header.encoding` is binary and an element's properties are all fixed-size
+    /// scalars (no lists), that element is bulk-decoded: one `read_exact` for the whole
+    /// element instead of one small read per property per row, same as
+    /// [`Parser::read_payload`]'s fixed-stride path.
+    ///
+    /// This only reads the payload; see [`Parser::read_ply_columnar`] to read a whole file.
+    pub fn read_payload_columnar<T: BufRead>(&self, reader: &mut T, header: &Header) -> Result<ColumnarPayload> {
+        let mut payload = ColumnarPayload::with_capacity(header.elements.len());
+        for (name, element_def) in &header.elements {
+            self.check_element_count(name, element_def.count)?;
+            let mut columns = ColumnarElement::with_capacity(element_def.properties.len());
+            for (_, prop) in &element_def.properties {
+                columns.insert(prop.name.clone(), empty_column_for(&prop.data_type));
+            }
+
+            let endian = match header.encoding {
+                Encoding::BinaryBigEndian => Some(Endian::Big),
+                Encoding::BinaryLittleEndian => Some(Endian::Little),
+                Encoding::Ascii => None,
+            };
+            if let (Some(endian), Some(stride)) = (endian, fixed_stride(element_def)) {
+                let mut buf = vec![0u8; element_def.count * stride];
+                reader.read_exact(&mut buf).map_err(|e| {
+                    if e.kind() == ErrorKind::UnexpectedEof {
+                        PlyError::Io(io::Error::new(
+                            ErrorKind::UnexpectedEof,
+                            format!(
+                                "Unexpected end of file while reading element '{}' (expected {} elements of {} bytes each).",
+                                name, element_def.count, stride,
+                            ),
+                        ))
+                    } else {
+                        PlyError::Io(e)
+                    }
+                })?;
+                for row in buf.chunks_exact(stride) {
+                    decode_fixed_stride_row_into_columns(element_def, row, endian, &mut columns);
+                }
+                payload.insert(name.clone(), columns);
+                continue;
+            }
+
+            for i in 0..element_def.count {
+                match header.encoding {
+                    Encoding::Ascii => {
+                        let mut line_str = String::new();
+                        if reader.read_line(&mut line_str)? == 0 {
+                            return Err(PlyError::Io(io::Error::new(
+                                ErrorKind::UnexpectedEof,
+                                format!(
+                                    "Unexpected end of file while reading element '{}' (expected {}, got {}).",
+                                    name, element_def.count, i,
+                                ),
+                            )));
+                        }
+                        let values = match grammar::data_line(&line_str) {
+                            Ok(v) => v,
+                            Err(e) => return Err(PlyError::Parse(format!("Couldn't parse element line.\n\tString: '{}'\n\tError: {}", line_str, e))),
+                        };
+                        let mut elem_it: Iter<&str> = values.iter();
+                        for (k, p) in &element_def.properties {
+                            let value = self.__read_ascii_property(&mut elem_it, &p.data_type)?;
+                            push_into_column(columns.get_mut(k).unwrap(), value);
+                        }
+                    }
+                    Encoding::BinaryBigEndian => {
+                        for (k, p) in &element_def.properties {
+                            let value = self.__read_binary_property(reader, &p.data_type, Endian::Big)?;
+                            push_into_column(columns.get_mut(k).unwrap(), value);
+                        }
+                    }
+                    Encoding::BinaryLittleEndian => {
+                        for (k, p) in &element_def.properties {
+                            let value = self.__read_binary_property(reader, &p.data_type, Endian::Little)?;
+                            push_into_column(columns.get_mut(k).unwrap(), value);
+                        }
+                    }
+                }
+            }
+
+            payload.insert(name.clone(), columns);
+        }
+        Ok(payload)
+    }
+
+    /// Reads a whole PLY file (header and payload) into a [`ColumnarPayload`], the way
+    /// [`Parser::read_ply`] does for the row-oriented [`Payload`].
+    ///
+    /// `source` may be gzip- or zstd-compressed, exactly as for [`Parser::read_ply`].
+    pub fn read_ply_columnar<'a, T: Read>(&self, source: &'a mut T) -> Result<(Header, ColumnarPayload)> {
+        let source = BufReader::new(source);
+        let mut source = decompress(source)?;
+        let mut location = LocationTracker::new();
+        let header = self.__read_header(&mut source, &mut location)?;
+        let payload = self.read_payload_columnar(&mut source, &header)?;
+        Ok((header, payload))
+    }
+}
+
+fn empty_column_for(data_type: &PropertyType) -> Column {
+    match *data_type {
+        PropertyType::Scalar(ScalarType::Char) => Column::Char(Vec::new()),
+        PropertyType::Scalar(ScalarType::UChar) => Column::UChar(Vec::new()),
+        PropertyType::Scalar(ScalarType::Short) => Column::Short(Vec::new()),
+        PropertyType::Scalar(ScalarType::UShort) => Column::UShort(Vec::new()),
+        PropertyType::Scalar(ScalarType::Int) => Column::Int(Vec::new()),
+        PropertyType::Scalar(ScalarType::UInt) => Column::UInt(Vec::new()),
+        PropertyType::Scalar(ScalarType::Half) => Column::Half(Vec::new()),
+        PropertyType::Scalar(ScalarType::Float) => Column::Float(Vec::new()),
+        PropertyType::Scalar(ScalarType::Double) => Column::Double(Vec::new()),
+        PropertyType::Scalar(ScalarType::Long) => Column::Long(Vec::new()),
+        PropertyType::Scalar(ScalarType::ULong) => Column::ULong(Vec::new()),
+        PropertyType::List(_, ScalarType::Char) => Column::ListChar(ListColumn::new()),
+        PropertyType::List(_, ScalarType::UChar) => Column::ListUChar(ListColumn::new()),
+        PropertyType::List(_, ScalarType::Short) => Column::ListShort(ListColumn::new()),
+        PropertyType::List(_, ScalarType::UShort) => Column::ListUShort(ListColumn::new()),
+        PropertyType::List(_, ScalarType::Int) => Column::ListInt(ListColumn::new()),
+        PropertyType::List(_, ScalarType::UInt) => Column::ListUInt(ListColumn::new()),
+        PropertyType::List(_, ScalarType::Half) => Column::ListHalf(ListColumn::new()),
+        PropertyType::List(_, ScalarType::Float) => Column::ListFloat(ListColumn::new()),
+        PropertyType::List(_, ScalarType::Double) => Column::ListDouble(ListColumn::new()),
+        PropertyType::List(_, ScalarType::Long) => Column::ListLong(ListColumn::new()),
+        PropertyType::List(_, ScalarType::ULong) => Column::ListULong(ListColumn::new()),
+    }
+}
+
+fn push_into_column(column: &mut Column, value: Property) {
+    match (column, value) {
+        (Column::Char(v), Property::Char(x)) => v.push(x),
+        (Column::UChar(v), Property::UChar(x)) => v.push(x),
+        (Column::Short(v), Property::Short(x)) => v.push(x),
+        (Column::UShort(v), Property::UShort(x)) => v.push(x),
+        (Column::Int(v), Property::Int(x)) => v.push(x),
+        (Column::UInt(v), Property::UInt(x)) => v.push(x),
+        (Column::Half(v), Property::Half(x)) => v.push(x),
+        (Column::Float(v), Property::Float(x)) => v.push(x),
+        (Column::Double(v), Property::Double(x)) => v.push(x),
+        (Column::Long(v), Property::Long(x)) => v.push(x),
+        (Column::ULong(v), Property::ULong(x)) => v.push(x),
+        (Column::ListChar(c), Property::ListChar(x)) => push_list(c, x),
+        (Column::ListUChar(c), Property::ListUChar(x)) => push_list(c, x),
+        (Column::ListShort(c), Property::ListShort(x)) => push_list(c, x),
+        (Column::ListUShort(c), Property::ListUShort(x)) => push_list(c, x),
+        (Column::ListInt(c), Property::ListInt(x)) => push_list(c, x),
+        (Column::ListUInt(c), Property::ListUInt(x)) => push_list(c, x),
+        (Column::ListHalf(c), Property::ListHalf(x)) => push_list(c, x),
+        (Column::ListFloat(c), Property::ListFloat(x)) => push_list(c, x),
+        (Column::ListDouble(c), Property::ListDouble(x)) => push_list(c, x),
+        (Column::ListLong(c), Property::ListLong(x)) => push_list(c, x),
+        (Column::ListULong(c), Property::ListULong(x)) => push_list(c, x),
+        // The property type was fixed by the header, so the column variant always matches.
+        _ => unreachable!("column/property type mismatch"),
+    }
+}
+
+fn push_list<T>(column: &mut ListColumn<T>, row: Vec<T>) {
+    column.values.extend(row);
+    column.offsets.push(column.values.len());
+}
+
+/// Returns the constant per-element byte stride of `element_def` if every property is a
+/// fixed-size scalar, or `None` if any property is a list (and thus has variable size).
+pub(crate) fn fixed_stride(element_def: &ElementDef) -> Option<usize> {
+    let mut stride = 0usize;
+    for (_, p) in &element_def.properties {
+        match &p.data_type {
+            PropertyType::Scalar(t) => stride += t.size_in_bytes(),
+            PropertyType::List(..) => return None,
+        }
+    }
+    Some(stride)
+}
+
+/// Minimum element count at which `__read_binary_payload_fixed_stride` decodes rows across
+/// multiple threads instead of sequentially. Below this, a single thread decoding the whole
+/// (small) buffer is faster than paying rayon's work-splitting overhead.
+const PARALLEL_DECODE_THRESHOLD: usize = 4096;
+
+/// Decodes one fixed-stride row into a `Property` per property, in `element_def.properties`
+/// order, honoring `endian`. `row` must be exactly as long as `fixed_stride(element_def)`
+/// reported. `Property` is `Send`/`Sync`, so this is safe to call from multiple threads over
+/// disjoint rows (see the `par_chunks` call in `__read_binary_payload_fixed_stride`).
+///
+/// Delegates to [`ScalarType::read_scalar`], the same header-driven codec `PropertyAccess`
+/// callers use for hand-rolled binary walks, so this bulk path can't silently drift from it.
+fn decode_fixed_stride_row(element_def: &ElementDef, row: &[u8], endian: Endian) -> Vec<Property> {
+    let mut offset = 0;
+    element_def
+        .properties
+        .iter()
+        .map(|(_, p)| {
+            let scalar_type = match &p.data_type {
+                PropertyType::Scalar(t) => t,
+                PropertyType::List(..) => unreachable!("fixed_stride only returns Some for scalar-only elements"),
+            };
+            let (value, size) = scalar_type.read_scalar(&row[offset..], endian);
+            offset += size;
+            value
+        })
+        .collect()
+}
+
+/// Decodes one fixed-stride row straight into `columns`, one property at a time, instead of
+/// collecting an intermediate `Vec<Property>` like [`decode_fixed_stride_row`] does for the
+/// row-oriented path. Used by [`Parser::read_payload_columnar`], where that per-row `Vec`
+/// would otherwise be one of millions of small allocations for a large mesh.
+fn decode_fixed_stride_row_into_columns(element_def: &ElementDef, row: &[u8], endian: Endian, columns: &mut ColumnarElement) {
+    let mut offset = 0;
+    for (name, p) in &element_def.properties {
+        let scalar_type = match &p.data_type {
+            PropertyType::Scalar(t) => t,
+            PropertyType::List(..) => unreachable!("fixed_stride only returns Some for scalar-only elements"),
+        };
+        let (value, size) = scalar_type.read_scalar(&row[offset..], endian);
+        offset += size;
+        push_into_column(columns.get_mut(name).unwrap(), value);
+    }
+}
+
 // //////////////////////
 // # Payload
 // //////////////////////
@@ -322,27 +704,27 @@ impl<E: PropertyAccess> Parser<E> {
     ///
     /// Make sure to read the elements in the order as they are defined in the header.
     pub fn read_payload_for_element<T: BufRead>(&self, reader: &mut T, element_def: &ElementDef, header: &Header) -> Result<Vec<E>> {
+        self.check_element_count(&element_def.name, element_def.count)?;
         let mut location = LocationTracker::new();
         match header.encoding {
             Encoding::Ascii => self.__read_ascii_payload_for_element(reader, &mut location, element_def),
-            Encoding::BinaryBigEndian => self.__read_big_endian_payload_for_element(reader, &mut location, element_def),
-            Encoding::BinaryLittleEndian => self.__read_little_endian_payload_for_element(reader, &mut location, element_def),
+            Encoding::BinaryBigEndian => self.__read_binary_payload_for_element(reader, &mut location, element_def, Endian::Big),
+            Encoding::BinaryLittleEndian => self.__read_binary_payload_for_element(reader, &mut location, element_def, Endian::Little),
         }
     }
     /// internal dispatcher based on the encoding
     fn __read_payload<T: BufRead>(&self, reader: &mut T, location: &mut LocationTracker, header: &Header) -> Result<Payload<E>> {
         let mut payload = Payload::with_capacity(header.elements.len());
 
-        // Use an iterator over `header.elements` and avoid repeated matching
-        let read_payload_for_element = match header.encoding {
-            Encoding::Ascii => Self::__read_ascii_payload_for_element,
-            Encoding::BinaryBigEndian => Self::__read_big_endian_payload_for_element,
-            Encoding::BinaryLittleEndian => Self::__read_little_endian_payload_for_element,
-        };
-
-        // Iterate over elements and process each with the selected reader
+        // Binary big/little endian share a single value-parametric reader, so only ascii
+        // needs its own branch here instead of a three-way function-pointer table.
         for (key, element_def) in &header.elements {
-            let elems = read_payload_for_element(self, reader, location, element_def)?;
+            self.check_element_count(&element_def.name, element_def.count)?;
+            let elems = match header.encoding {
+                Encoding::Ascii => self.__read_ascii_payload_for_element(reader, location, element_def)?,
+                Encoding::BinaryBigEndian => self.__read_binary_payload_for_element(reader, location, element_def, Endian::Big)?,
+                Encoding::BinaryLittleEndian => self.__read_binary_payload_for_element(reader, location, element_def, Endian::Little)?,
+            };
             payload.insert(key.clone(), elems);
         }
 
@@ -350,6 +732,175 @@ impl<E: PropertyAccess> Parser<E> {
     }
 }
 
+// //////////////////////
+// # Streaming iterator
+// //////////////////////
+impl<E: PropertyAccess> Parser<E> {
+    /// Returns an iterator that decodes one element of `element_def` at a time from the
+    /// current position of `reader`, instead of materializing the whole element list like
+    /// [`Parser::read_payload_for_element`] does.
+    ///
+    /// The iterator yields exactly `element_def.count` items, handles ASCII/LE/BE uniformly
+    /// according to `header.encoding`, and surfaces an unexpected end of file mid-stream as
+    /// an `Err` item rather than panicking. A caller filtering or transforming a large point
+    /// cloud can hold at most one `E` (plus the reader's buffer) at a time.
+    ///
+    /// The returned [`ElementIter`] also implements [`ExactSizeIterator`], reporting the
+    /// number of not-yet-decoded records via its remaining-count counter.
+    pub fn element_iter<'a, T: BufRead>(
+        &'a self,
+        reader: &'a mut T,
+        element_def: &'a ElementDef,
+        header: &Header,
+    ) -> ElementIter<'a, T, E> {
+        ElementIter {
+            parser: self,
+            reader,
+            element_def,
+            encoding: header.encoding,
+            index: 0,
+            location: LocationTracker::new(),
+        }
+    }
+}
+
+/// Iterator over the elements of a single [`ElementDef`], returned by [`Parser::element_iter`].
+pub struct ElementIter<'a, T: BufRead, E: PropertyAccess> {
+    parser: &'a Parser<E>,
+    reader: &'a mut T,
+    element_def: &'a ElementDef,
+    encoding: Encoding,
+    index: usize,
+    /// Tracks the byte offset for binary-encoded groups, so an error mid-stream reports a
+    /// [`Location::Byte`] instead of nothing.
+    location: LocationTracker,
+}
+
+impl<'a, T: BufRead, E: PropertyAccess> Iterator for ElementIter<'a, T, E> {
+    type Item = Result<E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.element_def.count {
+            return None;
+        }
+        let i = self.index;
+        self.index += 1;
+
+        let element = match self.encoding {
+            Encoding::Ascii => {
+                let mut line_str = String::new();
+                match self.reader.read_line(&mut line_str) {
+                    Ok(0) => {
+                        return Some(Err(PlyError::Io(io::Error::new(
+                            ErrorKind::UnexpectedEof,
+                            format!(
+                                "Unexpected end of file while streaming element '{}' (expected {}, got {}).",
+                                self.element_def.name, self.element_def.count, i,
+                            ),
+                        ))))
+                    }
+                    Ok(_) => {}
+                    Err(e) => return Some(Err(PlyError::Io(e))),
+                }
+                self.parser.read_ascii_element(&line_str, self.element_def)
+            }
+            Encoding::BinaryBigEndian | Encoding::BinaryLittleEndian => {
+                self.location.enter_record(&self.element_def.name, i);
+                let endian = if self.encoding == Encoding::BinaryBigEndian { Endian::Big } else { Endian::Little };
+                self.parser.__read_binary_element_located(self.reader, self.element_def, endian, &mut self.location)
+            }
+        };
+        Some(element)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.element_def.count - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T: BufRead, E: PropertyAccess> ExactSizeIterator for ElementIter<'a, T, E> {}
+
+impl<E: PropertyAccess> Parser<E> {
+    /// Returns an iterator that decodes one element at a time across *every* group declared
+    /// in `header`, in declaration order, instead of materializing the whole payload like
+    /// [`Parser::read_payload`] does.
+    ///
+    /// This is [`Parser::element_iter`] generalized over the whole file: once one group's
+    /// `count` elements have been yielded, the iterator moves on to the next group by itself.
+    /// Combined with [`PlyError::is_eof`]/[`PlyError::is_malformed`], a caller can process a
+    /// multi-gigabyte scan in constant memory and tell a cleanly truncated stream apart from
+    /// one that ran into bad data mid-record.
+    pub fn read_element_stream<'a, T: BufRead>(&'a self, reader: &'a mut T, header: &'a Header) -> PayloadStream<'a, T, E> {
+        PayloadStream {
+            parser: self,
+            reader,
+            header,
+            group_index: 0,
+            row_index: 0,
+            location: LocationTracker::new(),
+        }
+    }
+}
+
+/// Iterator over every element of every group of a [`Header`], returned by
+/// [`Parser::read_element_stream`].
+pub struct PayloadStream<'a, T: BufRead, E: PropertyAccess> {
+    parser: &'a Parser<E>,
+    reader: &'a mut T,
+    header: &'a Header,
+    group_index: usize,
+    row_index: usize,
+    /// Tracks the byte offset for binary-encoded groups, so an error mid-stream reports a
+    /// [`Location::Byte`] instead of nothing.
+    location: LocationTracker,
+}
+
+impl<'a, T: BufRead, E: PropertyAccess> Iterator for PayloadStream<'a, T, E> {
+    type Item = Result<E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (name, element_def) = self.header.elements.get_index(self.group_index)?;
+
+            if self.row_index >= element_def.count {
+                self.group_index += 1;
+                self.row_index = 0;
+                continue;
+            }
+
+            let i = self.row_index;
+            self.row_index += 1;
+
+            let element = match self.header.encoding {
+                Encoding::Ascii => {
+                    let mut line_str = String::new();
+                    match self.reader.read_line(&mut line_str) {
+                        Ok(0) => {
+                            return Some(Err(PlyError::Io(io::Error::new(
+                                ErrorKind::UnexpectedEof,
+                                format!(
+                                    "Unexpected end of file while streaming element '{}' (expected {}, got {}).",
+                                    name, element_def.count, i,
+                                ),
+                            ))))
+                        }
+                        Ok(_) => {}
+                        Err(e) => return Some(Err(PlyError::Io(e))),
+                    }
+                    self.parser.read_ascii_element(&line_str, element_def)
+                }
+                Encoding::BinaryBigEndian | Encoding::BinaryLittleEndian => {
+                    self.location.enter_record(name, i);
+                    let endian = if self.header.encoding == Encoding::BinaryBigEndian { Endian::Big } else { Endian::Little };
+                    self.parser.__read_binary_element_located(self.reader, element_def, endian, &mut self.location)
+                }
+            };
+            return Some(element);
+        }
+    }
+}
+
 /// Helper trait for high-level parsing of multiple elements.
 pub trait FromPly {
     /// Reads the entire PLY file from the reader.
@@ -363,6 +914,61 @@ impl<E: PlyRead> FromPly for Ply<E> {
     }
 }
 
+/// How the element backing one field of a `#[derive(FromPly)]` container was resolved,
+/// reported by [`FromPlyWithMask::read_ply_with_mask`].
+///
+/// Modeled on VCGLib's PLY `io_mask`: it lets a caller tell "this element's data came
+/// straight from the file" apart from "this field stayed at its `Default` because the file
+/// didn't declare the element at all," which matters when round-tripping files that
+/// shouldn't gain phantom elements they never had.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ElementPresence {
+    /// The element was found in the header under its primary `#[ply(name = ...)]`.
+    Present,
+    /// The element was found in the header, but only under one of its `#[ply(name = "a, b")]`
+    /// synonyms; the `String` is the name actually seen in the file.
+    Synonym(String),
+    /// The element wasn't declared in the header; the field was left at its `Default` value.
+    /// Only possible for a field marked `#[ply(optional)]` - an absent required element is
+    /// an error, not a `Missing` entry.
+    Missing,
+}
+
+/// Per-field element presence produced by [`FromPlyWithMask::read_ply_with_mask`], keyed by
+/// Rust field name (not PLY element name).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReadMask {
+    fields: std::collections::HashMap<String, ElementPresence>,
+}
+
+impl ReadMask {
+    /// An empty mask, built up one field at a time via [`ReadMask::insert`].
+    pub fn new() -> Self {
+        ReadMask { fields: std::collections::HashMap::new() }
+    }
+
+    /// Records how `field_name`'s element was resolved. Used by the `#[derive(FromPly)]`
+    /// expansion; most callers only need [`ReadMask::get`].
+    pub fn insert(&mut self, field_name: impl Into<String>, presence: ElementPresence) {
+        self.fields.insert(field_name.into(), presence);
+    }
+
+    /// The presence of the element mapped to `field_name`, or `None` if `field_name` isn't
+    /// one of the container's fields.
+    pub fn get(&self, field_name: &str) -> Option<&ElementPresence> {
+        self.fields.get(field_name)
+    }
+}
+
+/// Implemented alongside [`FromPly`] by `#[derive(FromPly)]`, additionally reporting which
+/// of the container's element fields were actually present in the file, synonym-matched, or
+/// defaulted because a `#[ply(optional)]` element was missing.
+pub trait FromPlyWithMask: FromPly {
+    /// Reads the file like [`FromPly::read_ply`], additionally returning a [`ReadMask`]
+    /// describing how each field's element was resolved.
+    fn read_ply_with_mask<T: Read>(reader: &mut T) -> Result<(Self, ReadMask)> where Self: Sized;
+}
+
 use std::slice::Iter;
 use std::str::FromStr;
 
@@ -381,8 +987,8 @@ impl<E: PropertyAccess> Parser<E> {
                 return Err(PlyError::Io(io::Error::new(
                     ErrorKind::UnexpectedEof,
                     format!(
-                        "Line {}: Unexpected end of file while reading element '{}' (expected {}, got {}).",
-                        location.line_index,
+                        "{}: Unexpected end of file while reading element '{}' (expected {}, got {}).",
+                        location.line_location(),
                         element_def.name,
                         element_def.count,
                         i,
@@ -434,11 +1040,15 @@ impl<E: PropertyAccess> Parser<E> {
                 ScalarType::UShort => Property::UShort(self.parse(s)?),
                 ScalarType::Int => Property::Int(self.parse(s)?),
                 ScalarType::UInt => Property::UInt(self.parse(s)?),
+                ScalarType::Half => Property::Half(self.parse(s)?),
                 ScalarType::Float => Property::Float(self.parse(s)?),
                 ScalarType::Double => Property::Double(self.parse(s)?),
+                ScalarType::Long => Property::Long(self.parse(s)?),
+                ScalarType::ULong => Property::ULong(self.parse(s)?),
             },
             PropertyType::List(_, ref scalar_type) => {
                 let count: usize = self.parse(s)?;
+                self.check_list_len(count)?;
                 match *scalar_type {
                     ScalarType::Char => Property::ListChar(self.__read_ascii_list(elem_iter, count)?),
                     ScalarType::UChar => Property::ListUChar(self.__read_ascii_list(elem_iter, count)?),
@@ -446,8 +1056,11 @@ impl<E: PropertyAccess> Parser<E> {
                     ScalarType::UShort => Property::ListUShort(self.__read_ascii_list(elem_iter, count)?),
                     ScalarType::Int => Property::ListInt(self.__read_ascii_list(elem_iter, count)?),
                     ScalarType::UInt => Property::ListUInt(self.__read_ascii_list(elem_iter, count)?),
+                    ScalarType::Half => Property::ListHalf(self.__read_ascii_list(elem_iter, count)?),
                     ScalarType::Float => Property::ListFloat(self.__read_ascii_list(elem_iter, count)?),
                     ScalarType::Double => Property::ListDouble(self.__read_ascii_list(elem_iter, count)?),
+                    ScalarType::Long => Property::ListLong(self.__read_ascii_list(elem_iter, count)?),
+                    ScalarType::ULong => Property::ListULong(self.__read_ascii_list(elem_iter, count)?),
                 }
             }
         };
@@ -465,8 +1078,13 @@ impl<E: PropertyAccess> Parser<E> {
     }
     fn __read_ascii_list<D: FromStr>(&self, elem_iter: &mut Iter<&str>, count: usize) -> Result<Vec<D>>
         where <D as FromStr>::Err: error::Error + Send + Sync + 'static {
-        let mut out: Vec<D> = Vec::with_capacity(count);
+        // See `__read_binary_list`: `count` is bounded by `check_list_len`, but we still grow
+        // incrementally rather than betting the full declared length on an untrusted file.
+        let mut out: Vec<D> = Vec::with_capacity(count.min(LIST_GROWTH_CHUNK));
         for i in 0..count {
+            if out.len() == out.capacity() {
+                out.reserve((count - out.len()).min(LIST_GROWTH_CHUNK));
+            }
             let s = match elem_iter.next() {
                 Some(s) => s,
                 None => {
@@ -501,8 +1119,21 @@ use ply::{ PropertyAccess, ElementDef, PropertyType, Property, ScalarType };
 use util::LocationTracker;
 use super::Parser;
 */
-use byteorder::{ BigEndian, LittleEndian, ReadBytesExt, ByteOrder };
+use byteorder::{ BigEndian, LittleEndian, ReadBytesExt };
 use peg;
+use crate::ply::Endian;
+
+/// Reads a single scalar value from `reader`, dispatching on `endian` at runtime instead of
+/// through a `byteorder::ByteOrder` type parameter. One call site covers both byte orders, so
+/// the surrounding binary-decode functions are compiled once instead of twice.
+macro_rules! read_endian {
+    ($reader:expr, $endian:expr, $method:ident) => {
+        match $endian {
+            Endian::Little => $reader.$method::<LittleEndian>(),
+            Endian::Big => $reader.$method::<BigEndian>(),
+        }
+    };
+}
 
 /// # Binary
 impl<E: PropertyAccess> Parser<E> {
@@ -510,79 +1141,141 @@ impl<E: PropertyAccess> Parser<E> {
     ///
     /// Make sure all elements are parsed in the order they are defined in the header.
     pub fn read_big_endian_element<T: Read>(&self, reader: &mut T, element_def: &ElementDef) -> Result<E> {
-        // Reduce coupling with ByteOrder
-        self.__read_binary_element::<T, BigEndian>(reader, element_def)
+        self.__read_binary_element(reader, element_def, Endian::Big)
     }
     /// Reads a single element as declared in `element_def`. Assumes little endian encoding.
     ///
     /// Make sure all elements are parsed in the order they are defined in the header.
     pub fn read_little_endian_element<T: Read>(&self, reader: &mut T, element_def: &ElementDef) -> Result<E> {
-        // Reduce coupling with ByteOrder
-        self.__read_binary_element::<T, LittleEndian>(reader, element_def)
+        self.__read_binary_element(reader, element_def, Endian::Little)
     }
 
-    /// internal wrapper
-    fn __read_big_endian_payload_for_element<T: Read>(&self, reader: &mut T, location: &mut LocationTracker, element_def: &ElementDef) -> Result<Vec<E>> {
-        self.__read_binary_payload_for_element::<T, BigEndian>(reader, location, element_def)
-    }
-    fn __read_little_endian_payload_for_element<T: Read>(&self, reader: &mut T, location: &mut LocationTracker, element_def: &ElementDef) -> Result<Vec<E>> {
-        self.__read_binary_payload_for_element::<T, LittleEndian>(reader, location, element_def)
-    }
+    fn __read_binary_payload_for_element<T: Read>(&self, reader: &mut T, location: &mut LocationTracker, element_def: &ElementDef, endian: Endian) -> Result<Vec<E>> {
+        // When every property is a fixed-size scalar (no lists), the whole element has a
+        // constant byte stride: read it all in one `read_exact` and decode by offset instead
+        // of issuing one small `Read` call per property.
+        if let Some(stride) = fixed_stride(element_def) {
+            return self.__read_binary_payload_fixed_stride(reader, location, element_def, stride, endian);
+        }
 
-    fn __read_binary_payload_for_element<T: Read, B: ByteOrder>(&self, reader: &mut T, location: &mut LocationTracker, element_def: &ElementDef) -> Result<Vec<E>> {
         let mut elems = Vec::<E>::with_capacity(element_def.count);
-        location.next_line();
         for i in 0..element_def.count {
-            let element = self
-                .__read_binary_element::<T, B>(reader, element_def)
-                .map_err(|e| {
-                    let is_eof = if let PlyError::Io(ref io_err) = e {
-                        io_err.kind() == ErrorKind::UnexpectedEof
-                    } else {
-                        false
-                    };
+            location.enter_record(&element_def.name, i);
+            let element = self.__read_binary_element_located(reader, element_def, endian, location)?;
+            elems.push(element);
+        }
+        Ok(elems)
+    }
 
-                    if is_eof {
-                        PlyError::Io(io::Error::new(
-                            ErrorKind::UnexpectedEof,
-                            format!(
-                                "Line {}: Unexpected end of file while reading binary element '{}' (expected {}, got {}).\n\tError: {}",
-                                location.line_index,
-                                element_def.name,
-                                element_def.count,
-                                i,
-                                e,
-                            ),
-                        ))
-                    } else {
-                        e
-                    }
-                })?;
+    /// Bulk-decodes `element_def.count` fixed-stride elements in one `read_exact`, then
+    /// slices each field out of the in-memory buffer by constant offset. Only valid when
+    /// `fixed_stride` returned `Some` for `element_def` (i.e. no list properties); results
+    /// are bit-identical to the per-property path in `__read_binary_element`.
+    ///
+    /// Rows are independent of each other, so once the whole element is buffered, decoding
+    /// each row into its `Property` values is split across threads via rayon's
+    /// `par_chunks` whenever there are enough rows to make that worthwhile (below
+    /// [`PARALLEL_DECODE_THRESHOLD`] it decodes sequentially to avoid paying thread-pool
+    /// overhead on small payloads). Assembling the decoded values into `E` stays on the
+    /// calling thread, since `PropertyAccess` implementors aren't required to be `Send`.
+    fn __read_binary_payload_fixed_stride<T: Read>(
+        &self,
+        reader: &mut T,
+        location: &mut LocationTracker,
+        element_def: &ElementDef,
+        stride: usize,
+        endian: Endian,
+    ) -> Result<Vec<E>> {
+        location.enter_record(&element_def.name, 0);
+        let mut buf = vec![0u8; element_def.count * stride];
+        reader.read_exact(&mut buf).map_err(|e| {
+            if e.kind() == ErrorKind::UnexpectedEof {
+                PlyError::Io(io::Error::new(
+                    ErrorKind::UnexpectedEof,
+                    format!(
+                        "{}: unexpected end of file while reading binary element '{}' (expected {} elements of {} bytes each).",
+                        location.byte_location(), element_def.name, element_def.count, stride,
+                    ),
+                ))
+            } else {
+                PlyError::Io(e)
+            }
+        })?;
+        location.advance_bytes(buf.len() as u64);
+
+        let rows: Vec<Vec<Property>> = if element_def.count >= PARALLEL_DECODE_THRESHOLD {
+            use rayon::prelude::*;
+            buf.par_chunks(stride)
+                .map(|row| decode_fixed_stride_row(element_def, row, endian))
+                .collect()
+        } else {
+            buf.chunks_exact(stride)
+                .map(|row| decode_fixed_stride_row(element_def, row, endian))
+                .collect()
+        };
+
+        let mut elems = Vec::<E>::with_capacity(element_def.count);
+        for values in rows {
+            let mut element = E::new();
+            for ((name, _), value) in element_def.properties.iter().zip(values) {
+                element.set_property(name, value);
+            }
             elems.push(element);
-            location.next_line();
         }
         Ok(elems)
     }
-    fn __read_binary_element<T: Read, B: ByteOrder>(&self, reader: &mut T, element_def: &ElementDef) -> Result<E> {
+
+    fn __read_binary_element<T: Read>(&self, reader: &mut T, element_def: &ElementDef, endian: Endian) -> Result<E> {
+        let mut raw_element = E::new();
+
+        for (k, p) in &element_def.properties {
+            let property = self.__read_binary_property(reader, &p.data_type, endian)?;
+            raw_element.set_property(k, property);
+        }
+        Ok(raw_element)
+    }
+
+    /// Like [`Parser::__read_binary_element`], but decodes through a byte-counting wrapper and
+    /// records which property is being read in `location`, so a failure (truncated record, bad
+    /// list length, value out of range) comes back as a [`PlyError`] annotated with a
+    /// [`Location::Byte`] - the absolute offset plus the (element, record index, property)
+    /// being decoded - instead of no location at all.
+    fn __read_binary_element_located<T: Read>(
+        &self,
+        reader: &mut T,
+        element_def: &ElementDef,
+        endian: Endian,
+        location: &mut LocationTracker,
+    ) -> Result<E> {
         let mut raw_element = E::new();
 
         for (k, p) in &element_def.properties {
-            let property = self.__read_binary_property::<T, B>(reader, &p.data_type)?;
+            location.enter_property(k);
+            let mut counting = CountingReader::new(&mut *reader);
+            let result = self.__read_binary_property(&mut counting, &p.data_type, endian);
+            // Advance by whatever was actually consumed even on failure, so the offset
+            // reported below points at (or right after) where decoding gave up, not where
+            // the property started.
+            location.advance_bytes(counting.bytes_read());
+            let property = attach_location(location.byte_location(), result)?;
             raw_element.set_property(k, property);
         }
         Ok(raw_element)
     }
-    fn __read_binary_property<T: Read, B: ByteOrder>(&self, reader: &mut T, data_type: &PropertyType) -> Result<Property> {
+    fn __read_binary_property<T: Read>(&self, reader: &mut T, data_type: &PropertyType, endian: Endian) -> Result<Property> {
         let result = match *data_type {
             PropertyType::Scalar(ref scalar_type) => match *scalar_type {
                 ScalarType::Char => Property::Char(reader.read_i8()?),
                 ScalarType::UChar => Property::UChar(reader.read_u8()?),
-                ScalarType::Short => Property::Short(reader.read_i16::<B>()?),
-                ScalarType::UShort => Property::UShort(reader.read_u16::<B>()?),
-                ScalarType::Int => Property::Int(reader.read_i32::<B>()?),
-                ScalarType::UInt => Property::UInt(reader.read_u32::<B>()?),
-                ScalarType::Float => Property::Float(reader.read_f32::<B>()?),
-                ScalarType::Double => Property::Double(reader.read_f64::<B>()?),
+                ScalarType::Short => Property::Short(read_endian!(reader, endian, read_i16)?),
+                ScalarType::UShort => Property::UShort(read_endian!(reader, endian, read_u16)?),
+                ScalarType::Int => Property::Int(read_endian!(reader, endian, read_i32)?),
+                ScalarType::UInt => Property::UInt(read_endian!(reader, endian, read_u32)?),
+                ScalarType::Half => Property::Half(half::f16::from_bits(read_endian!(reader, endian, read_u16)?)),
+                ScalarType::Float => Property::Float(read_endian!(reader, endian, read_f32)?),
+                ScalarType::Double => Property::Double(read_endian!(reader, endian, read_f64)?),
+                ScalarType::Long => Property::Long(read_endian!(reader, endian, read_i64)?),
+                ScalarType::ULong => Property::ULong(read_endian!(reader, endian, read_u64)?),
             },
             PropertyType::List(ref index_type, ref property_type) => {
                 let count: usize = match *index_type {
@@ -599,7 +1292,7 @@ impl<E: PropertyAccess> Parser<E> {
                     }
                     ScalarType::UChar => usize::from(reader.read_u8()?),
                     ScalarType::Short => {
-                        let v = reader.read_i16::<B>()?;
+                        let v = read_endian!(reader, endian, read_i16)?;
                         if v < 0 {
                             return Err(PlyError::Parse(
                                 "List length cannot be negative (i16).".to_string(),
@@ -609,9 +1302,9 @@ impl<E: PropertyAccess> Parser<E> {
                             io::Error::new(ErrorKind::InvalidInput, "List length does not fit into usize.")
                         })?
                     }
-                    ScalarType::UShort => usize::from(reader.read_u16::<B>()?),
+                    ScalarType::UShort => usize::from(read_endian!(reader, endian, read_u16)?),
                     ScalarType::Int => {
-                        let v = reader.read_i32::<B>()?;
+                        let v = read_endian!(reader, endian, read_i32)?;
                         if v < 0 {
                             return Err(PlyError::Parse(
                                 "List length cannot be negative (i32).".to_string(),
@@ -621,21 +1314,40 @@ impl<E: PropertyAccess> Parser<E> {
                             io::Error::new(ErrorKind::InvalidInput, "List length does not fit into usize.")
                         })?
                     }
-                    ScalarType::UInt => usize::try_from(reader.read_u32::<B>()?).map_err(|_| {
+                    ScalarType::UInt => usize::try_from(read_endian!(reader, endian, read_u32)?).map_err(|_| {
+                        io::Error::new(ErrorKind::InvalidInput, "List length does not fit into usize.")
+                    })?,
+                    ScalarType::Long => {
+                        let v = read_endian!(reader, endian, read_i64)?;
+                        if v < 0 {
+                            return Err(PlyError::Parse(
+                                "List length cannot be negative (i64).".to_string(),
+                            ));
+                        }
+                        usize::try_from(v).map_err(|_| {
+                            io::Error::new(ErrorKind::InvalidInput, "List length does not fit into usize.")
+                        })?
+                    }
+                    ScalarType::ULong => usize::try_from(read_endian!(reader, endian, read_u64)?).map_err(|_| {
                         io::Error::new(ErrorKind::InvalidInput, "List length does not fit into usize.")
                     })?,
+                    ScalarType::Half => return Err(PlyError::Parse("Index of list must be an integer type, float16 declared in ScalarType.".to_string())),
                     ScalarType::Float => return Err(PlyError::Parse("Index of list must be an integer type, float declared in ScalarType.".to_string())),
                     ScalarType::Double => return Err(PlyError::Parse("Index of list must be an integer type, double declared in ScalarType.".to_string())),
                 };
+                self.check_list_len(count)?;
                 match *property_type {
                     ScalarType::Char => Property::ListChar(self.__read_binary_list(reader, &|r| r.read_i8().map_err(PlyError::Io), count)?),
                     ScalarType::UChar => Property::ListUChar(self.__read_binary_list(reader, &|r| r.read_u8().map_err(PlyError::Io), count)?),
-                    ScalarType::Short => Property::ListShort(self.__read_binary_list(reader, &|r| r.read_i16::<B>().map_err(PlyError::Io), count)?),
-                    ScalarType::UShort => Property::ListUShort(self.__read_binary_list(reader, &|r| r.read_u16::<B>().map_err(PlyError::Io), count)?),
-                    ScalarType::Int => Property::ListInt(self.__read_binary_list(reader, &|r| r.read_i32::<B>().map_err(PlyError::Io), count)?),
-                    ScalarType::UInt => Property::ListUInt(self.__read_binary_list(reader, &|r| r.read_u32::<B>().map_err(PlyError::Io), count)?),
-                    ScalarType::Float => Property::ListFloat(self.__read_binary_list(reader, &|r| r.read_f32::<B>().map_err(PlyError::Io), count)?),
-                    ScalarType::Double => Property::ListDouble(self.__read_binary_list(reader, &|r| r.read_f64::<B>().map_err(PlyError::Io), count)?),
+                    ScalarType::Short => Property::ListShort(self.__read_binary_list(reader, &|r| read_endian!(r, endian, read_i16).map_err(PlyError::Io), count)?),
+                    ScalarType::UShort => Property::ListUShort(self.__read_binary_list(reader, &|r| read_endian!(r, endian, read_u16).map_err(PlyError::Io), count)?),
+                    ScalarType::Int => Property::ListInt(self.__read_binary_list(reader, &|r| read_endian!(r, endian, read_i32).map_err(PlyError::Io), count)?),
+                    ScalarType::UInt => Property::ListUInt(self.__read_binary_list(reader, &|r| read_endian!(r, endian, read_u32).map_err(PlyError::Io), count)?),
+                    ScalarType::Half => Property::ListHalf(self.__read_binary_list(reader, &|r| read_endian!(r, endian, read_u16).map_err(PlyError::Io).map(half::f16::from_bits), count)?),
+                    ScalarType::Float => Property::ListFloat(self.__read_binary_list(reader, &|r| read_endian!(r, endian, read_f32).map_err(PlyError::Io), count)?),
+                    ScalarType::Double => Property::ListDouble(self.__read_binary_list(reader, &|r| read_endian!(r, endian, read_f64).map_err(PlyError::Io), count)?),
+                    ScalarType::Long => Property::ListLong(self.__read_binary_list(reader, &|r| read_endian!(r, endian, read_i64).map_err(PlyError::Io), count)?),
+                    ScalarType::ULong => Property::ListULong(self.__read_binary_list(reader, &|r| read_endian!(r, endian, read_u64).map_err(PlyError::Io), count)?),
                 }
             }
         };
@@ -643,8 +1355,14 @@ impl<E: PropertyAccess> Parser<E> {
     }
     fn __read_binary_list<T: Read, D: FromStr>(&self, reader: &mut T, read_from: &dyn Fn(&mut T) -> Result<D>, count: usize) -> Result<Vec<D>>
         where <D as FromStr>::Err: error::Error + Send + Sync + 'static {
-        let mut list = Vec::<D>::with_capacity(count);
+        // `count` has already passed `check_list_len`, but it's still a value taken straight
+        // from the file: reserve in bounded chunks as elements actually arrive instead of
+        // eagerly committing to the full declared length up front.
+        let mut list = Vec::<D>::with_capacity(count.min(LIST_GROWTH_CHUNK));
         for i in 0..count {
+            if list.len() == list.capacity() {
+                list.reserve((count - list.len()).min(LIST_GROWTH_CHUNK));
+            }
             let value : D = match read_from(reader) {
                 Err(e) => return Err(PlyError::Parse(
                     format!("Couldn't find a list element at index {}.\n\tError: {:?}", i, e)
@@ -664,7 +1382,7 @@ mod tests {
     use super::grammar as g;
     use super::Line;
     use crate::parser::Parser;
-    use crate::ply::{ DefaultElement, PropertyDef, Version, Encoding, ScalarType, PropertyType, ElementDef, KeyMap, Addable };
+    use crate::ply::{ DefaultElement, Header, PropertyDef, Version, Encoding, ScalarType, PropertyType, ElementDef, KeyMap, Addable };
     macro_rules! assert_ok {
         ($e:expr) => (
             match $e {
@@ -749,6 +1467,363 @@ mod tests {
         assert!(properties.is_ok(), "{}", format!("error: {:?}", properties));
     }
     #[test]
+    fn read_property_long_ulong_ascii() {
+        let p = Parser::<DefaultElement>::new();
+        let txt = "-9223372036854775808 18446744073709551615";
+        let mut prop = KeyMap::<PropertyDef>::new();
+        prop.add(PropertyDef::new("a".to_string(), PropertyType::Scalar(ScalarType::Long)));
+        prop.add(PropertyDef::new("b".to_string(), PropertyType::Scalar(ScalarType::ULong)));
+        let mut elem_def = ElementDef::new("dummy".to_string());
+        elem_def.properties = prop;
+
+        let element = p.read_ascii_element(txt, &elem_def).unwrap();
+        assert_eq!(element.get_long("a"), Some(i64::MIN));
+        assert_eq!(element.get_ulong("b"), Some(u64::MAX));
+    }
+    #[test]
+    fn read_property_long_ulong_binary_little_endian() {
+        let p = Parser::<DefaultElement>::new();
+        let mut prop = KeyMap::<PropertyDef>::new();
+        prop.add(PropertyDef::new("a".to_string(), PropertyType::Scalar(ScalarType::Long)));
+        prop.add(PropertyDef::new("b".to_string(), PropertyType::Scalar(ScalarType::ULong)));
+        let mut elem_def = ElementDef::new("dummy".to_string());
+        elem_def.properties = prop;
+
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&(-42i64).to_le_bytes());
+        bytes.extend_from_slice(&42u64.to_le_bytes());
+        let mut reader = bytes.as_slice();
+
+        let element = p.read_little_endian_element(&mut reader, &elem_def).unwrap();
+        assert_eq!(element.get_long("a"), Some(-42));
+        assert_eq!(element.get_ulong("b"), Some(42));
+    }
+    #[test]
+    fn read_big_and_little_endian_elements_agree_on_value() {
+        let p = Parser::<DefaultElement>::new();
+        let mut prop = KeyMap::<PropertyDef>::new();
+        prop.add(PropertyDef::new("x".to_string(), PropertyType::Scalar(ScalarType::Int)));
+        let mut elem_def = ElementDef::new("dummy".to_string());
+        elem_def.properties = prop;
+
+        let mut le_bytes = (-7i32).to_le_bytes().to_vec();
+        let mut le_reader = le_bytes.as_mut_slice();
+        let le_element = p.read_little_endian_element(&mut le_reader, &elem_def).unwrap();
+
+        let mut be_bytes = (-7i32).to_be_bytes().to_vec();
+        let mut be_reader = be_bytes.as_mut_slice();
+        let be_element = p.read_big_endian_element(&mut be_reader, &elem_def).unwrap();
+
+        assert_eq!(le_element.get_int("x"), be_element.get_int("x"));
+        assert_eq!(le_element.get_int("x"), Some(-7));
+    }
+    #[test]
+    fn binary_list_length_over_cap_is_rejected_without_allocating() {
+        let p = Parser::<DefaultElement>::new().with_max_list_len(4);
+        let mut prop = KeyMap::<PropertyDef>::new();
+        prop.add(PropertyDef::new(
+            "vertex_index".to_string(),
+            PropertyType::List(ScalarType::UChar, ScalarType::Int),
+        ));
+        let mut elem_def = ElementDef::new("face".to_string());
+        elem_def.properties = prop;
+
+        // Declares a list of 200 ints but doesn't actually supply them: if the cap weren't
+        // enforced before allocating, this would fail with an EOF instead of the cap error.
+        let mut bytes = vec![200u8];
+        let mut reader = bytes.as_mut_slice();
+        let err = p.read_little_endian_element(&mut reader, &elem_def).unwrap_err();
+        assert!(err.to_string().contains("exceeds the configured limit"));
+    }
+    #[test]
+    fn binary_list_length_within_cap_is_accepted() {
+        let p = Parser::<DefaultElement>::new().with_max_list_len(4);
+        let mut prop = KeyMap::<PropertyDef>::new();
+        prop.add(PropertyDef::new(
+            "vertex_index".to_string(),
+            PropertyType::List(ScalarType::UChar, ScalarType::Int),
+        ));
+        let mut elem_def = ElementDef::new("face".to_string());
+        elem_def.properties = prop;
+
+        let mut bytes = vec![3u8];
+        for v in [1i32, 2, 3] {
+            bytes.extend_from_slice(&v.to_le_bytes());
+        }
+        let mut reader = bytes.as_mut_slice();
+        let element = p.read_little_endian_element(&mut reader, &elem_def).unwrap();
+        assert_eq!(element.get_list_int("vertex_index").as_deref(), Some(&[1, 2, 3][..]));
+    }
+    #[test]
+    fn element_count_over_cap_is_rejected_before_reading_payload() {
+        let txt = "ply\n\
+        format ascii 1.0\n\
+        element point 1000\n\
+        property int x\n\
+        end_header\n";
+        let mut header_bytes = txt.as_bytes();
+        let p = Parser::<DefaultElement>::new().with_max_total_elements(10);
+        let header = p.read_header(&mut header_bytes).unwrap();
+
+        let payload_bytes = "0\n".repeat(1000);
+        let mut reader = payload_bytes.as_bytes();
+        let err = p.read_payload(&mut reader, &header).unwrap_err();
+        assert!(err.to_string().contains("exceeds the configured limit"));
+    }
+    #[test]
+    fn read_payload_fixed_stride_matches_per_property_path() {
+        let txt = "ply\n\
+        format binary_little_endian 1.0\n\
+        element point 3\n\
+        property int x\n\
+        property float y\n\
+        end_header\n";
+        let mut header_bytes = txt.as_bytes();
+        let p = Parser::<DefaultElement>::new();
+        let header = p.read_header(&mut header_bytes).unwrap();
+
+        let mut payload_bytes = Vec::new();
+        for (x, y) in [(1i32, 1.5f32), (-2, 2.5), (3, -3.5)] {
+            payload_bytes.extend_from_slice(&x.to_le_bytes());
+            payload_bytes.extend_from_slice(&y.to_le_bytes());
+        }
+        let mut reader = payload_bytes.as_slice();
+        let payload = p.read_payload(&mut reader, &header).unwrap();
+
+        let points = &payload["point"];
+        assert_eq!(points.len(), 3);
+        assert_eq!(points[0].get_int("x"), Some(1));
+        assert_eq!(points[0].get_float("y"), Some(1.5));
+        assert_eq!(points[1].get_int("x"), Some(-2));
+        assert_eq!(points[2].get_float("y"), Some(-3.5));
+    }
+    #[test]
+    fn read_payload_fixed_stride_parallel_path_matches_sequential() {
+        // Enough rows to cross PARALLEL_DECODE_THRESHOLD and take the rayon `par_chunks` path.
+        let count = super::PARALLEL_DECODE_THRESHOLD + 10;
+        let txt = format!(
+            "ply\nformat binary_little_endian 1.0\nelement point {}\nproperty int x\nproperty float y\nend_header\n",
+            count
+        );
+        let mut header_bytes = txt.as_bytes();
+        let p = Parser::<DefaultElement>::new();
+        let header = p.read_header(&mut header_bytes).unwrap();
+
+        let mut payload_bytes = Vec::new();
+        for i in 0..count {
+            payload_bytes.extend_from_slice(&(i as i32).to_le_bytes());
+            payload_bytes.extend_from_slice(&(i as f32).to_le_bytes());
+        }
+        let mut reader = payload_bytes.as_slice();
+        let payload = p.read_payload(&mut reader, &header).unwrap();
+
+        let points = &payload["point"];
+        assert_eq!(points.len(), count);
+        for i in 0..count {
+            assert_eq!(points[i].get_int("x"), Some(i as i32));
+            assert_eq!(points[i].get_float("y"), Some(i as f32));
+        }
+    }
+    #[test]
+    fn read_payload_fixed_stride_reports_unexpected_eof() {
+        let txt = "ply\n\
+        format binary_little_endian 1.0\n\
+        element point 2\n\
+        property int x\n\
+        end_header\n";
+        let mut header_bytes = txt.as_bytes();
+        let p = Parser::<DefaultElement>::new();
+        let header = p.read_header(&mut header_bytes).unwrap();
+
+        // Only one element's worth of bytes for a payload that declares two.
+        let mut payload_bytes = 1i32.to_le_bytes().to_vec();
+        let mut reader = payload_bytes.as_mut_slice();
+        assert_err!(p.read_payload(&mut reader, &header));
+    }
+    #[test]
+    fn read_payload_columnar_bulk_path_matches_per_property_path() {
+        use crate::ply::columnar::ColumnarAccess;
+
+        let txt = "ply\n\
+        format binary_little_endian 1.0\n\
+        element point 3\n\
+        property int x\n\
+        property float y\n\
+        end_header\n";
+        let mut header_bytes = txt.as_bytes();
+        let p = Parser::<DefaultElement>::new();
+        let header = p.read_header(&mut header_bytes).unwrap();
+
+        let mut payload_bytes = Vec::new();
+        for (x, y) in [(1i32, 1.5f32), (-2, 2.5), (3, -3.5)] {
+            payload_bytes.extend_from_slice(&x.to_le_bytes());
+            payload_bytes.extend_from_slice(&y.to_le_bytes());
+        }
+        let mut reader = payload_bytes.as_slice();
+        let columnar = p.read_payload_columnar(&mut reader, &header).unwrap();
+
+        assert_eq!(columnar.column_i32("point", "x"), Some(&[1, -2, 3][..]));
+        assert_eq!(columnar.column_f32("point", "y"), Some(&[1.5, 2.5, -3.5][..]));
+    }
+    #[test]
+    fn element_iter_yields_all_rows() {
+        let txt = "ply\n\
+        format ascii 1.0\n\
+        element point 2\n\
+        property int x\n\
+        property int y\n\
+        end_header\n\
+        -7 5\n\
+        2 4\n";
+        let mut bytes = txt.as_bytes();
+        let p = Parser::<DefaultElement>::new();
+        let header = p.read_header(&mut bytes).unwrap();
+        let element_def = &header.elements["point"];
+
+        let rows: Vec<_> = p.element_iter(&mut bytes, element_def, &header).collect();
+        assert_eq!(rows.len(), 2);
+        assert!(rows[0].is_ok());
+        assert_eq!(rows[0].as_ref().unwrap().get_int("x"), Some(-7));
+        assert!(rows[1].is_ok());
+        assert_eq!(rows[1].as_ref().unwrap().get_int("x"), Some(2));
+    }
+    #[test]
+    fn element_iter_reports_unexpected_eof() {
+        let txt = "ply\n\
+        format ascii 1.0\n\
+        element point 2\n\
+        property int x\n\
+        end_header\n\
+        1\n";
+        let mut bytes = txt.as_bytes();
+        let p = Parser::<DefaultElement>::new();
+        let header = p.read_header(&mut bytes).unwrap();
+        let element_def = &header.elements["point"];
+
+        let mut it = p.element_iter(&mut bytes, element_def, &header);
+        assert!(it.next().unwrap().is_ok());
+        assert!(it.next().unwrap().is_err());
+        assert!(it.next().is_none());
+    }
+    #[test]
+    fn element_iter_size_hint_tracks_remaining_count() {
+        let txt = "ply\n\
+        format ascii 1.0\n\
+        element point 3\n\
+        property int x\n\
+        end_header\n\
+        1\n\
+        2\n\
+        3\n";
+        let mut bytes = txt.as_bytes();
+        let p = Parser::<DefaultElement>::new();
+        let header = p.read_header(&mut bytes).unwrap();
+        let element_def = &header.elements["point"];
+
+        let mut it = p.element_iter(&mut bytes, element_def, &header);
+        assert_eq!(it.len(), 3);
+        it.next();
+        assert_eq!(it.len(), 2);
+        it.next();
+        it.next();
+        assert_eq!(it.len(), 0);
+        assert!(it.next().is_none());
+    }
+    #[test]
+    fn read_element_stream_crosses_group_boundaries() {
+        let txt = "ply\n\
+        format ascii 1.0\n\
+        element point 2\n\
+        property int x\n\
+        element face 1\n\
+        property int y\n\
+        end_header\n\
+        1\n\
+        2\n\
+        9\n";
+        let mut bytes = txt.as_bytes();
+        let p = Parser::<DefaultElement>::new();
+        let header = p.read_header(&mut bytes).unwrap();
+
+        let rows: Vec<_> = p
+            .read_element_stream(&mut bytes, &header)
+            .collect::<crate::errors::PlyResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0].get_int("x"), Some(1));
+        assert_eq!(rows[1].get_int("x"), Some(2));
+        assert_eq!(rows[2].get_int("y"), Some(9));
+    }
+    #[test]
+    fn read_element_stream_reports_unexpected_eof() {
+        let txt = "ply\n\
+        format ascii 1.0\n\
+        element point 2\n\
+        property int x\n\
+        end_header\n\
+        1\n";
+        let mut bytes = txt.as_bytes();
+        let p = Parser::<DefaultElement>::new();
+        let header = p.read_header(&mut bytes).unwrap();
+
+        let mut it = p.read_element_stream(&mut bytes, &header);
+        assert!(it.next().unwrap().is_ok());
+        let err = it.next().unwrap().unwrap_err();
+        assert!(err.is_eof());
+        assert!(!err.is_malformed());
+        assert!(it.next().is_none());
+    }
+    #[test]
+    fn binary_payload_error_reports_byte_offset_and_property() {
+        let mut prop = KeyMap::<PropertyDef>::new();
+        prop.add(PropertyDef::new(
+            "vertex_index".to_string(),
+            PropertyType::List(ScalarType::UChar, ScalarType::Int),
+        ));
+        let mut elem_def = ElementDef::new("face".to_string());
+        elem_def.count = 2;
+        elem_def.properties = prop;
+
+        let mut header_elements = KeyMap::<ElementDef>::new();
+        header_elements.add(elem_def);
+        let header = Header {
+            encoding: Encoding::BinaryLittleEndian,
+            version: Version { major: 1, minor: 0 },
+            obj_infos: Vec::new(),
+            comments: Vec::new(),
+            elements: header_elements,
+        };
+
+        // First face (3 ints) is complete; the second declares 3 ints but only supplies 1.
+        let mut bytes = vec![3u8];
+        bytes.extend_from_slice(&1i32.to_le_bytes());
+        bytes.extend_from_slice(&2i32.to_le_bytes());
+        bytes.extend_from_slice(&3i32.to_le_bytes());
+        bytes.push(3u8);
+        bytes.extend_from_slice(&4i32.to_le_bytes());
+        let mut reader = bytes.as_slice();
+
+        let p = Parser::<DefaultElement>::new();
+        let err = p.read_payload(&mut reader, &header).unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("vertex_index"));
+        assert!(message.contains("face"));
+        assert!(message.contains("#1"));
+        assert!(message.contains("byte offset"));
+    }
+    #[test]
+    fn ply_error_classifies_malformed_vs_eof() {
+        use crate::errors::PlyError;
+
+        let eof = PlyError::Io(std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "short read"));
+        assert!(eof.is_eof());
+        assert!(!eof.is_malformed());
+
+        let malformed = PlyError::Parse("bad list length".to_string());
+        assert!(malformed.is_malformed());
+        assert!(!malformed.is_eof());
+    }
+    #[test]
     fn magic_number_ok() {
         assert_ok!(g::magic_number("ply"));
     }
@@ -48,8 +48,14 @@ rule scalar() -> ScalarType
 	/ "uint"    { ScalarType::UInt }
 	/ "float32" { ScalarType::Float }
 	/ "float64" { ScalarType::Double }
+	/ "float16" { ScalarType::Half }
+	/ "half"    { ScalarType::Half }
 	/ "float"   { ScalarType::Float }
 	/ "double"  { ScalarType::Double }
+	/ "int64"   { ScalarType::Long }
+	/ "long"    { ScalarType::Long }
+	/ "uint64"  { ScalarType::ULong }
+	/ "ulong"   { ScalarType::ULong }
 
 rule data_type() -> PropertyType
 	= s:scalar()   { PropertyType::Scalar(s) }
@@ -0,0 +1,179 @@
+//! Struct-of-arrays payload representation.
+//!
+//! [`Payload`](super::Payload) stores one boxed `E` per row, which is a poor fit for
+//! million-vertex point clouds where every property is the same scalar type and callers
+//! just want a contiguous `&[f32]` to hand to a GPU buffer or `ndarray`. [`ColumnarPayload`]
+//! instead keeps one contiguous [`Column`] per property, mirroring how columnar formats
+//! (e.g. Parquet) lay out typed column buffers rather than row objects.
+
+use super::KeyMap;
+use half::f16;
+
+/// A single contiguous column of property values.
+///
+/// Scalar properties store one value per row. List properties store a flat `values`
+/// buffer plus an `offsets` array of length `row_count + 1`, where `offsets[i]..offsets[i + 1]`
+/// delimits the `i`-th row's list. This keeps variable-length lists cache-friendly instead
+/// of boxing a `Vec` per row.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Column {
+    /// Column of signed 8-bit integers.
+    Char(Vec<i8>),
+    /// Column of unsigned 8-bit integers.
+    UChar(Vec<u8>),
+    /// Column of signed 16-bit integers.
+    Short(Vec<i16>),
+    /// Column of unsigned 16-bit integers.
+    UShort(Vec<u16>),
+    /// Column of signed 32-bit integers.
+    Int(Vec<i32>),
+    /// Column of unsigned 32-bit integers.
+    UInt(Vec<u32>),
+    /// Column of 16-bit (half-precision) floating point values.
+    Half(Vec<f16>),
+    /// Column of 32-bit floating point values.
+    Float(Vec<f32>),
+    /// Column of 64-bit floating point values.
+    Double(Vec<f64>),
+    /// Column of signed 64-bit integers.
+    Long(Vec<i64>),
+    /// Column of unsigned 64-bit integers.
+    ULong(Vec<u64>),
+    /// Column of signed 8-bit integer lists, flattened with row offsets.
+    ListChar(ListColumn<i8>),
+    /// Column of unsigned 8-bit integer lists, flattened with row offsets.
+    ListUChar(ListColumn<u8>),
+    /// Column of signed 16-bit integer lists, flattened with row offsets.
+    ListShort(ListColumn<i16>),
+    /// Column of unsigned 16-bit integer lists, flattened with row offsets.
+    ListUShort(ListColumn<u16>),
+    /// Column of signed 32-bit integer lists, flattened with row offsets.
+    ListInt(ListColumn<i32>),
+    /// Column of unsigned 32-bit integer lists, flattened with row offsets.
+    ListUInt(ListColumn<u32>),
+    /// Column of 16-bit (half-precision) floating point lists, flattened with row offsets.
+    ListHalf(ListColumn<f16>),
+    /// Column of 32-bit floating point lists, flattened with row offsets.
+    ListFloat(ListColumn<f32>),
+    /// Column of 64-bit floating point lists, flattened with row offsets.
+    ListDouble(ListColumn<f64>),
+    /// Column of signed 64-bit integer lists, flattened with row offsets.
+    ListLong(ListColumn<i64>),
+    /// Column of unsigned 64-bit integer lists, flattened with row offsets.
+    ListULong(ListColumn<u64>),
+}
+
+/// A flattened list column: a single `values` buffer shared by all rows, sliced by `offsets`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListColumn<T> {
+    /// Concatenated list elements for every row, in row order.
+    pub values: Vec<T>,
+    /// Row `i`'s list occupies `values[offsets[i]..offsets[i + 1]]`.
+    ///
+    /// Always has `row_count + 1` entries, starting at `0`.
+    pub offsets: Vec<usize>,
+}
+
+impl<T> ListColumn<T> {
+    /// Creates an empty list column with a single leading zero offset.
+    pub fn new() -> Self {
+        ListColumn { values: Vec::new(), offsets: vec![0] }
+    }
+
+    /// Returns the slice of values belonging to row `index`, if it exists.
+    pub fn row(&self, index: usize) -> Option<&[T]> {
+        let start = *self.offsets.get(index)?;
+        let end = *self.offsets.get(index + 1)?;
+        Some(&self.values[start..end])
+    }
+
+    /// Number of rows stored in this column.
+    pub fn len(&self) -> usize {
+        self.offsets.len().saturating_sub(1)
+    }
+
+    /// Returns `true` if this column has no rows.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> Default for ListColumn<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Columns belonging to a single element, keyed by property name.
+pub type ColumnarElement = KeyMap<Column>;
+
+/// Struct-of-arrays payload: one [`ColumnarElement`] per element, keyed by element name.
+///
+/// Produced by [`crate::parser::Parser::read_payload_columnar`] as a zero-copy-friendly
+/// alternative to [`super::Payload`] for bulk numeric access.
+pub type ColumnarPayload = KeyMap<ColumnarElement>;
+
+/// Convenience accessors for pulling typed column slices out of a [`ColumnarPayload`].
+pub trait ColumnarAccess {
+    /// Returns the `float` column of `property` within `element`, if both exist and match.
+    fn column_f32(&self, element: &str, property: &str) -> Option<&[f32]>;
+    /// Returns the `double` column of `property` within `element`, if both exist and match.
+    fn column_f64(&self, element: &str, property: &str) -> Option<&[f64]>;
+    /// Returns the `int` column of `property` within `element`, if both exist and match.
+    fn column_i32(&self, element: &str, property: &str) -> Option<&[i32]>;
+    /// Returns the `uint` column of `property` within `element`, if both exist and match.
+    fn column_u32(&self, element: &str, property: &str) -> Option<&[u32]>;
+}
+
+macro_rules! column_accessor {
+    ($name:ident, $variant:ident, $t:ty) => {
+        fn $name(&self, element: &str, property: &str) -> Option<&[$t]> {
+            match self.get(element)?.get(property)? {
+                Column::$variant(v) => Some(v.as_slice()),
+                _ => None,
+            }
+        }
+    };
+}
+
+impl ColumnarAccess for ColumnarPayload {
+    column_accessor!(column_f32, Float, f32);
+    column_accessor!(column_f64, Double, f64);
+    column_accessor!(column_i32, Int, i32);
+    column_accessor!(column_u32, UInt, u32);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn list_column_row_slicing() {
+        let col = ListColumn { values: vec![1, 2, 3, 4, 5], offsets: vec![0, 2, 2, 5] };
+        assert_eq!(col.row(0), Some(&[1, 2][..]));
+        assert_eq!(col.row(1), Some(&[][..]));
+        assert_eq!(col.row(2), Some(&[3, 4, 5][..]));
+        assert_eq!(col.row(3), None);
+        assert_eq!(col.len(), 3);
+    }
+
+    #[test]
+    fn list_column_default_is_empty() {
+        let col = ListColumn::<i32>::new();
+        assert!(col.is_empty());
+        assert_eq!(col.offsets, vec![0]);
+    }
+
+    #[test]
+    fn columnar_access_reads_typed_columns() {
+        let mut payload: ColumnarPayload = ColumnarPayload::new();
+        let mut vertex: ColumnarElement = ColumnarElement::new();
+        vertex.insert("x".to_string(), Column::Float(vec![1.0, 2.0, 3.0]));
+        payload.insert("vertex".to_string(), vertex);
+
+        assert_eq!(payload.column_f32("vertex", "x"), Some(&[1.0, 2.0, 3.0][..]));
+        assert_eq!(payload.column_f64("vertex", "x"), None);
+        assert_eq!(payload.column_f32("vertex", "y"), None);
+        assert_eq!(payload.column_f32("face", "x"), None);
+    }
+}
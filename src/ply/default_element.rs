@@ -6,6 +6,7 @@
 use super::KeyMap;
 use super::Property;
 use super::PropertyAccess;
+use half::f16;
 use std::borrow::Cow;
 
 /// Ready to use data-structure for all kind of element definitions.
@@ -64,6 +65,12 @@ impl PropertyAccess for DefaultElement {
             _ => None,
         }
     }
+    fn get_half(&self, key: &str) -> Option<f16> {
+        match *get!(self.get(key)) {
+            Property::Half(x) => Some(x),
+            _ => None,
+        }
+    }
     fn get_float(&self, key: &str) -> Option<f32> {
         match *get!(self.get(key)) {
             Property::Float(x) => Some(x),
@@ -76,6 +83,18 @@ impl PropertyAccess for DefaultElement {
             _ => None,
         }
     }
+    fn get_long(&self, key: &str) -> Option<i64> {
+        match *get!(self.get(key)) {
+            Property::Long(x) => Some(x),
+            _ => None,
+        }
+    }
+    fn get_ulong(&self, key: &str) -> Option<u64> {
+        match *get!(self.get(key)) {
+            Property::ULong(x) => Some(x),
+            _ => None,
+        }
+    }
     fn get_list_char(&self, key: &str) -> Option<Cow<'_, [i8]>> {
         match *get!(self.get(key)) {
             Property::ListChar(ref x) => Some(Cow::Borrowed(x)),
@@ -112,6 +131,12 @@ impl PropertyAccess for DefaultElement {
             _ => None,
         }
     }
+    fn get_list_half(&self, key: &str) -> Option<Cow<'_, [f16]>> {
+        match *get!(self.get(key)) {
+            Property::ListHalf(ref x) => Some(Cow::Borrowed(x)),
+            _ => None,
+        }
+    }
     fn get_list_float(&self, key: &str) -> Option<Cow<'_, [f32]>> {
         match *get!(self.get(key)) {
             Property::ListFloat(ref x) => Some(Cow::Borrowed(x)),
@@ -124,4 +149,16 @@ impl PropertyAccess for DefaultElement {
             _ => None,
         }
     }
+    fn get_list_long(&self, key: &str) -> Option<Cow<'_, [i64]>> {
+        match *get!(self.get(key)) {
+            Property::ListLong(ref x) => Some(Cow::Borrowed(x)),
+            _ => None,
+        }
+    }
+    fn get_list_ulong(&self, key: &str) -> Option<Cow<'_, [u64]>> {
+        match *get!(self.get(key)) {
+            Property::ListULong(ref x) => Some(Cow::Borrowed(x)),
+            _ => None,
+        }
+    }
 }
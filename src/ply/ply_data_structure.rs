@@ -48,6 +48,17 @@ impl<E: PropertyAccess> Ply<E> {
             payload: Payload::new(),
         }
     }
+
+    /// Changes the encoding this `Ply` will be written with, without touching the payload.
+    ///
+    /// Because the payload is already decoded into `E`, switching `target` and writing the
+    /// result back out is a lossless conversion between ascii and binary PLY: element/property
+    /// order, comments, obj_info, and list length/index types are all preserved exactly since
+    /// they live on `header`, not on the encoding. For converting a file you haven't fully
+    /// parsed into memory, see [`crate::transcode::transcode`].
+    pub fn transcode(&mut self, target: Encoding) {
+        self.header.encoding = target;
+    }
 }
 
 // Header Types
@@ -92,6 +103,25 @@ impl Header {
     }
 }
 
+/// Captures a parsed [`Header`] verbatim so a `#[derive(FromPly)]`/`#[derive(ToPly)]` container
+/// can round-trip it with perfect fidelity instead of rebuilding one purely from Rust field
+/// types.
+///
+/// Add a field of this type annotated `#[ply(header)]`: the generated `FromPly` reader
+/// populates it with a clone of the header it just parsed, and the generated `ToPly` writer -
+/// when the field holds `Some` header, i.e. the value was read from a file rather than built in
+/// code - reuses its comments, `obj_info` lines, element declaration order, and each property's
+/// original on-disk [`PropertyType`](crate::ply::PropertyType) instead of recomputing them from
+/// `WriteSchema`, refreshing only each element's `count` and the chosen `encoding`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PlyHeaderMeta(pub Option<Header>);
+
+impl From<Header> for PlyHeaderMeta {
+    fn from(header: Header) -> Self {
+        PlyHeaderMeta(Some(header))
+    }
+}
+
 /// Alias to give object information an explicit type.
 pub type ObjInfo = String;
 
@@ -292,4 +322,13 @@ mod tests {
         let ply = Ply::<MockElement>::default();
         assert_eq!(ply, Ply::<MockElement>::new());
     }
+
+    #[test]
+    fn test_ply_transcode_switches_encoding_only() {
+        let mut ply = Ply::<MockElement>::new();
+        ply.header.encoding = Encoding::Ascii;
+        ply.transcode(Encoding::BinaryLittleEndian);
+        assert_eq!(ply.header.encoding, Encoding::BinaryLittleEndian);
+        assert!(ply.payload.is_empty());
+    }
 }
\ No newline at end of file
@@ -2,10 +2,17 @@
 //!
 //! PLY payload values are dynamically typed according to the header. This module
 //! provides:
-//! - [`Property`] as an enum covering all supported scalar and list payload values.
+//! - [`Property`] as an enum covering all supported scalar and list payload values, with
+//!   [`Property::as_i64`]/[`as_u64`](Property::as_u64)/[`as_f64`](Property::as_f64) and
+//!   fallible [`Property::try_as`]/[`Property::iter_as`] to coerce between them.
 //! - [`ScalarType`] / [`PropertyType`] to describe the types declared in the header.
 //! - [`PropertyAccess`] to allow parsing/writing payloads into custom data structures.
 
+use half::f16;
+
+use crate::errors::{PlyError, PlyResult, SchemaError};
+use crate::ply::ElementDef;
+
 /// Scalar type used to encode properties in the payload.
 ///
 /// For the translation to rust types, see individual documentation.
@@ -24,10 +31,258 @@ pub enum ScalarType {
     Int,
     /// Unsigned 32 bit integer, rust: `u32`.
     UInt,
+    /// 16 bit (half-precision) floating point number, rust: `half::f16`. PLY spelling:
+    /// `float16`/`half` (not part of the original PLY standard, but accepted/emitted the
+    /// same way `int64`/`uint64` extend it for 64 bit integers).
+    Half,
     /// 32 bit floating point number, rust: `f32`.
     Float,
     /// 64 bit floating point number, rust: `f64`.
     Double,
+    /// Signed 64 bit integer, rust: `i64`. PLY spelling: `int64`/`long`.
+    Long,
+    /// Unsigned 64 bit integer, rust: `u64`. PLY spelling: `uint64`/`ulong`.
+    ULong,
+}
+
+impl ScalarType {
+    /// Returns `true` if this is an integer type (signed or unsigned).
+    pub fn is_integer(&self) -> bool {
+        !matches!(self, ScalarType::Half | ScalarType::Float | ScalarType::Double)
+    }
+
+    /// Returns `true` if this is a signed integer type. `false` for unsigned integers and
+    /// for floating-point types.
+    pub fn is_signed(&self) -> bool {
+        matches!(
+            self,
+            ScalarType::Char | ScalarType::Short | ScalarType::Int | ScalarType::Long
+        )
+    }
+
+    /// Returns `true` if this is a floating-point type.
+    pub fn is_float(&self) -> bool {
+        matches!(self, ScalarType::Half | ScalarType::Float | ScalarType::Double)
+    }
+
+    /// Returns the width of this type in bits.
+    pub fn bit_width(&self) -> u32 {
+        self.size_in_bytes() as u32 * 8
+    }
+
+    /// Returns the inclusive `(min, max)` value range representable by this type, as
+    /// `Property` values of the same variant. Returns `None` for floating-point types,
+    /// which have no meaningful smallest/largest finite representation for this purpose.
+    pub fn value_range(&self) -> Option<(Property, Property)> {
+        Some(match self {
+            ScalarType::Char => (Property::Char(i8::MIN), Property::Char(i8::MAX)),
+            ScalarType::UChar => (Property::UChar(u8::MIN), Property::UChar(u8::MAX)),
+            ScalarType::Short => (Property::Short(i16::MIN), Property::Short(i16::MAX)),
+            ScalarType::UShort => (Property::UShort(u16::MIN), Property::UShort(u16::MAX)),
+            ScalarType::Int => (Property::Int(i32::MIN), Property::Int(i32::MAX)),
+            ScalarType::UInt => (Property::UInt(u32::MIN), Property::UInt(u32::MAX)),
+            ScalarType::Long => (Property::Long(i64::MIN), Property::Long(i64::MAX)),
+            ScalarType::ULong => (Property::ULong(u64::MIN), Property::ULong(u64::MAX)),
+            ScalarType::Half | ScalarType::Float | ScalarType::Double => return None,
+        })
+    }
+
+    /// Returns the integer `ScalarType` with the given bit width and signedness, or `None`
+    /// if no PLY scalar type matches (PLY only has 8/16/32/64-bit integers).
+    pub fn from_bit_width_signed(bits: u32, signed: bool) -> Option<ScalarType> {
+        Some(match (bits, signed) {
+            (8, true) => ScalarType::Char,
+            (8, false) => ScalarType::UChar,
+            (16, true) => ScalarType::Short,
+            (16, false) => ScalarType::UShort,
+            (32, true) => ScalarType::Int,
+            (32, false) => ScalarType::UInt,
+            (64, true) => ScalarType::Long,
+            (64, false) => ScalarType::ULong,
+            _ => return None,
+        })
+    }
+
+    /// Returns the narrowest unsigned integer `ScalarType` whose range covers
+    /// `0..=max_value`. Useful for picking the smallest list index type that can still
+    /// encode a list's length, trading off storage size against flexibility as described
+    /// on [`PropertyType::List`].
+    pub fn smallest_unsigned_for(max_value: u64) -> ScalarType {
+        match max_value {
+            v if v <= u8::MAX as u64 => ScalarType::UChar,
+            v if v <= u16::MAX as u64 => ScalarType::UShort,
+            v if v <= u32::MAX as u64 => ScalarType::UInt,
+            _ => ScalarType::ULong,
+        }
+    }
+
+    /// Returns the Rust type this scalar decodes into, e.g. `"i32"` for `ScalarType::Int`.
+    /// Used to spell out "expected"/"found" types in a [`SchemaError`](crate::errors::SchemaError).
+    pub fn rust_type_name(&self) -> &'static str {
+        match self {
+            ScalarType::Char => "i8",
+            ScalarType::UChar => "u8",
+            ScalarType::Short => "i16",
+            ScalarType::UShort => "u16",
+            ScalarType::Int => "i32",
+            ScalarType::UInt => "u32",
+            ScalarType::Half => "half::f16",
+            ScalarType::Float => "f32",
+            ScalarType::Double => "f64",
+            ScalarType::Long => "i64",
+            ScalarType::ULong => "u64",
+        }
+    }
+}
+
+/// Byte order of a binary-encoded PLY scalar.
+///
+/// Mirrors `Encoding::BinaryLittleEndian`/`BinaryBigEndian`, but as a plain runtime value
+/// rather than a `byteorder::ByteOrder` type parameter - useful for code that only learns
+/// the order at runtime (for example, by inspecting a parsed header) and would otherwise
+/// have to monomorphize or dynamically dispatch over the `byteorder` trait itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Endian {
+    /// Least significant byte first.
+    Little,
+    /// Most significant byte first.
+    Big,
+}
+
+impl ScalarType {
+    /// Returns the number of bytes this scalar type occupies in its binary encoding.
+    pub fn size_in_bytes(&self) -> usize {
+        match self {
+            ScalarType::Char | ScalarType::UChar => 1,
+            ScalarType::Short | ScalarType::UShort | ScalarType::Half => 2,
+            ScalarType::Int | ScalarType::UInt | ScalarType::Float => 4,
+            ScalarType::Double | ScalarType::Long | ScalarType::ULong => 8,
+        }
+    }
+
+    /// Decodes a single scalar of this type from the front of `bytes`, returning the
+    /// decoded [`Property`] and the number of bytes consumed (always
+    /// [`Self::size_in_bytes`]).
+    ///
+    /// This is the header-driven binary codec underlying the parser/writer's own
+    /// `byteorder`-based decoding; it exists so downstream code that walks a payload by
+    /// hand (random access, parallel decode, ...) doesn't have to duplicate byte-order
+    /// handling.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` is shorter than [`Self::size_in_bytes`].
+    pub fn read_scalar(&self, bytes: &[u8], endian: Endian) -> (Property, usize) {
+        use byteorder::{BigEndian, ByteOrder, LittleEndian};
+
+        let size = self.size_in_bytes();
+        assert!(
+            bytes.len() >= size,
+            "not enough bytes to decode a {:?}: need {} byte(s), got {}",
+            self,
+            size,
+            bytes.len()
+        );
+
+        macro_rules! read_multi_byte {
+            ($variant:ident, $read:ident) => {
+                Property::$variant(match endian {
+                    Endian::Little => LittleEndian::$read(bytes),
+                    Endian::Big => BigEndian::$read(bytes),
+                })
+            };
+        }
+
+        let property = match self {
+            ScalarType::Char => Property::Char(bytes[0] as i8),
+            ScalarType::UChar => Property::UChar(bytes[0]),
+            ScalarType::Short => read_multi_byte!(Short, read_i16),
+            ScalarType::UShort => read_multi_byte!(UShort, read_u16),
+            ScalarType::Half => Property::Half(f16::from_bits(match endian {
+                Endian::Little => LittleEndian::read_u16(bytes),
+                Endian::Big => BigEndian::read_u16(bytes),
+            })),
+            ScalarType::Int => read_multi_byte!(Int, read_i32),
+            ScalarType::UInt => read_multi_byte!(UInt, read_u32),
+            ScalarType::Float => read_multi_byte!(Float, read_f32),
+            ScalarType::Double => read_multi_byte!(Double, read_f64),
+            ScalarType::Long => read_multi_byte!(Long, read_i64),
+            ScalarType::ULong => read_multi_byte!(ULong, read_u64),
+        };
+        (property, size)
+    }
+}
+
+impl Property {
+    /// Appends this property's binary encoding to `out`, using `endian` for any
+    /// multi-byte scalar. A list is encoded as a leading element count - written as a
+    /// `uint`, matching `PropertyType::List(ScalarType::UInt, _)` - followed by the
+    /// elements, the layout [`ScalarType::read_scalar`] expects when walking list
+    /// elements back out.
+    pub fn write_bytes(&self, endian: Endian, out: &mut Vec<u8>) {
+        use byteorder::{BigEndian, ByteOrder, LittleEndian};
+
+        macro_rules! push_multi_byte {
+            ($value:expr, $write:ident, $size:expr) => {{
+                let mut buf = [0u8; $size];
+                match endian {
+                    Endian::Little => LittleEndian::$write(&mut buf, $value),
+                    Endian::Big => BigEndian::$write(&mut buf, $value),
+                }
+                out.extend_from_slice(&buf);
+            }};
+        }
+
+        macro_rules! push_list {
+            ($values:expr, $push_elem:expr) => {{
+                push_multi_byte!($values.len() as u32, write_u32, 4);
+                for v in $values.iter() {
+                    $push_elem(*v, endian, out);
+                }
+            }};
+        }
+
+        fn push_char(v: i8, _endian: Endian, out: &mut Vec<u8>) {
+            out.push(v as u8);
+        }
+        fn push_uchar(v: u8, _endian: Endian, out: &mut Vec<u8>) {
+            out.push(v);
+        }
+
+        fn push_half(v: f16, endian: Endian, out: &mut Vec<u8>) {
+            let mut buf = [0u8; 2];
+            match endian {
+                Endian::Little => LittleEndian::write_u16(&mut buf, v.to_bits()),
+                Endian::Big => BigEndian::write_u16(&mut buf, v.to_bits()),
+            }
+            out.extend_from_slice(&buf);
+        }
+
+        match self {
+            Property::Char(v) => out.push(*v as u8),
+            Property::UChar(v) => out.push(*v),
+            Property::Short(v) => push_multi_byte!(*v, write_i16, 2),
+            Property::UShort(v) => push_multi_byte!(*v, write_u16, 2),
+            Property::Half(v) => push_half(*v, endian, out),
+            Property::Int(v) => push_multi_byte!(*v, write_i32, 4),
+            Property::UInt(v) => push_multi_byte!(*v, write_u32, 4),
+            Property::Float(v) => push_multi_byte!(*v, write_f32, 4),
+            Property::Double(v) => push_multi_byte!(*v, write_f64, 8),
+            Property::Long(v) => push_multi_byte!(*v, write_i64, 8),
+            Property::ULong(v) => push_multi_byte!(*v, write_u64, 8),
+            Property::ListChar(v) => push_list!(v, push_char),
+            Property::ListUChar(v) => push_list!(v, push_uchar),
+            Property::ListShort(v) => push_list!(v, |x, _e, out: &mut Vec<u8>| push_multi_byte!(x, write_i16, 2)),
+            Property::ListUShort(v) => push_list!(v, |x, _e, out: &mut Vec<u8>| push_multi_byte!(x, write_u16, 2)),
+            Property::ListHalf(v) => push_list!(v, push_half),
+            Property::ListInt(v) => push_list!(v, |x, _e, out: &mut Vec<u8>| push_multi_byte!(x, write_i32, 4)),
+            Property::ListUInt(v) => push_list!(v, |x, _e, out: &mut Vec<u8>| push_multi_byte!(x, write_u32, 4)),
+            Property::ListFloat(v) => push_list!(v, |x, _e, out: &mut Vec<u8>| push_multi_byte!(x, write_f32, 4)),
+            Property::ListDouble(v) => push_list!(v, |x, _e, out: &mut Vec<u8>| push_multi_byte!(x, write_f64, 8)),
+            Property::ListLong(v) => push_list!(v, |x, _e, out: &mut Vec<u8>| push_multi_byte!(x, write_i64, 8)),
+            Property::ListULong(v) => push_list!(v, |x, _e, out: &mut Vec<u8>| push_multi_byte!(x, write_u64, 8)),
+        }
+    }
 }
 
 /// Data type used to encode properties in the payload.
@@ -51,6 +306,30 @@ pub enum PropertyType {
     List(ScalarType, ScalarType)
 }
 
+impl PropertyType {
+    /// Returns `true` if this is a list property type.
+    pub fn is_list(&self) -> bool {
+        matches!(self, PropertyType::List(_, _))
+    }
+
+    /// Returns the type of the property's value(s): the scalar type itself for
+    /// `PropertyType::Scalar`, or the element type for `PropertyType::List`.
+    pub fn element_type(&self) -> &ScalarType {
+        match self {
+            PropertyType::Scalar(scalar_type) => scalar_type,
+            PropertyType::List(_, elem_type) => elem_type,
+        }
+    }
+
+    /// Returns the list's index (count) type, or `None` for `PropertyType::Scalar`.
+    pub fn index_type(&self) -> Option<&ScalarType> {
+        match self {
+            PropertyType::Scalar(_) => None,
+            PropertyType::List(index_type, _) => Some(index_type),
+        }
+    }
+}
+
 /// Wrapper used to implement a dynamic type system as required by the PLY file format.
 #[derive(Debug, PartialEq, Clone)]
 pub enum Property {
@@ -66,10 +345,16 @@ pub enum Property {
     Int(i32),
     /// Unsigned 32-bit integer scalar (`u32`).
     UInt(u32),
+    /// 16-bit (half-precision) floating point scalar (`half::f16`).
+    Half(f16),
     /// 32-bit floating point scalar (`f32`).
     Float(f32),
     /// 64-bit floating point scalar (`f64`).
     Double(f64),
+    /// Signed 64-bit integer scalar (`i64`).
+    Long(i64),
+    /// Unsigned 64-bit integer scalar (`u64`).
+    ULong(u64),
     /// List of signed 8-bit integers.
     ListChar(Vec<i8>),
     /// List of unsigned 8-bit integers.
@@ -82,12 +367,247 @@ pub enum Property {
     ListInt(Vec<i32>),
     /// List of unsigned 32-bit integers.
     ListUInt(Vec<u32>),
+    /// List of 16-bit (half-precision) floating point values.
+    ListHalf(Vec<f16>),
     /// List of 32-bit floating point values.
     ListFloat(Vec<f32>),
     /// List of 64-bit floating point values.
     ListDouble(Vec<f64>),
+    /// List of signed 64-bit integers.
+    ListLong(Vec<i64>),
+    /// List of unsigned 64-bit integers.
+    ListULong(Vec<u64>),
+}
+
+/// Intermediate representation of a scalar [`Property`]'s mathematical value, used by
+/// [`PropertyCoerce`] to convert between scalar variants without matching on every
+/// combination of source/destination type.
+///
+/// Integers are widened into `i128` (sign-extending signed sources, zero-extending
+/// unsigned ones) so the full range of every PLY integer type - including `u64` - fits
+/// losslessly; floats are widened into `f64`.
+#[derive(Debug, Clone, Copy)]
+enum CoerceValue {
+    Int(i128),
+    Float(f64),
+}
+
+impl Property {
+    /// Returns the mathematical value of a scalar property as a [`CoerceValue`], or
+    /// `None` if `self` is a list property.
+    fn coerce_value(&self) -> Option<CoerceValue> {
+        Some(match self {
+            Property::Char(v) => CoerceValue::Int(*v as i128),
+            Property::UChar(v) => CoerceValue::Int(*v as i128),
+            Property::Short(v) => CoerceValue::Int(*v as i128),
+            Property::UShort(v) => CoerceValue::Int(*v as i128),
+            Property::Int(v) => CoerceValue::Int(*v as i128),
+            Property::UInt(v) => CoerceValue::Int(*v as i128),
+            Property::Long(v) => CoerceValue::Int(*v as i128),
+            Property::ULong(v) => CoerceValue::Int(*v as i128),
+            Property::Half(v) => CoerceValue::Float(v.to_f64()),
+            Property::Float(v) => CoerceValue::Float(*v as f64),
+            Property::Double(v) => CoerceValue::Float(*v),
+            _ => return None,
+        })
+    }
+
+    /// Returns the property value widened to `i64`, the way Rust's `as` operator would:
+    /// signed sources sign-extend, unsigned sources zero-extend, floats truncate towards
+    /// zero. Returns `0` if `self` is a list property.
+    pub fn as_i64(&self) -> i64 {
+        match self.coerce_value() {
+            Some(CoerceValue::Int(v)) => v as i64,
+            Some(CoerceValue::Float(v)) => v as i64,
+            None => 0,
+        }
+    }
+
+    /// Returns the property value widened to `u64`, the way Rust's `as` operator would:
+    /// the source's bit pattern is sign-extended/zero-extended to 128 bits and then
+    /// truncated to 64 bits, so negative sources reinterpret as large unsigned values.
+    /// Returns `0` if `self` is a list property.
+    pub fn as_u64(&self) -> u64 {
+        match self.coerce_value() {
+            Some(CoerceValue::Int(v)) => v as u64,
+            Some(CoerceValue::Float(v)) => v as u64,
+            None => 0,
+        }
+    }
+
+    /// Returns the property value widened to `f64`. Returns `0.0` if `self` is a list
+    /// property.
+    pub fn as_f64(&self) -> f64 {
+        match self.coerce_value() {
+            Some(CoerceValue::Int(v)) => v as f64,
+            Some(CoerceValue::Float(v)) => v,
+            None => 0.0,
+        }
+    }
+
+    /// Fallibly converts the property value to `T`, returning `None` if `self` is a list
+    /// property or if the mathematical value doesn't fit `T` (out-of-range integer,
+    /// non-finite float converted to an integer, ...). See [`PropertyCoerce`].
+    pub fn try_as<T: PropertyCoerce>(&self) -> Option<T> {
+        T::try_from_coerce_value(self.coerce_value()?)
+    }
+
+    /// For list properties, returns a lazy iterator that coerces each element to `T` via
+    /// [`Property::try_as`]'s rules, yielding `None` for elements that don't fit. Returns
+    /// `None` if `self` is a scalar property.
+    pub fn iter_as<T: PropertyCoerce>(&self) -> Option<Box<dyn Iterator<Item = Option<T>> + '_>> {
+        macro_rules! list_iter {
+            ($values:expr, $wrap:expr) => {
+                Box::new($values.iter().map(move |v| {
+                    T::try_from_coerce_value($wrap(*v))
+                })) as Box<dyn Iterator<Item = Option<T>> + '_>
+            };
+        }
+
+        Some(match self {
+            Property::ListChar(v) => list_iter!(v, |x: i8| CoerceValue::Int(x as i128)),
+            Property::ListUChar(v) => list_iter!(v, |x: u8| CoerceValue::Int(x as i128)),
+            Property::ListShort(v) => list_iter!(v, |x: i16| CoerceValue::Int(x as i128)),
+            Property::ListUShort(v) => list_iter!(v, |x: u16| CoerceValue::Int(x as i128)),
+            Property::ListInt(v) => list_iter!(v, |x: i32| CoerceValue::Int(x as i128)),
+            Property::ListUInt(v) => list_iter!(v, |x: u32| CoerceValue::Int(x as i128)),
+            Property::ListLong(v) => list_iter!(v, |x: i64| CoerceValue::Int(x as i128)),
+            Property::ListULong(v) => list_iter!(v, |x: u64| CoerceValue::Int(x as i128)),
+            Property::ListHalf(v) => list_iter!(v, |x: f16| CoerceValue::Float(x.to_f64())),
+            Property::ListFloat(v) => list_iter!(v, |x: f32| CoerceValue::Float(x as f64)),
+            Property::ListDouble(v) => list_iter!(v, |x: f64| CoerceValue::Float(x)),
+            _ => return None,
+        })
+    }
+
+    /// Converts `self` to the exact variant declared by `target`, following
+    /// [`Property::try_as`]/[`Property::iter_as`]'s fallibility rules: integer destinations
+    /// fail if the value doesn't fit, float destinations never fail on magnitude/precision.
+    ///
+    /// Returns `None` if `self` is a list but `target` is a scalar (or vice versa), or if a
+    /// value doesn't fit the declared scalar type. `f16` isn't a [`PropertyCoerce`]
+    /// implementor (it's not a native Rust numeric type), so `ScalarType::Half` is handled
+    /// by going through `f64` instead, same as [`Property::as_f64`].
+    pub fn coerce_to(&self, target: &PropertyType) -> Option<Property> {
+        macro_rules! scalar {
+            ($rust_ty:ty, $variant:ident) => {
+                Some(Property::$variant(self.try_as::<$rust_ty>()?))
+            };
+        }
+        macro_rules! list {
+            ($rust_ty:ty, $variant:ident) => {{
+                let values: Option<Vec<$rust_ty>> = self.iter_as::<$rust_ty>()?.collect();
+                Some(Property::$variant(values?))
+            }};
+        }
+
+        match target {
+            PropertyType::Scalar(scalar_type) => match scalar_type {
+                ScalarType::Char => scalar!(i8, Char),
+                ScalarType::UChar => scalar!(u8, UChar),
+                ScalarType::Short => scalar!(i16, Short),
+                ScalarType::UShort => scalar!(u16, UShort),
+                ScalarType::Int => scalar!(i32, Int),
+                ScalarType::UInt => scalar!(u32, UInt),
+                ScalarType::Long => scalar!(i64, Long),
+                ScalarType::ULong => scalar!(u64, ULong),
+                ScalarType::Float => scalar!(f32, Float),
+                ScalarType::Double => scalar!(f64, Double),
+                ScalarType::Half => Some(Property::Half(f16::from_f64(self.try_as::<f64>()?))),
+            },
+            PropertyType::List(_, elem_type) => match elem_type {
+                ScalarType::Char => list!(i8, ListChar),
+                ScalarType::UChar => list!(u8, ListUChar),
+                ScalarType::Short => list!(i16, ListShort),
+                ScalarType::UShort => list!(u16, ListUShort),
+                ScalarType::Int => list!(i32, ListInt),
+                ScalarType::UInt => list!(u32, ListUInt),
+                ScalarType::Long => list!(i64, ListLong),
+                ScalarType::ULong => list!(u64, ListULong),
+                ScalarType::Float => list!(f32, ListFloat),
+                ScalarType::Double => list!(f64, ListDouble),
+                ScalarType::Half => {
+                    let values: Option<Vec<f64>> = self.iter_as::<f64>()?.collect();
+                    Some(Property::ListHalf(values?.into_iter().map(f16::from_f64).collect()))
+                },
+            },
+        }
+    }
+}
+
+mod coerce_private {
+    pub trait Sealed {}
+}
+
+/// Sealed trait backing [`Property::try_as`]/[`Property::iter_as`]: fallible conversion
+/// from a scalar property's mathematical value to a native Rust numeric type.
+///
+/// Implemented for `i8/u8/i16/u16/i32/u32/i64/u64/f32/f64`, the Rust types every PLY
+/// scalar decodes into.
+///
+/// Integer destinations follow the semantics of rustc's `ScalarInt`: the source value is
+/// sign-extended (or zero-extended) into a 128-bit buffer, then the conversion fails
+/// unless that mathematical value fits within the destination's `MIN..=MAX` range - unlike
+/// `as`, no silent truncation. Float sources are rejected unless they are finite and their
+/// truncated value is in range. Float destinations follow `as`: the conversion only fails
+/// if `self` is a list property, never due to the magnitude or precision of the value.
+pub trait PropertyCoerce: coerce_private::Sealed + Sized {
+    #[doc(hidden)]
+    fn try_from_coerce_value(value: CoerceValue) -> Option<Self>;
+}
+
+macro_rules! impl_coerce_int {
+    ($rust_ty:ty) => {
+        impl coerce_private::Sealed for $rust_ty {}
+        impl PropertyCoerce for $rust_ty {
+            fn try_from_coerce_value(value: CoerceValue) -> Option<Self> {
+                let v = match value {
+                    CoerceValue::Int(v) => v,
+                    CoerceValue::Float(v) => {
+                        if !v.is_finite() {
+                            return None;
+                        }
+                        let truncated = v.trunc();
+                        if truncated < Self::MIN as f64 || truncated > Self::MAX as f64 {
+                            return None;
+                        }
+                        return Some(truncated as Self);
+                    }
+                };
+                if v < Self::MIN as i128 || v > Self::MAX as i128 {
+                    return None;
+                }
+                Some(v as Self)
+            }
+        }
+    };
 }
 
+macro_rules! impl_coerce_float {
+    ($rust_ty:ty) => {
+        impl coerce_private::Sealed for $rust_ty {}
+        impl PropertyCoerce for $rust_ty {
+            fn try_from_coerce_value(value: CoerceValue) -> Option<Self> {
+                Some(match value {
+                    CoerceValue::Int(v) => v as Self,
+                    CoerceValue::Float(v) => v as Self,
+                })
+            }
+        }
+    };
+}
+
+impl_coerce_int!(i8);
+impl_coerce_int!(u8);
+impl_coerce_int!(i16);
+impl_coerce_int!(u16);
+impl_coerce_int!(i32);
+impl_coerce_int!(u32);
+impl_coerce_int!(i64);
+impl_coerce_int!(u64);
+impl_coerce_float!(f32);
+impl_coerce_float!(f64);
+
 /// Provides setters and getters for the Parser and the Writer.
 ///
 /// This trait allows you to create your own data structure for the case that the
@@ -139,6 +659,12 @@ pub trait PropertyAccess {
         None
     }
 
+    /// Returns the property value as a 16-bit (half-precision) floating point number
+    /// (`float16`/`half`).
+    fn get_half(&self, _property_name: &str) -> Option<f16> {
+        None
+    }
+
     /// Returns the property value as a 32-bit floating point number (`float`).
     fn get_float(&self, _property_name: &str) -> Option<f32> {
         None
@@ -149,6 +675,16 @@ pub trait PropertyAccess {
         None
     }
 
+    /// Returns the property value as a signed 64-bit integer (`long`/`int64`).
+    fn get_long(&self, _property_name: &str) -> Option<i64> {
+        None
+    }
+
+    /// Returns the property value as an unsigned 64-bit integer (`ulong`/`uint64`).
+    fn get_ulong(&self, _property_name: &str) -> Option<u64> {
+        None
+    }
+
     /// Returns the property value as a list of signed 8-bit integers.
     fn get_list_char(&self, _property_name: &str) -> Option<&[i8]> {
         None
@@ -179,6 +715,12 @@ pub trait PropertyAccess {
         None
     }
 
+    /// Returns the property value as a list of 16-bit (half-precision) floating point
+    /// numbers.
+    fn get_list_half(&self, _property_name: &str) -> Option<&[f16]> {
+        None
+    }
+
     /// Returns the property value as a list of 32-bit floating point numbers.
     fn get_list_float(&self, _property_name: &str) -> Option<&[f32]> {
         None
@@ -188,6 +730,272 @@ pub trait PropertyAccess {
     fn get_list_double(&self, _property_name: &str) -> Option<&[f64]> {
         None
     }
+
+    /// Returns the property value as a list of signed 64-bit integers.
+    fn get_list_long(&self, _property_name: &str) -> Option<&[i64]> {
+        None
+    }
+
+    /// Returns the property value as a list of unsigned 64-bit integers.
+    fn get_list_ulong(&self, _property_name: &str) -> Option<&[u64]> {
+        None
+    }
+
+    /// Returns the property value as `T`, dispatching through the monomorphic getter for
+    /// `T`'s [`ScalarType`] (see [`PlyScalar`]).
+    ///
+    /// This lets generic code - for example a mesh loader parameterized over the
+    /// coordinate type - write `element.get::<f32>("x")` instead of picking `get_float`
+    /// vs. `get_double` by hand.
+    fn get<T: PlyScalar>(&self, property_name: &str) -> Option<T> where Self: Sized {
+        T::get_from(self, property_name)
+    }
+
+    /// Returns the property value as a list of `T`, dispatching through the monomorphic
+    /// list getter for `T`'s [`ScalarType`] (see [`PlyScalar`]).
+    fn get_list<T: PlyScalar>(&self, property_name: &str) -> Option<&[T]> where Self: Sized {
+        T::get_list_from(self, property_name)
+    }
+
+    /// Returns the property value coerced into `T`, trying every scalar getter in turn and
+    /// range-checking whichever one hits through the same `i128`/`f64` intermediate
+    /// [`Property::try_as`] uses, rather than requiring the stored value to already be
+    /// exactly `T`'s own [`ScalarType`].
+    ///
+    /// Unlike [`get`](PropertyAccess::get), this also accepts a property declared under a
+    /// *different* scalar type - e.g. a `short` position landing in a `u32` field, or a
+    /// `double` landing in an `f32` field - succeeding whenever the mathematical value fits
+    /// `T`, and returning `None` only for a genuinely out-of-range or non-numeric property.
+    fn get_scalar_as<T: PropertyCoerce>(&self, property_name: &str) -> Option<T> {
+        if let Some(v) = self.get_char(property_name) {
+            return T::try_from_coerce_value(CoerceValue::Int(v as i128));
+        }
+        if let Some(v) = self.get_uchar(property_name) {
+            return T::try_from_coerce_value(CoerceValue::Int(v as i128));
+        }
+        if let Some(v) = self.get_short(property_name) {
+            return T::try_from_coerce_value(CoerceValue::Int(v as i128));
+        }
+        if let Some(v) = self.get_ushort(property_name) {
+            return T::try_from_coerce_value(CoerceValue::Int(v as i128));
+        }
+        if let Some(v) = self.get_int(property_name) {
+            return T::try_from_coerce_value(CoerceValue::Int(v as i128));
+        }
+        if let Some(v) = self.get_uint(property_name) {
+            return T::try_from_coerce_value(CoerceValue::Int(v as i128));
+        }
+        if let Some(v) = self.get_long(property_name) {
+            return T::try_from_coerce_value(CoerceValue::Int(v as i128));
+        }
+        if let Some(v) = self.get_ulong(property_name) {
+            return T::try_from_coerce_value(CoerceValue::Int(v as i128));
+        }
+        if let Some(v) = self.get_half(property_name) {
+            return T::try_from_coerce_value(CoerceValue::Float(v.to_f64()));
+        }
+        if let Some(v) = self.get_float(property_name) {
+            return T::try_from_coerce_value(CoerceValue::Float(v as f64));
+        }
+        if let Some(v) = self.get_double(property_name) {
+            return T::try_from_coerce_value(CoerceValue::Float(v));
+        }
+        None
+    }
+}
+
+/// A face-like element that exposes its vertex indices and can be rebuilt from a single
+/// triangle, letting the generated [`FromPly`](crate::parser::FromPly) reader's
+/// `#[ply(triangulate)]` attribute fan-triangulate arbitrary n-gons while reading.
+///
+/// Fan triangulation turns a face `[i0, i1, ..., i_{n-1}]` into the triangles
+/// `(i0, i1, i2), (i0, i2, i3), ..., (i0, i_{n-2}, i_{n-1})` - the same approach OBJ loaders
+/// use before handing polygon faces to a renderer that only draws triangles.
+pub trait Polygon: Sized {
+    /// Returns this face's vertex indices, in winding order.
+    fn polygon_indices(&self) -> &[u32];
+
+    /// Builds a 3-index face from an explicit triangle.
+    fn from_triangle(a: u32, b: u32, c: u32) -> Self;
+}
+
+/// Describes the PLY properties a type writes, for building the `element`/`property` lines
+/// of a header without an instance of the type in hand.
+///
+/// The write-side counterpart of a read schema: `#[derive(PlyWrite)]` generates this from a
+/// struct's fields (see `ply-rs-macros`), and [`crate::writer::Writer`] doesn't need it
+/// directly, but the `#[derive(ToPly)]` container macro calls it to build each element group's
+/// header entry before writing the group's payload.
+pub trait WriteSchema {
+    /// The properties this type writes, in declaration order, as `(name, type)` pairs.
+    fn property_type_schema() -> Vec<(String, PropertyType)>;
+}
+
+/// Whether a property a [`ReadSchema`] implementor expects must be present in the source
+/// header, or may legitimately be missing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Requiredness {
+    /// The reader must see this property declared for the element; its absence is a hard
+    /// parse error.
+    Required,
+    /// The property may be absent from the header - typically because the field is
+    /// `#[ply(optional)]`, has a `#[ply(default = "...")]`, or is an `Option<T>` - in which
+    /// case the field keeps its default/fallback value instead of erroring.
+    Optional,
+}
+
+/// Describes the PLY properties a type reads, for validating that a header actually declares
+/// everything a [`PropertyAccess`] implementor's `set_property` calls depend on.
+///
+/// The read-side counterpart of [`WriteSchema`]: `#[derive(PlyRead)]` generates this from a
+/// struct's fields (see `ply-rs-macros`), and the generated `FromPly` reader consults it right
+/// after reading the header to check that every [`Requiredness::Required`] property is declared
+/// there, rather than silently leaving the field at its `Default`/fallback value.
+pub trait ReadSchema {
+    /// The properties this type reads, in declaration order, as `(name, requiredness)` pairs.
+    fn schema() -> Vec<(String, Requiredness)>;
+
+    /// Checks that every [`Requiredness::Required`] property in [`schema`](ReadSchema::schema)
+    /// is declared on `element_def`, returning a [`PlyError::Schema`] naming the first missing
+    /// one otherwise.
+    ///
+    /// The generated [`FromPly`](crate::parser::FromPly) reader calls this once per matched
+    /// element name right after reading the header, so a file that omits a required property
+    /// is rejected up front with a message pointing at the element and property instead of
+    /// silently leaving the corresponding field at its `Default`/`#[ply(default = ...)]` value.
+    fn validate_required(element_name: &str, element_def: &ElementDef) -> PlyResult<()> {
+        for (name, requiredness) in Self::schema() {
+            if matches!(requiredness, Requiredness::Required) && !element_def.properties.contains_key(&name) {
+                return Err(PlyError::Schema(SchemaError {
+                    element: element_name.to_string(),
+                    property: name,
+                    expected: "present".to_string(),
+                    found: "missing".to_string(),
+                }));
+            }
+        }
+        Ok(())
+    }
+}
+
+mod private {
+    /// Prevents downstream crates from implementing [`super::PlyScalar`] for types this
+    /// crate doesn't know how to encode as a PLY property.
+    pub trait Sealed {}
+}
+
+/// Associates a native Rust scalar type with its [`ScalarType`] discriminant and the
+/// [`PropertyAccess`] getters used to read/write it, so generic code can call
+/// [`PropertyAccess::get`]/[`PropertyAccess::get_list`] instead of picking a monomorphic
+/// getter (`get_float`, `get_list_uint`, ...) by hand.
+///
+/// Sealed: implemented only for `i8`, `u8`, `i16`, `u16`, `i32`, `u32`, `i64`, `u64`, `f32`,
+/// and `f64`, the Rust types PLY scalars actually decode into.
+pub trait PlyScalar: private::Sealed + Sized {
+    /// The [`ScalarType`] PLY uses to encode this Rust type.
+    const SCALAR_TYPE: ScalarType;
+
+    #[doc(hidden)]
+    fn get_from<A: PropertyAccess>(access: &A, property_name: &str) -> Option<Self>;
+    #[doc(hidden)]
+    fn get_list_from<'a, A: PropertyAccess>(access: &'a A, property_name: &str) -> Option<&'a [Self]>;
+
+    /// Converts a decoded [`Property`] into `Self`, the same way the per-type setters
+    /// generated for a concrete scalar field do: any scalar variant other than `Half` converts
+    /// via `as`, widening losslessly (`float` into `f64`) or narrowing with the usual `as`
+    /// truncation (`double` into `f32`). Lets `#[derive(PlyRead)]` decode a field typed as
+    /// one of the struct's own generic parameters - e.g. `struct Vertex<S: PlyScalar> { x: S }`
+    /// read as either `Vertex<f32>` or `Vertex<f64>` - without knowing `S` until monomorphization.
+    fn from_property(property: &Property) -> Option<Self>;
+
+    /// The write-side counterpart of [`from_property`](PlyScalar::from_property): wraps `self`
+    /// in the [`Property`] variant matching `SCALAR_TYPE`. A generic field's `get_char`/
+    /// `get_float`/... getters can't cast `self` to a concrete type directly (`T` isn't known
+    /// to support `as` inside a generic fn body), so `#[derive(PlyWrite)]` round-trips the
+    /// value through here instead and re-matches the resulting `Property` against whichever
+    /// scalar bucket is being asked for.
+    fn to_property(&self) -> Property;
+}
+
+macro_rules! impl_ply_scalar {
+    ($rust_ty:ty, $scalar_type:expr, $get:ident, $get_list:ident, $variant:ident) => {
+        impl private::Sealed for $rust_ty {}
+        impl PlyScalar for $rust_ty {
+            const SCALAR_TYPE: ScalarType = $scalar_type;
+            fn get_from<A: PropertyAccess>(access: &A, property_name: &str) -> Option<Self> {
+                access.$get(property_name)
+            }
+            fn get_list_from<'a, A: PropertyAccess>(access: &'a A, property_name: &str) -> Option<&'a [Self]> {
+                access.$get_list(property_name)
+            }
+            fn from_property(property: &Property) -> Option<Self> {
+                match *property {
+                    Property::Char(v) => Some(v as $rust_ty),
+                    Property::UChar(v) => Some(v as $rust_ty),
+                    Property::Short(v) => Some(v as $rust_ty),
+                    Property::UShort(v) => Some(v as $rust_ty),
+                    Property::Int(v) => Some(v as $rust_ty),
+                    Property::UInt(v) => Some(v as $rust_ty),
+                    Property::Float(v) => Some(v as $rust_ty),
+                    Property::Double(v) => Some(v as $rust_ty),
+                    Property::Long(v) => Some(v as $rust_ty),
+                    Property::ULong(v) => Some(v as $rust_ty),
+                    _ => None,
+                }
+            }
+            fn to_property(&self) -> Property {
+                Property::$variant(*self)
+            }
+        }
+    };
+}
+
+impl_ply_scalar!(i8, ScalarType::Char, get_char, get_list_char, Char);
+impl_ply_scalar!(u8, ScalarType::UChar, get_uchar, get_list_uchar, UChar);
+impl_ply_scalar!(i16, ScalarType::Short, get_short, get_list_short, Short);
+impl_ply_scalar!(u16, ScalarType::UShort, get_ushort, get_list_ushort, UShort);
+impl_ply_scalar!(i32, ScalarType::Int, get_int, get_list_int, Int);
+impl_ply_scalar!(u32, ScalarType::UInt, get_uint, get_list_uint, UInt);
+impl_ply_scalar!(f32, ScalarType::Float, get_float, get_list_float, Float);
+impl_ply_scalar!(f64, ScalarType::Double, get_double, get_list_double, Double);
+impl_ply_scalar!(i64, ScalarType::Long, get_long, get_list_long, Long);
+impl_ply_scalar!(u64, ScalarType::ULong, get_ulong, get_list_ulong, ULong);
+
+/// Implemented by `#[derive(PlyEnum)]` on a field-less enum, mapping each variant to an integer
+/// discriminant encoded as `SCALAR_TYPE` - see `ply-rs-macros` for the derive and the field-level
+/// `#[ply(enum, type = "...")]` attribute that lets such an enum be used as a struct field in
+/// `#[derive(PlyRead)]`/`#[derive(PlyWrite)]`. Unlike [`PlyScalar`], this isn't sealed: any type
+/// can implement it, though in practice only the derive does.
+pub trait PlyEnum: Sized {
+    /// The PLY scalar the discriminant is encoded as, as declared by `#[ply(repr = "...")]`.
+    const SCALAR_TYPE: ScalarType;
+
+    /// Maps a raw discriminant back to its variant, or `None` if no variant has that value -
+    /// the caller then leaves the field at its default, the same as an absent property would.
+    fn from_discriminant(value: i64) -> Option<Self>;
+
+    /// The discriminant this variant writes as.
+    fn to_discriminant(&self) -> i64;
+
+    /// Converts a decoded [`Property`] into `Self` by widening it to `i64` and looking up the
+    /// matching variant via [`from_discriminant`](PlyEnum::from_discriminant) - the enum
+    /// counterpart of [`PlyScalar::from_property`].
+    fn from_property(property: &Property) -> Option<Self> {
+        let value = match *property {
+            Property::Char(v) => v as i64,
+            Property::UChar(v) => v as i64,
+            Property::Short(v) => v as i64,
+            Property::UShort(v) => v as i64,
+            Property::Int(v) => v as i64,
+            Property::UInt(v) => v as i64,
+            Property::Float(v) => v as i64,
+            Property::Double(v) => v as i64,
+            Property::Long(v) => v,
+            Property::ULong(v) => v as i64,
+            _ => return None,
+        };
+        Self::from_discriminant(value)
+    }
 }
 
 #[cfg(test)]
@@ -211,8 +1019,11 @@ mod tests {
         let _ = ScalarType::UShort;
         let _ = ScalarType::Int;
         let _ = ScalarType::UInt;
+        let _ = ScalarType::Half;
         let _ = ScalarType::Float;
         let _ = ScalarType::Double;
+        let _ = ScalarType::Long;
+        let _ = ScalarType::ULong;
 
         // PropertyType
         let _ = PropertyType::Scalar(ScalarType::Char);
@@ -221,8 +1032,11 @@ mod tests {
         let _ = PropertyType::Scalar(ScalarType::UShort);
         let _ = PropertyType::Scalar(ScalarType::Int);
         let _ = PropertyType::Scalar(ScalarType::UInt);
+        let _ = PropertyType::Scalar(ScalarType::Half);
         let _ = PropertyType::Scalar(ScalarType::Float);
         let _ = PropertyType::Scalar(ScalarType::Double);
+        let _ = PropertyType::Scalar(ScalarType::Long);
+        let _ = PropertyType::Scalar(ScalarType::ULong);
         let _ = PropertyType::List(ScalarType::UInt, ScalarType::Char);
 
         // Property
@@ -232,16 +1046,22 @@ mod tests {
         let _ = Property::UShort(u16::MAX);
         let _ = Property::Int(i32::MIN);
         let _ = Property::UInt(u32::MAX);
+        let _ = Property::Half(f16::from_f32(1.5));
         let _ = Property::Float(f32::NAN);
         let _ = Property::Double(f64::NAN);
+        let _ = Property::Long(i64::MIN);
+        let _ = Property::ULong(u64::MAX);
         let _ = Property::ListChar(vec![i8::MIN]);
         let _ = Property::ListUChar(vec![u8::MAX]);
         let _ = Property::ListShort(vec![i16::MIN]);
         let _ = Property::ListUShort(vec![u16::MAX]);
         let _ = Property::ListInt(vec![i32::MIN]);
         let _ = Property::ListUInt(vec![u32::MAX]);
+        let _ = Property::ListHalf(vec![f16::from_f32(1.5)]);
         let _ = Property::ListFloat(vec![f32::NAN]);
         let _ = Property::ListDouble(vec![f64::NAN]);
+        let _ = Property::ListLong(vec![i64::MIN]);
+        let _ = Property::ListULong(vec![u64::MAX]);
     }
 
     #[test]
@@ -252,16 +1072,25 @@ mod tests {
         assert_eq!(Property::UShort(0), Property::UShort(0));
         assert_eq!(Property::Int(0), Property::Int(0));
         assert_eq!(Property::UInt(0), Property::UInt(0));
+        assert_eq!(Property::Half(f16::from_f32(0.0)), Property::Half(f16::from_f32(0.0)));
         assert_eq!(Property::Float(0.0), Property::Float(0.0));
         assert_eq!(Property::Double(0.0), Property::Double(0.0));
+        assert_eq!(Property::Long(0), Property::Long(0));
+        assert_eq!(Property::ULong(0), Property::ULong(0));
         assert_eq!(Property::ListChar(vec![]), Property::ListChar(vec![]));
         assert_eq!(Property::ListUChar(vec![]), Property::ListUChar(vec![]));
         assert_eq!(Property::ListShort(vec![0]), Property::ListShort(vec![0]));
         assert_eq!(Property::ListUShort(vec![0]), Property::ListUShort(vec![0]));
         assert_eq!(Property::ListInt(vec![0]), Property::ListInt(vec![0]));
         assert_eq!(Property::ListUInt(vec![0]), Property::ListUInt(vec![0]));
+        assert_eq!(
+            Property::ListHalf(vec![f16::from_f32(0.0)]),
+            Property::ListHalf(vec![f16::from_f32(0.0)])
+        );
         assert_eq!(Property::ListFloat(vec![0.0]), Property::ListFloat(vec![0.0]));
         assert_eq!(Property::ListDouble(vec![0.0]), Property::ListDouble(vec![0.0]));
+        assert_eq!(Property::ListLong(vec![0]), Property::ListLong(vec![0]));
+        assert_eq!(Property::ListULong(vec![0]), Property::ListULong(vec![0]));
     }
 
     #[test]
@@ -280,6 +1109,231 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_generic_get_dispatches_to_monomorphic_getter() {
+        use crate::ply::DefaultElement;
+
+        let mut elem = DefaultElement::new();
+        elem.set_property("x", Property::Float(1.5));
+        elem.set_property("idx", Property::ListUInt(vec![0, 1, 2]));
+
+        assert_eq!(elem.get::<f32>("x"), Some(1.5));
+        assert_eq!(elem.get::<f64>("x"), None);
+        assert_eq!(elem.get_list::<u32>("idx"), Some(&[0, 1, 2][..]));
+        assert_eq!(elem.get_list::<i32>("idx"), None);
+    }
+
+    #[test]
+    fn test_as_i64_as_u64_as_f64_widen_like_as_operator() {
+        assert_eq!(Property::Char(-1).as_i64(), -1);
+        assert_eq!(Property::Char(-1).as_u64(), u64::MAX);
+        assert_eq!(Property::UChar(200).as_i64(), 200);
+        assert_eq!(Property::Float(1.75).as_i64(), 1);
+        assert_eq!(Property::Double(2.5).as_f64(), 2.5);
+        assert_eq!(Property::ULong(u64::MAX).as_i64(), -1);
+
+        // List properties have no single scalar value.
+        assert_eq!(Property::ListUInt(vec![1, 2]).as_i64(), 0);
+    }
+
+    #[test]
+    fn test_try_as_rejects_out_of_range_integers() {
+        assert_eq!(Property::Int(200).try_as::<u8>(), Some(200u8));
+        assert_eq!(Property::Int(-1).try_as::<u8>(), None);
+        assert_eq!(Property::Int(300).try_as::<u8>(), None);
+        assert_eq!(Property::ULong(u64::MAX).try_as::<i64>(), None);
+        assert_eq!(Property::Char(-5).try_as::<i64>(), Some(-5));
+    }
+
+    #[test]
+    fn test_get_scalar_as_coerces_across_scalar_types() {
+        use crate::ply::DefaultElement;
+
+        let mut elem = DefaultElement::new();
+        elem.set_property("n", Property::Short(200));
+        elem.set_property("d", Property::Double(2.5));
+
+        // A `short` position read through the `u32` coercing getter widens losslessly.
+        assert_eq!(elem.get_scalar_as::<u32>("n"), Some(200u32));
+        // `get` only accepts the exact stored scalar type; `get_scalar_as` doesn't.
+        assert_eq!(elem.get::<u32>("n"), None);
+        // Narrowing `double` into `f32` never fails on magnitude/precision.
+        assert_eq!(elem.get_scalar_as::<f32>("d"), Some(2.5f32));
+    }
+
+    #[test]
+    fn test_get_scalar_as_rejects_out_of_range_and_missing() {
+        use crate::ply::DefaultElement;
+
+        let mut elem = DefaultElement::new();
+        elem.set_property("n", Property::Int(-1));
+
+        // -1 doesn't fit a u8: a genuine overflow, not a missing property.
+        assert_eq!(elem.get_scalar_as::<u8>("n"), None);
+        assert_eq!(elem.get_scalar_as::<i32>("missing"), None);
+    }
+
+    #[test]
+    fn test_try_as_rejects_non_finite_and_out_of_range_floats() {
+        assert_eq!(Property::Float(f32::NAN).try_as::<i32>(), None);
+        assert_eq!(Property::Double(1e300).try_as::<i32>(), None);
+        assert_eq!(Property::Double(3.9).try_as::<i32>(), Some(3));
+        assert_eq!(Property::Int(42).try_as::<f64>(), Some(42.0));
+    }
+
+    #[test]
+    fn test_try_as_none_for_list_properties() {
+        assert_eq!(Property::ListInt(vec![1, 2]).try_as::<i64>(), None);
+    }
+
+    #[test]
+    fn test_iter_as_lazily_coerces_list_elements() {
+        let coerced: Vec<Option<u8>> = Property::ListInt(vec![10, -1, 300])
+            .iter_as::<u8>()
+            .expect("list property")
+            .collect();
+        assert_eq!(coerced, vec![Some(10), None, None]);
+
+        assert!(Property::Int(1).iter_as::<i64>().is_none());
+    }
+
+    #[test]
+    fn test_scalar_type_size_in_bytes() {
+        assert_eq!(ScalarType::Char.size_in_bytes(), 1);
+        assert_eq!(ScalarType::UChar.size_in_bytes(), 1);
+        assert_eq!(ScalarType::Short.size_in_bytes(), 2);
+        assert_eq!(ScalarType::UShort.size_in_bytes(), 2);
+        assert_eq!(ScalarType::Int.size_in_bytes(), 4);
+        assert_eq!(ScalarType::UInt.size_in_bytes(), 4);
+        assert_eq!(ScalarType::Half.size_in_bytes(), 2);
+        assert_eq!(ScalarType::Float.size_in_bytes(), 4);
+        assert_eq!(ScalarType::Double.size_in_bytes(), 8);
+        assert_eq!(ScalarType::Long.size_in_bytes(), 8);
+        assert_eq!(ScalarType::ULong.size_in_bytes(), 8);
+    }
+
+    #[test]
+    fn test_read_scalar_round_trips_write_bytes_both_endians() {
+        for endian in [Endian::Little, Endian::Big] {
+            let mut out = Vec::new();
+            Property::Int(-7).write_bytes(endian, &mut out);
+            assert_eq!(out.len(), 4);
+            let (decoded, consumed) = ScalarType::Int.read_scalar(&out, endian);
+            assert_eq!(decoded, Property::Int(-7));
+            assert_eq!(consumed, 4);
+        }
+    }
+
+    #[test]
+    fn test_half_round_trips_write_bytes_both_endians() {
+        for endian in [Endian::Little, Endian::Big] {
+            let mut out = Vec::new();
+            Property::Half(f16::from_f32(1.5)).write_bytes(endian, &mut out);
+            assert_eq!(out.len(), 2);
+            let (decoded, consumed) = ScalarType::Half.read_scalar(&out, endian);
+            assert_eq!(decoded, Property::Half(f16::from_f32(1.5)));
+            assert_eq!(consumed, 2);
+        }
+    }
+
+    #[test]
+    fn test_read_scalar_little_vs_big_endian() {
+        let mut le = Vec::new();
+        Property::UShort(0x0102).write_bytes(Endian::Little, &mut le);
+        assert_eq!(le, vec![0x02, 0x01]);
+
+        let mut be = Vec::new();
+        Property::UShort(0x0102).write_bytes(Endian::Big, &mut be);
+        assert_eq!(be, vec![0x01, 0x02]);
+
+        assert_eq!(
+            ScalarType::UShort.read_scalar(&be, Endian::Big).0,
+            Property::UShort(0x0102)
+        );
+    }
+
+    #[test]
+    fn test_write_bytes_list_has_leading_uint_count() {
+        let mut out = Vec::new();
+        Property::ListFloat(vec![1.0, 2.0, 3.0]).write_bytes(Endian::Little, &mut out);
+
+        let (count, consumed) = ScalarType::UInt.read_scalar(&out, Endian::Little);
+        assert_eq!(count, Property::UInt(3));
+        assert_eq!(consumed, 4);
+
+        let (first_elem, _) = ScalarType::Float.read_scalar(&out[consumed..], Endian::Little);
+        assert_eq!(first_elem, Property::Float(1.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_read_scalar_panics_on_short_input() {
+        ScalarType::Int.read_scalar(&[0u8; 2], Endian::Little);
+    }
+
+    #[test]
+    fn test_scalar_type_classification() {
+        assert!(ScalarType::Int.is_integer());
+        assert!(ScalarType::Int.is_signed());
+        assert!(!ScalarType::Int.is_float());
+
+        assert!(ScalarType::UInt.is_integer());
+        assert!(!ScalarType::UInt.is_signed());
+
+        assert!(!ScalarType::Double.is_integer());
+        assert!(!ScalarType::Double.is_signed());
+        assert!(ScalarType::Double.is_float());
+
+        assert!(!ScalarType::Half.is_integer());
+        assert!(ScalarType::Half.is_float());
+
+        assert_eq!(ScalarType::Char.bit_width(), 8);
+        assert_eq!(ScalarType::ULong.bit_width(), 64);
+    }
+
+    #[test]
+    fn test_scalar_type_value_range() {
+        assert_eq!(
+            ScalarType::UChar.value_range(),
+            Some((Property::UChar(0), Property::UChar(255)))
+        );
+        assert_eq!(
+            ScalarType::Int.value_range(),
+            Some((Property::Int(i32::MIN), Property::Int(i32::MAX)))
+        );
+        assert_eq!(ScalarType::Float.value_range(), None);
+    }
+
+    #[test]
+    fn test_scalar_type_from_bit_width_signed() {
+        assert_eq!(ScalarType::from_bit_width_signed(8, false), Some(ScalarType::UChar));
+        assert_eq!(ScalarType::from_bit_width_signed(32, true), Some(ScalarType::Int));
+        assert_eq!(ScalarType::from_bit_width_signed(64, false), Some(ScalarType::ULong));
+        assert_eq!(ScalarType::from_bit_width_signed(24, true), None);
+    }
+
+    #[test]
+    fn test_scalar_type_smallest_unsigned_for() {
+        assert_eq!(ScalarType::smallest_unsigned_for(0), ScalarType::UChar);
+        assert_eq!(ScalarType::smallest_unsigned_for(255), ScalarType::UChar);
+        assert_eq!(ScalarType::smallest_unsigned_for(256), ScalarType::UShort);
+        assert_eq!(ScalarType::smallest_unsigned_for(u16::MAX as u64 + 1), ScalarType::UInt);
+        assert_eq!(ScalarType::smallest_unsigned_for(u32::MAX as u64 + 1), ScalarType::ULong);
+    }
+
+    #[test]
+    fn test_property_type_introspection() {
+        let scalar = PropertyType::Scalar(ScalarType::Float);
+        assert!(!scalar.is_list());
+        assert_eq!(scalar.element_type(), &ScalarType::Float);
+        assert_eq!(scalar.index_type(), None);
+
+        let list = PropertyType::List(ScalarType::UChar, ScalarType::Int);
+        assert!(list.is_list());
+        assert_eq!(list.element_type(), &ScalarType::Int);
+        assert_eq!(list.index_type(), Some(&ScalarType::UChar));
+    }
+
     #[test]
     fn test_property_access_defaults() {
         let mut dummy = Dummy::new();
@@ -291,15 +1345,38 @@ mod tests {
         assert_eq!(dummy.get_ushort("foo"), None);
         assert_eq!(dummy.get_int("foo"), None);
         assert_eq!(dummy.get_uint("foo"), None);
+        assert_eq!(dummy.get_half("foo"), None);
         assert_eq!(dummy.get_float("foo"), None);
         assert_eq!(dummy.get_double("foo"), None);
+        assert_eq!(dummy.get_long("foo"), None);
+        assert_eq!(dummy.get_ulong("foo"), None);
         assert_eq!(dummy.get_list_char("foo"), None);
         assert_eq!(dummy.get_list_uchar("foo"), None);
         assert_eq!(dummy.get_list_short("foo"), None);
         assert_eq!(dummy.get_list_ushort("foo"), None);
         assert_eq!(dummy.get_list_int("foo"), None);
         assert_eq!(dummy.get_list_uint("foo"), None);
+        assert_eq!(dummy.get_list_half("foo"), None);
         assert_eq!(dummy.get_list_float("foo"), None);
         assert_eq!(dummy.get_list_double("foo"), None);
+        assert_eq!(dummy.get_list_long("foo"), None);
+        assert_eq!(dummy.get_list_ulong("foo"), None);
+    }
+
+    #[test]
+    fn test_write_schema() {
+        struct Vertex;
+        impl WriteSchema for Vertex {
+            fn property_type_schema() -> Vec<(String, PropertyType)> {
+                vec![
+                    ("x".to_string(), PropertyType::Scalar(ScalarType::Float)),
+                    ("y".to_string(), PropertyType::Scalar(ScalarType::Float)),
+                ]
+            }
+        }
+
+        let schema = Vertex::property_type_schema();
+        assert_eq!(schema[0], ("x".to_string(), PropertyType::Scalar(ScalarType::Float)));
+        assert_eq!(schema.len(), 2);
     }
 }
\ No newline at end of file
@@ -1,3 +1,14 @@
+//! `serde` support, built directly on the header/[`DefaultElement`] the rest of the crate
+//! already parses into.
+//!
+//! [`PlyDeserializer`]'s `deserialize_any` chain is self-describing: it never needs to know a
+//! target's concrete shape up front, because every property is visited as whatever
+//! [`Property`] variant the header's declared [`ScalarType`] decoded into (`visit_i8`,
+//! `visit_f32`, `visit_seq` for a list, ...), each element is a `visit_map` keyed by
+//! property name, nested inside its group's `visit_seq` under the top-level map's element name. This is what lets a `HashMap<String, T>`,
+//! a `#[serde(flatten)]` struct, or any other target that doesn't name every property up front
+//! deserialize successfully, not just a struct whose fields match the header exactly.
+
 use std::io::{Read, BufRead, BufReader, Write};
 use serde::{Deserializer, Serialize, de, ser};
 use crate::parser;
@@ -5,6 +16,204 @@ use crate::writer;
 use crate::ply::{Header, Property, DefaultElement, Encoding, Ply, ElementDef, PropertyDef, PropertyType, ScalarType, Addable};
 use crate::errors::{PlyResult, PlyError};
 
+/// Reserved [`PlyMapAccess`] key whose value is the header's `comment` lines, surfaced after
+/// the real elements are exhausted. A field renamed to this (e.g.
+/// `#[serde(rename = "__ply_comments__")] comments: Vec<String>`) receives them directly;
+/// [`WithHeader`] is the more convenient way to get the same data without touching field names.
+const COMMENTS_KEY: &str = "__ply_comments__";
+
+/// Reserved [`PlyMapAccess`] key for the header's `obj_info` lines. See [`COMMENTS_KEY`].
+const OBJ_INFO_KEY: &str = "__ply_obj_info__";
+
+/// Property name carrying the discriminant of a serialized enum newtype/struct variant -
+/// an internally-tagged representation, the same model serde_json/CBOR readers use for
+/// `#[serde(tag = "...")]` enums. A unit variant in a property field has no inner fields to
+/// tag, so it is stored directly as that property's own value instead (see [`EnumRepr`]); a
+/// unit variant serialized as a whole element is tagged under this key.
+const VARIANT_TAG_KEY: &str = "__ply_variant__";
+
+/// How enum variants are encoded: the discriminant tagging a [`VARIANT_TAG_KEY`] property
+/// (newtype/struct variants), or the sole value of a unit variant's property. Set via
+/// [`PlyWriteConfig::enum_repr`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EnumRepr {
+    /// Store `variant_index` as `Property::UInt`. The default - compact, and stable across
+    /// variant renames since it never touches the variant's name.
+    #[default]
+    VariantIndex,
+    /// Store the variant's name as `Property::ListChar`, the same representation
+    /// [`PropertySerializer::serialize_str`] uses for strings. Stable across reordering the
+    /// enum's variants, at the cost of a few more bytes per value.
+    VariantName,
+}
+
+fn variant_tag_property(repr: EnumRepr, variant_index: u32, variant: &str) -> Property {
+    match repr {
+        EnumRepr::VariantIndex => Property::UInt(variant_index),
+        EnumRepr::VariantName => {
+            Property::ListChar(variant.bytes().map(|b| b as i8).collect())
+        }
+    }
+}
+
+/// Policy for encoding `i64`/`u64`/`i128`/`u128` values, set via
+/// [`PlyWriteConfig::int64_policy`]. Core PLY has no integer scalar wider than 32 bits;
+/// this crate additionally supports `int64`/`uint64` as an extension (see [`Property::Long`]/
+/// [`Property::ULong`]), but nothing wider, and not every reader understands the extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Int64Policy {
+    /// Encode `i64`/`u64` exactly as `Property::Long`/`ULong`. `i128`/`u128` values are
+    /// range-checked against `i64`/`u64` and rejected with [`PlyError::Serialize`] if they
+    /// don't fit. The default - never loses precision, but `i128`/`u128` outside 64-bit
+    /// range must use a different policy.
+    #[default]
+    Error,
+    /// Cast through `f64`, the historical behavior, but only when the value round-trips
+    /// back to the original integer exactly; errors instead of silently truncating it.
+    Widen,
+    /// Split into a little-endian `Property::ListUInt` of 32-bit words, low word first:
+    /// two words for a 64-bit value, four for a 128-bit one. Portable to readers with no
+    /// native 64-bit scalar support at all. [`words_to_u128`] reverses the split.
+    SplitList,
+}
+
+/// Splits `v` into little-endian 32-bit words, low word first - the [`Int64Policy::SplitList`]
+/// encoding. `words` many words are emitted (2 for a 64-bit value, 4 for 128-bit).
+fn u128_to_words(v: u128, words: usize) -> Vec<u32> {
+    (0..words).map(|i| (v >> (32 * i)) as u32).collect()
+}
+
+/// Reassembles a value split by [`Int64Policy::SplitList`] (little-endian 32-bit words, low
+/// word first) back into a `u128`. Returns `None` if `words` holds neither 2 nor 4 words.
+pub fn words_to_u128(words: &[u32]) -> Option<u128> {
+    if words.len() != 2 && words.len() != 4 {
+        return None;
+    }
+    Some(words.iter().enumerate().fold(0u128, |acc, (i, &w)| acc | ((w as u128) << (32 * i))))
+}
+
+/// Encodes a signed `i64`/`i128` value (`bits` is 64 or 128) per `policy`. See [`Int64Policy`].
+fn signed_int64_property(policy: Int64Policy, v: i128, bits: u32) -> Result<Property, PlyError> {
+    match policy {
+        Int64Policy::Error => {
+            if bits <= 64 {
+                if let Ok(v) = i64::try_from(v) {
+                    return Ok(Property::Long(v));
+                }
+            }
+            Err(PlyError::Serialize(format!(
+                "{v} does not fit a PLY int64 property; choose Int64Policy::Widen or ::SplitList"
+            )))
+        }
+        Int64Policy::Widen => {
+            let widened = v as f64;
+            if widened as i128 == v {
+                Ok(Property::Double(widened))
+            } else {
+                Err(PlyError::Serialize(format!(
+                    "{v} cannot be represented exactly as f64; choose Int64Policy::SplitList to keep full precision"
+                )))
+            }
+        }
+        Int64Policy::SplitList => {
+            let word_count = if bits <= 64 { 2 } else { 4 };
+            Ok(Property::ListUInt(u128_to_words(v as u128, word_count)))
+        }
+    }
+}
+
+/// Encodes an unsigned `u64`/`u128` value (`bits` is 64 or 128) per `policy`. See [`Int64Policy`].
+fn unsigned_int64_property(policy: Int64Policy, v: u128, bits: u32) -> Result<Property, PlyError> {
+    match policy {
+        Int64Policy::Error => {
+            if bits <= 64 {
+                if let Ok(v) = u64::try_from(v) {
+                    return Ok(Property::ULong(v));
+                }
+            }
+            Err(PlyError::Serialize(format!(
+                "{v} does not fit a PLY uint64 property; choose Int64Policy::Widen or ::SplitList"
+            )))
+        }
+        Int64Policy::Widen => {
+            let widened = v as f64;
+            if widened as u128 == v {
+                Ok(Property::Double(widened))
+            } else {
+                Err(PlyError::Serialize(format!(
+                    "{v} cannot be represented exactly as f64; choose Int64Policy::SplitList to keep full precision"
+                )))
+            }
+        }
+        Int64Policy::SplitList => {
+            let word_count = if bits <= 64 { 2 } else { 4 };
+            Ok(Property::ListUInt(u128_to_words(v, word_count)))
+        }
+    }
+}
+
+/// Pairs a deserialized/to-be-serialized value with the PLY header metadata that has no
+/// home in a typed element struct: free-form `comment` and `obj_info` lines. Plain `T`s
+/// read and write their elements exactly as before; wrap in `WithHeader` (with
+/// [`from_reader_with_header`]/[`to_writer_with_header`]) when that provenance needs to
+/// round-trip too - scanner model, units, acquisition date, and the like are routinely
+/// stashed there in real-world PLY files.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct WithHeader<T> {
+    /// The deserialized/to-be-serialized element data.
+    pub value: T,
+    /// The header's `comment` lines, in file order.
+    pub comments: Vec<String>,
+    /// The header's `obj_info` lines, in file order.
+    pub obj_info: Vec<String>,
+}
+
+/// Like [`from_reader`], but also returns the header's `comment`/`obj_info` lines instead of
+/// silently discarding them.
+pub fn from_reader_with_header<R, T>(r: R) -> PlyResult<WithHeader<T>>
+where
+    R: Read,
+    T: de::DeserializeOwned,
+{
+    let mut deserializer = PlyDeserializer::from_reader(r)?;
+    let value = T::deserialize(&mut deserializer)?;
+    Ok(WithHeader {
+        value,
+        comments: deserializer.header.comments.clone(),
+        obj_info: deserializer.header.obj_infos.clone(),
+    })
+}
+
+/// Like [`to_writer`], but also writes `value.comments`/`value.obj_info` into the header.
+pub fn to_writer_with_header<W, T>(w: W, value: &WithHeader<T>) -> PlyResult<()>
+where
+    W: Write,
+    T: Serialize,
+{
+    let mut ply = serialize_to_ply(&value.value, &PlyWriteConfig::default())?;
+    ply.header.comments = value.comments.clone();
+    ply.header.obj_infos = value.obj_info.clone();
+    write_ply(w, ply)
+}
+
+/// Deserialize a PLY file already fully loaded into memory.
+///
+/// This exists as the `&[u8]` counterpart to [`from_reader`] - the entry point serde_cbor's
+/// `Deserializer::from_slice` and similar "I already have the bytes" APIs provide. It is
+/// **not** zero-copy yet: [`PlyDeserializer`] is built on `std::io::Read`/`BufReader`, which
+/// copies into scratch buffers regardless of the source, so today this just wraps `bytes` in
+/// a `Cursor` and runs the same owning path as `from_reader`. Avoiding that copy for binary
+/// property lists whose on-disk byte order matches the host's would need a `Read`-free parser
+/// backend addressed directly against the slice (a `SliceReader` alongside the current
+/// `CountingReader`) - a larger restructuring than this signature change, left for when that
+/// backend exists. Callers get the convenience now and a stable API to retarget later.
+pub fn from_slice<'de, T>(bytes: &'de [u8]) -> PlyResult<T>
+where
+    T: de::DeserializeOwned,
+{
+    from_reader(std::io::Cursor::new(bytes))
+}
+
 // ============================================================================
 // Deserialization
 // ============================================================================
@@ -20,15 +229,28 @@ where
     Ok(t)
 }
 
-struct PlyDeserializer<R: Read> {
+/// Drives a PLY file's header and elements one at a time. [`from_reader`]/[`from_slice`] build
+/// one of these internally and hand the whole `T` off to `serde::Deserialize`, materializing
+/// every element group in memory; [`PlyDeserializer::elements`] is the escape hatch for files
+/// where that's too much - it decodes and yields one element at a time, dropping it before the
+/// next `read_*_element` call, following serde_cbor's reader-based incremental `Deserializer`
+/// model.
+pub struct PlyDeserializer<R: Read> {
     parser: parser::Parser<DefaultElement>,
     reader: BufReader<R>, // Wrap in BufReader to support lines
     header: Header,
     current_element_idx: usize,
+    /// Set when an [`ElementStream`] fails to drain its remaining records on drop (e.g. the
+    /// file was truncated mid-group). Once set, `reader` is at an unknown offset rather than a
+    /// group boundary, so [`PlyDeserializer::elements`] refuses to start another stream instead
+    /// of silently decoding whatever garbage bytes are left.
+    desynced: bool,
 }
 
 impl<R: Read> PlyDeserializer<R> {
-    fn from_reader(r: R) -> PlyResult<Self> {
+    /// Reads just the header, leaving the reader positioned at the start of the first
+    /// element's payload.
+    pub fn from_reader(r: R) -> PlyResult<Self> {
         let parser = parser::Parser::<DefaultElement>::new();
         let mut reader = BufReader::new(r);
         let header = parser.read_header(&mut reader)?;
@@ -37,8 +259,89 @@ impl<R: Read> PlyDeserializer<R> {
             reader,
             header,
             current_element_idx: 0,
+            desynced: false,
+        })
+    }
+
+    /// Streams `element_name`'s group one record at a time instead of collecting it into a
+    /// `Vec`, so a caller can process a billion-point scan in bounded memory.
+    ///
+    /// Any element groups declared before `element_name` are decoded and discarded to advance
+    /// the reader to the right spot; `element_name` itself is consumed by this call, so calling
+    /// `elements` again (for a later group) picks up right after it. The returned
+    /// [`ElementStream`] drains any records the caller didn't iterate over when it's dropped,
+    /// so the reader is always left at the next group's boundary even if iteration stops early.
+    ///
+    /// Returns [`PlyError::Parse`] if `element_name` isn't in the header, or if it names a
+    /// group that an earlier call to `elements` already streamed past. The returned iterator
+    /// itself yields [`PlyError::Io`] (without panicking) if the underlying reader fails or the
+    /// file ends mid-record, except that a binary list cut off part-way surfaces as
+    /// [`PlyError::Parse`].
+    pub fn elements<'a, T>(&'a mut self, element_name: &str) -> PlyResult<ElementStream<'a, R, T>>
+    where
+        T: de::DeserializeOwned,
+    {
+        if self.desynced {
+            return Err(PlyError::Parse(
+                "reader position is unknown after a previous element stream failed to drain \
+                 its remaining records".to_string(),
+            ));
+        }
+
+        let target_idx = self.header.elements.keys().position(|name| name == element_name)
+            .ok_or_else(|| PlyError::Parse(format!(
+                "Element '{}' not found in header", element_name,
+            )))?;
+
+        if target_idx < self.current_element_idx {
+            return Err(PlyError::Parse(format!(
+                "Element '{}' was already streamed by an earlier call to `elements`", element_name,
+            )));
+        }
+
+        while self.current_element_idx < target_idx {
+            let name = self.header.elements.keys().nth(self.current_element_idx).unwrap().clone();
+            let element_def = self.header.elements.get(&name).unwrap().clone();
+            for skipped in self.parser.element_iter(&mut self.reader, &element_def, &self.header) {
+                skipped?;
+            }
+            self.current_element_idx += 1;
+        }
+        self.current_element_idx += 1;
+
+        let element_def = self.header.elements.get(element_name).unwrap().clone();
+        Ok(ElementStream {
+            de: self,
+            element_def,
+            current_count: 0,
+            marker: std::marker::PhantomData,
         })
     }
+
+    /// Decodes one record of `element_def` off `self.reader`, dispatching on the header's
+    /// encoding - the single place [`PlyElementSeqAccess`] and [`ElementStream`] both go
+    /// through so the three `read_*_element` call sites don't drift out of sync.
+    fn read_one_element(&mut self, element_def: &ElementDef) -> PlyResult<DefaultElement> {
+        match self.header.encoding {
+            Encoding::Ascii => {
+                let mut line = String::new();
+                let read = self.reader.read_line(&mut line).map_err(PlyError::Io)?;
+                if read == 0 {
+                    return Err(PlyError::Io(std::io::Error::new(
+                        std::io::ErrorKind::UnexpectedEof,
+                        format!("Unexpected end of file while reading element '{}'.", element_def.name),
+                    )));
+                }
+                self.parser.read_ascii_element(&line, element_def)
+            },
+            Encoding::BinaryBigEndian => {
+                self.parser.read_big_endian_element(&mut self.reader, element_def)
+            },
+            Encoding::BinaryLittleEndian => {
+                self.parser.read_little_endian_element(&mut self.reader, element_def)
+            },
+        }
+    }
 }
 
 impl<'de, 'a, R: Read> Deserializer<'de> for &'a mut PlyDeserializer<R> {
@@ -80,11 +383,14 @@ impl<'de, 'a, R: Read> Deserializer<'de> for &'a mut PlyDeserializer<R> {
 
 struct PlyMapAccess<'a, R: Read> {
     de: &'a mut PlyDeserializer<R>,
+    /// How many of the two reserved metadata keys ([`COMMENTS_KEY`], [`OBJ_INFO_KEY`]) have
+    /// already been handed out, once `de.current_element_idx` runs past the real elements.
+    synthetic_idx: usize,
 }
 
 impl<'a, R: Read> PlyMapAccess<'a, R> {
     fn new(de: &'a mut PlyDeserializer<R>) -> Self {
-        PlyMapAccess { de }
+        PlyMapAccess { de, synthetic_idx: 0 }
     }
 }
 
@@ -95,11 +401,17 @@ impl<'de, 'a, R: Read> de::MapAccess<'de> for PlyMapAccess<'a, R> {
     where
         K: de::DeserializeSeed<'de>,
     {
-        if self.de.current_element_idx >= self.de.header.elements.len() {
-            return Ok(None);
+        if self.de.current_element_idx < self.de.header.elements.len() {
+            let element_name = self.de.header.elements.keys().nth(self.de.current_element_idx).unwrap().clone();
+            return seed.deserialize(de::IntoDeserializer::into_deserializer(element_name))
+                .map(Some);
         }
-        let element_name = self.de.header.elements.keys().nth(self.de.current_element_idx).unwrap().clone();
-        seed.deserialize(de::IntoDeserializer::into_deserializer(element_name))
+        let key = match self.synthetic_idx {
+            0 => COMMENTS_KEY,
+            1 => OBJ_INFO_KEY,
+            _ => return Ok(None),
+        };
+        seed.deserialize(de::IntoDeserializer::into_deserializer(key))
             .map(Some)
     }
 
@@ -107,18 +419,28 @@ impl<'de, 'a, R: Read> de::MapAccess<'de> for PlyMapAccess<'a, R> {
     where
         V: de::DeserializeSeed<'de>,
     {
-        let element_name = self.de.header.elements.keys().nth(self.de.current_element_idx).unwrap().clone();
-        let element_def = self.de.header.elements.get(&element_name).unwrap().clone();
-        
-        self.de.current_element_idx += 1;
+        if self.de.current_element_idx < self.de.header.elements.len() {
+            let element_name = self.de.header.elements.keys().nth(self.de.current_element_idx).unwrap().clone();
+            let element_def = self.de.header.elements.get(&element_name).unwrap().clone();
 
-        let seq_access = PlyElementSeqAccess {
-            de: self.de,
-            element_def,
-            current_count: 0,
-        };
+            self.de.current_element_idx += 1;
+
+            let seq_access = PlyElementSeqAccess {
+                de: self.de,
+                element_def,
+                current_count: 0,
+            };
+
+            return seed.deserialize(SeqDeserializer(seq_access));
+        }
 
-        seed.deserialize(SeqDeserializer(seq_access))
+        let list = match self.synthetic_idx {
+            0 => self.de.header.comments.clone(),
+            1 => self.de.header.obj_infos.clone(),
+            _ => unreachable!("next_value_seed called without a matching next_key_seed"),
+        };
+        self.synthetic_idx += 1;
+        seed.deserialize(de::value::SeqDeserializer::new(list.into_iter()))
     }
 }
 
@@ -158,19 +480,7 @@ impl<'de, 'a, R: Read> de::SeqAccess<'de> for PlyElementSeqAccess<'a, R> {
 
         self.current_count += 1;
 
-        let element = match self.de.header.encoding {
-            Encoding::Ascii => {
-                let mut line = String::new();
-                self.de.reader.read_line(&mut line).map_err(PlyError::Io)?;
-                self.de.parser.read_ascii_element(&line, &self.element_def)?
-            },
-            Encoding::BinaryBigEndian => {
-                self.de.parser.read_big_endian_element(&mut self.de.reader, &self.element_def)?
-            },
-            Encoding::BinaryLittleEndian => {
-                self.de.parser.read_little_endian_element(&mut self.de.reader, &self.element_def)?
-            },
-        };
+        let element = self.de.read_one_element(&self.element_def)?;
 
         let element_deserializer = ElementDeserializer {
             element,
@@ -181,6 +491,67 @@ impl<'de, 'a, R: Read> de::SeqAccess<'de> for PlyElementSeqAccess<'a, R> {
     }
 }
 
+/// Iterator returned by [`PlyDeserializer::elements`]. Decodes one record per `next()` call
+/// via the same `read_*_element` path as [`PlyElementSeqAccess`], deserializing it through
+/// [`ElementDeserializer`] and dropping it before the next call reads the following record.
+pub struct ElementStream<'a, R: Read, T> {
+    de: &'a mut PlyDeserializer<R>,
+    element_def: ElementDef,
+    current_count: usize,
+    marker: std::marker::PhantomData<T>,
+}
+
+impl<'a, R: Read, T> Iterator for ElementStream<'a, R, T>
+where
+    T: de::DeserializeOwned,
+{
+    type Item = PlyResult<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current_count >= self.element_def.count {
+            return None;
+        }
+        self.current_count += 1;
+
+        let element = match self.de.read_one_element(&self.element_def) {
+            Ok(element) => element,
+            Err(e) => return Some(Err(e)),
+        };
+
+        let element_deserializer = ElementDeserializer {
+            element,
+            element_def: &self.element_def,
+        };
+
+        Some(T::deserialize(element_deserializer))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.element_def.count - self.current_count;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, R: Read, T> ExactSizeIterator for ElementStream<'a, R, T> where T: de::DeserializeOwned {}
+
+impl<'a, R: Read, T> Drop for ElementStream<'a, R, T> {
+    /// If the caller stopped iterating before this group's `count` records were all read,
+    /// drains whatever is left so the reader still lands exactly on the next group's first
+    /// byte - otherwise a later `PlyDeserializer::elements` call would read garbage out of the
+    /// middle of this group's payload instead of its own. If draining itself fails (e.g. the
+    /// file was truncated), the reader's position can no longer be trusted, so the underlying
+    /// [`PlyDeserializer`] is marked `desynced` and refuses to start another stream.
+    fn drop(&mut self) {
+        while self.current_count < self.element_def.count {
+            self.current_count += 1;
+            if self.de.read_one_element(&self.element_def).is_err() {
+                self.de.desynced = true;
+                break;
+            }
+        }
+    }
+}
+
 struct ElementDeserializer<'b> {
     element: DefaultElement,
     element_def: &'b ElementDef,
@@ -257,19 +628,25 @@ impl<'de, 'a> Deserializer<'de> for PropertyDeserializer<'a> {
             Property::UShort(v) => visitor.visit_u16(*v),
             Property::Int(v) => visitor.visit_i32(*v),
             Property::UInt(v) => visitor.visit_u32(*v),
+            Property::Half(v) => visitor.visit_f32(v.to_f32()),
             Property::Float(v) => visitor.visit_f32(*v),
             Property::Double(v) => visitor.visit_f64(*v),
+            Property::Long(v) => visitor.visit_i64(*v),
+            Property::ULong(v) => visitor.visit_u64(*v),
             Property::ListChar(v) => visitor.visit_seq(de::value::SeqDeserializer::new(v.iter().cloned())),
             Property::ListUChar(v) => visitor.visit_seq(de::value::SeqDeserializer::new(v.iter().cloned())),
             Property::ListShort(v) => visitor.visit_seq(de::value::SeqDeserializer::new(v.iter().cloned())),
             Property::ListUShort(v) => visitor.visit_seq(de::value::SeqDeserializer::new(v.iter().cloned())),
             Property::ListInt(v) => visitor.visit_seq(de::value::SeqDeserializer::new(v.iter().cloned())),
             Property::ListUInt(v) => visitor.visit_seq(de::value::SeqDeserializer::new(v.iter().cloned())),
+            Property::ListHalf(v) => visitor.visit_seq(de::value::SeqDeserializer::new(v.iter().map(|x| x.to_f32()))),
             Property::ListFloat(v) => visitor.visit_seq(de::value::SeqDeserializer::new(v.iter().cloned())),
             Property::ListDouble(v) => visitor.visit_seq(de::value::SeqDeserializer::new(v.iter().cloned())),
+            Property::ListLong(v) => visitor.visit_seq(de::value::SeqDeserializer::new(v.iter().cloned())),
+            Property::ListULong(v) => visitor.visit_seq(de::value::SeqDeserializer::new(v.iter().cloned())),
         }
     }
-    
+
     fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, Self::Error> where V: de::Visitor<'de> {
         self.deserialize_any(visitor)
     }
@@ -315,8 +692,21 @@ impl<'de, 'a> Deserializer<'de> for PropertyDeserializer<'a> {
     fn deserialize_char<V>(self, visitor: V) -> Result<V::Value, Self::Error> where V: de::Visitor<'de> { self.deserialize_any(visitor) }
     fn deserialize_str<V>(self, visitor: V) -> Result<V::Value, Self::Error> where V: de::Visitor<'de> { self.deserialize_any(visitor) }
     fn deserialize_string<V>(self, visitor: V) -> Result<V::Value, Self::Error> where V: de::Visitor<'de> { self.deserialize_any(visitor) }
-    fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value, Self::Error> where V: de::Visitor<'de> { self.deserialize_any(visitor) }
-    fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value, Self::Error> where V: de::Visitor<'de> { self.deserialize_any(visitor) }
+    // `ListUChar` feeds `visit_bytes`/`visit_byte_buf` directly instead of going through
+    // `deserialize_any`'s `visit_seq`, so `serde_bytes`/`Vec<u8>` fields get the fast path
+    // that mirrors `serialize_bytes` on the writer side.
+    fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value, Self::Error> where V: de::Visitor<'de> {
+        match self.0 {
+            Property::ListUChar(v) => visitor.visit_bytes(v),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+    fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value, Self::Error> where V: de::Visitor<'de> {
+        match self.0 {
+            Property::ListUChar(v) => visitor.visit_byte_buf(v.clone()),
+            _ => self.deserialize_any(visitor),
+        }
+    }
     fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error> where V: de::Visitor<'de> { self.deserialize_any(visitor) }
     fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value, Self::Error> where V: de::Visitor<'de> { self.deserialize_any(visitor) }
     fn deserialize_unit_struct<V>(self, _name: &'static str, visitor: V) -> Result<V::Value, Self::Error> where V: de::Visitor<'de> { self.deserialize_any(visitor) }
@@ -330,33 +720,277 @@ impl<'de, 'a> Deserializer<'de> for PropertyDeserializer<'a> {
     fn deserialize_struct<V>(self, _name: &'static str, _fields: &'static [&'static str], visitor: V) -> Result<V::Value, Self::Error> where V: de::Visitor<'de> { self.deserialize_any(visitor) }
 }
 
+/// Deserializes a single already-parsed [`DefaultElement`] the caller keeps alive for `'de`,
+/// instead of handing [`ElementDeserializer`] ownership of it. This is what lets
+/// [`BorrowedPropertyDeserializer`] call `visit_borrowed_bytes` for a `ListUChar` property:
+/// the byte slice's lifetime is tied to `element` itself rather than to the deserializer call,
+/// so a target field typed `&'de [u8]` (e.g. via `serde_bytes`) borrows straight out of it with
+/// no allocation.
+///
+/// [`PlyDeserializer::elements`] can't use this directly - its `ElementStream` parses one
+/// element at a time and hands ownership to the caller via the returned `T`, so nothing lives
+/// long enough to borrow from. Use this instead when a [`DefaultElement`] is already sitting
+/// in a buffer the caller controls - e.g. one payload element out of a parsed [`Ply`] - and
+/// stays in scope while `T` is deserialized from it.
+pub fn from_element_borrowed<'de, T>(element: &'de DefaultElement, element_def: &ElementDef) -> PlyResult<T>
+where
+    T: de::Deserialize<'de>,
+{
+    T::deserialize(BorrowedElementDeserializer { element, element_def })
+}
+
+struct BorrowedElementDeserializer<'de, 'b> {
+    element: &'de DefaultElement,
+    element_def: &'b ElementDef,
+}
+
+impl<'de, 'b> Deserializer<'de> for BorrowedElementDeserializer<'de, 'b> {
+    type Error = PlyError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error> where V: de::Visitor<'de> {
+        self.deserialize_map(visitor)
+    }
+
+    fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, Self::Error> where V: de::Visitor<'de> {
+        visitor.visit_map(BorrowedElementPropertyAccess {
+            element: self.element,
+            element_def: self.element_def,
+            current_prop_idx: 0,
+        })
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct struct enum identifier ignored_any
+    }
+}
+
+struct BorrowedElementPropertyAccess<'de, 'b> {
+    element: &'de DefaultElement,
+    element_def: &'b ElementDef,
+    current_prop_idx: usize,
+}
+
+impl<'de, 'b> de::MapAccess<'de> for BorrowedElementPropertyAccess<'de, 'b> {
+    type Error = PlyError;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: de::DeserializeSeed<'de>,
+    {
+        if self.current_prop_idx >= self.element_def.properties.len() {
+            return Ok(None);
+        }
+
+        let prop_name = self.element_def.properties.keys().nth(self.current_prop_idx).unwrap().clone();
+        seed.deserialize(de::IntoDeserializer::into_deserializer(prop_name)).map(Some)
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::DeserializeSeed<'de>,
+    {
+        let prop_name = self.element_def.properties.keys().nth(self.current_prop_idx).unwrap().clone();
+        let prop_value = self.element.get(&prop_name).unwrap();
+
+        self.current_prop_idx += 1;
+
+        seed.deserialize(BorrowedPropertyDeserializer(prop_value))
+    }
+}
+
+/// Zero-copy counterpart of [`PropertyDeserializer`]: holds a `&'de Property` instead of a
+/// reference scoped to the call, so a `ListUChar` property can be handed to
+/// `visit_borrowed_bytes` directly. Every other property type has no serde primitive for a
+/// borrowed typed slice (`visit_borrowed_bytes`/`visit_borrowed_str` are the only two), so
+/// those still fall back to [`PropertyDeserializer::deserialize_any`]'s owned `visit_seq`.
+struct BorrowedPropertyDeserializer<'de>(&'de Property);
+
+impl<'de> Deserializer<'de> for BorrowedPropertyDeserializer<'de> {
+    type Error = PlyError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error> where V: de::Visitor<'de> {
+        PropertyDeserializer(self.0).deserialize_any(visitor)
+    }
+
+    fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, Self::Error> where V: de::Visitor<'de> {
+        self.deserialize_any(visitor)
+    }
+
+    // `ListUChar` is the one property shape with a borrowed serde primitive
+    // (`visit_borrowed_bytes`); everything else forwards to the owned path.
+    fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value, Self::Error> where V: de::Visitor<'de> {
+        match self.0 {
+            Property::ListUChar(v) => visitor.visit_borrowed_bytes(v),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+    fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value, Self::Error> where V: de::Visitor<'de> {
+        match self.0 {
+            Property::ListUChar(v) => visitor.visit_byte_buf(v.clone()),
+            _ => self.deserialize_any(visitor),
+        }
+    }
+    // Mirror `PropertyDeserializer`'s float-to-int rounding so routing a property through the
+    // borrowed path doesn't change what a `double`/`float` column reads into an integer field.
+    fn deserialize_i64<V>(self, visitor: V) -> Result<V::Value, Self::Error> where V: de::Visitor<'de> {
+        PropertyDeserializer(self.0).deserialize_i64(visitor)
+    }
+    fn deserialize_i128<V>(self, visitor: V) -> Result<V::Value, Self::Error> where V: de::Visitor<'de> {
+        PropertyDeserializer(self.0).deserialize_i128(visitor)
+    }
+    fn deserialize_u64<V>(self, visitor: V) -> Result<V::Value, Self::Error> where V: de::Visitor<'de> {
+        PropertyDeserializer(self.0).deserialize_u64(visitor)
+    }
+    fn deserialize_u128<V>(self, visitor: V) -> Result<V::Value, Self::Error> where V: de::Visitor<'de> {
+        PropertyDeserializer(self.0).deserialize_u128(visitor)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 u8 u16 u32 f32 f64 char str string
+        option unit unit_struct newtype_struct seq tuple
+        tuple_struct struct enum identifier ignored_any
+    }
+}
+
 // ============================================================================
 // Serialization
 // ============================================================================
 
 /// Serialize a struct to a PLY file.
+///
+/// Uses [`PlyWriteConfig::default`] - ascii encoding and `ScalarType::UChar` list lengths.
+/// See [`to_writer_with`] to pick a binary encoding or a wider list length type.
 pub fn to_writer<W, T>(w: W, value: &T) -> PlyResult<()>
 where
     W: Write,
     T: Serialize,
 {
-    let mut serializer = PlySerializer::new();
+    let ply = serialize_to_ply(value, &PlyWriteConfig::default())?;
+    write_ply(w, ply)
+}
+
+/// Like [`to_writer`], but `config` picks the output [`Encoding`] and the `ScalarType` used
+/// for list property lengths.
+///
+/// The default [`to_writer`] always writes ascii with `uchar` list lengths, which fails with
+/// [`PlyError::Serialize`] for a list whose length exceeds 255 and rules out the compact binary files most
+/// downstream tools actually want. Pick `ScalarType::UShort`/`UInt` here for faces with more
+/// than 255 vertices.
+pub fn to_writer_with<W, T>(w: W, value: &T, config: PlyWriteConfig) -> PlyResult<()>
+where
+    W: Write,
+    T: Serialize,
+{
+    let ply = serialize_to_ply(value, &config)?;
+    write_ply(w, ply)
+}
+
+/// Selects the output [`Encoding`] and list-length [`ScalarType`] used by [`to_writer_with`].
+///
+/// `Default` reproduces the historical [`to_writer`] behavior: ascii encoding, `uchar` list
+/// lengths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlyWriteConfig {
+    /// Encoding the payload is written with.
+    pub encoding: Encoding,
+    /// `ScalarType` used for the length prefix of every list property.
+    pub list_len_type: ScalarType,
+    /// How enum variants are encoded - see [`EnumRepr`].
+    pub enum_repr: EnumRepr,
+    /// How `i64`/`u64`/`i128`/`u128` values are encoded - see [`Int64Policy`].
+    pub int64_policy: Int64Policy,
+}
+
+impl Default for PlyWriteConfig {
+    fn default() -> Self {
+        PlyWriteConfig {
+            encoding: Encoding::Ascii,
+            list_len_type: ScalarType::UChar,
+            enum_repr: EnumRepr::default(),
+            int64_policy: Int64Policy::default(),
+        }
+    }
+}
+
+/// Like [`to_writer`], but instead of inferring each property's [`PropertyType`] from the
+/// first serialized value, every property is coerced to the exact type declared in
+/// `header`. This fixes two gaps in inference: an empty list would otherwise always become
+/// `Property::ListInt` regardless of its real element type, and `i64`/`u64` scalars would
+/// always become `Property::Long`/`ULong` (under the default [`Int64Policy`]) even where the
+/// header declares a narrower type. Mirrors how avro-rs resolves a serialized value against a
+/// target schema instead of inferring one.
+///
+/// `header` is written as given (encoding, comments, obj_info, property order and list
+/// length types included) except that each element's `count` is overwritten with the
+/// number of values `value` actually serialized for it. Every element/property name found
+/// in the serialized payload must have a matching declaration in `header`, and every value
+/// must fit the declared type (see [`Property::coerce_to`]) - otherwise this returns
+/// [`PlyError::Serialize`].
+pub fn to_writer_with_schema<W, T>(w: W, value: &T, header: &Header) -> PlyResult<()>
+where
+    W: Write,
+    T: Serialize,
+{
+    let mut serializer = PlySerializer::new(EnumRepr::default(), Int64Policy::default());
     value.serialize(&mut serializer)?;
-    
+    let mut ply = serializer.ply;
+    ply.header = header.clone();
+
+    for (element_name, elements) in ply.payload.iter_mut() {
+        let element_def = ply.header.elements.get(element_name).ok_or_else(|| {
+            PlyError::Serialize(format!("Element '{}' is not declared in the given header", element_name))
+        })?;
+        for element in elements.iter_mut() {
+            for (prop_name, prop_val) in element.iter_mut() {
+                let prop_def = element_def.properties.get(prop_name).ok_or_else(|| {
+                    PlyError::Serialize(format!(
+                        "Property '{}' on element '{}' is not declared in the given header",
+                        prop_name, element_name,
+                    ))
+                })?;
+                *prop_val = prop_val.coerce_to(&prop_def.data_type).ok_or_else(|| {
+                    PlyError::Serialize(format!(
+                        "Property '{}' on element '{}' doesn't fit the declared type {:?}",
+                        prop_name, element_name, prop_def.data_type,
+                    ))
+                })?;
+            }
+        }
+    }
+    for (element_name, element_def) in ply.header.elements.iter_mut() {
+        element_def.count = ply.payload.get(element_name).map_or(0, |elements| elements.len());
+    }
+
+    write_ply(w, ply)
+}
+
+/// Runs `value` through [`PlySerializer`] and infers `header.elements` from the resulting
+/// payload, same as [`to_writer`] - factored out so [`to_writer_with_header`] can inject
+/// `comments`/`obj_info` before the header reaches the writer.
+fn serialize_to_ply<T>(value: &T, config: &PlyWriteConfig) -> PlyResult<Ply<DefaultElement>>
+where
+    T: Serialize + ?Sized,
+{
+    let mut serializer = PlySerializer::new(config.enum_repr, config.int64_policy);
+    value.serialize(&mut serializer)?;
+
     // Construct header from collected data
     let mut ply = serializer.ply;
-    
+    ply.header.encoding = config.encoding;
+
     // We need to infer header if it's empty.
     // The serializer populates ply.payload.
     // ply.header should be constructed based on payload keys and first element of each list.
-    
+
     // If header is empty (which it is by default), we infer.
     if ply.header.elements.is_empty() {
         for (name, list) in &ply.payload {
             let count = list.len();
             let mut elem_def = ElementDef::new(name.clone());
             elem_def.count = count;
-            
+
             if let Some(first) = list.first() {
                 // Infer properties from the first element
                 for (prop_name, prop_val) in first {
@@ -367,16 +1001,22 @@ where
                         Property::UShort(_) => PropertyType::Scalar(ScalarType::UShort),
                         Property::Int(_) => PropertyType::Scalar(ScalarType::Int),
                         Property::UInt(_) => PropertyType::Scalar(ScalarType::UInt),
+                        Property::Half(_) => PropertyType::Scalar(ScalarType::Half),
                         Property::Float(_) => PropertyType::Scalar(ScalarType::Float),
                         Property::Double(_) => PropertyType::Scalar(ScalarType::Double),
-                        Property::ListChar(_) => PropertyType::List(ScalarType::UChar, ScalarType::Char), // Lists usually store length as uchar or int
-                        Property::ListUChar(_) => PropertyType::List(ScalarType::UChar, ScalarType::UChar),
-                        Property::ListShort(_) => PropertyType::List(ScalarType::UChar, ScalarType::Short),
-                        Property::ListUShort(_) => PropertyType::List(ScalarType::UChar, ScalarType::UShort),
-                        Property::ListInt(_) => PropertyType::List(ScalarType::UChar, ScalarType::Int),
-                        Property::ListUInt(_) => PropertyType::List(ScalarType::UChar, ScalarType::UInt),
-                        Property::ListFloat(_) => PropertyType::List(ScalarType::UChar, ScalarType::Float),
-                        Property::ListDouble(_) => PropertyType::List(ScalarType::UChar, ScalarType::Double),
+                        Property::Long(_) => PropertyType::Scalar(ScalarType::Long),
+                        Property::ULong(_) => PropertyType::Scalar(ScalarType::ULong),
+                        Property::ListChar(_) => PropertyType::List(config.list_len_type, ScalarType::Char),
+                        Property::ListUChar(_) => PropertyType::List(config.list_len_type, ScalarType::UChar),
+                        Property::ListShort(_) => PropertyType::List(config.list_len_type, ScalarType::Short),
+                        Property::ListUShort(_) => PropertyType::List(config.list_len_type, ScalarType::UShort),
+                        Property::ListInt(_) => PropertyType::List(config.list_len_type, ScalarType::Int),
+                        Property::ListUInt(_) => PropertyType::List(config.list_len_type, ScalarType::UInt),
+                        Property::ListHalf(_) => PropertyType::List(config.list_len_type, ScalarType::Half),
+                        Property::ListFloat(_) => PropertyType::List(config.list_len_type, ScalarType::Float),
+                        Property::ListDouble(_) => PropertyType::List(config.list_len_type, ScalarType::Double),
+                        Property::ListLong(_) => PropertyType::List(config.list_len_type, ScalarType::Long),
+                        Property::ListULong(_) => PropertyType::List(config.list_len_type, ScalarType::ULong),
                     };
                     elem_def.properties.add(PropertyDef::new(prop_name.clone(), type_def));
                 }
@@ -385,20 +1025,28 @@ where
         }
     }
 
+    Ok(ply)
+}
+
+/// Writes an already-assembled [`Ply`] out via [`writer::Writer`].
+fn write_ply<W: Write>(mut w: W, mut ply: Ply<DefaultElement>) -> PlyResult<()> {
     let writer = writer::Writer::new();
-    let mut w = w;
     writer.write_ply(&mut w, &mut ply)?;
     Ok(())
 }
 
 struct PlySerializer {
     ply: Ply<DefaultElement>,
+    enum_repr: EnumRepr,
+    int64_policy: Int64Policy,
 }
 
 impl PlySerializer {
-    fn new() -> Self {
+    fn new(enum_repr: EnumRepr, int64_policy: Int64Policy) -> Self {
         PlySerializer {
             ply: Ply::new(),
+            enum_repr,
+            int64_policy,
         }
     }
 }
@@ -465,11 +1113,11 @@ impl<'a> ser::Serializer for &'a mut PlySerializer {
     }
 
     fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
-        Ok(MapSerializer { ply: &mut self.ply })
+        Ok(MapSerializer { ply: &mut self.ply, enum_repr: self.enum_repr, int64_policy: self.int64_policy })
     }
 
     fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct, Self::Error> {
-        Ok(StructSerializer { ply: &mut self.ply })
+        Ok(StructSerializer { ply: &mut self.ply, enum_repr: self.enum_repr, int64_policy: self.int64_policy })
     }
 
     fn serialize_struct_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _len: usize) -> Result<Self::SerializeStructVariant, Self::Error> {
@@ -479,6 +1127,8 @@ impl<'a> ser::Serializer for &'a mut PlySerializer {
 
 struct MapSerializer<'a> {
     ply: &'a mut Ply<DefaultElement>,
+    enum_repr: EnumRepr,
+    int64_policy: Int64Policy,
 }
 
 impl<'a> ser::SerializeMap for MapSerializer<'a> {
@@ -501,11 +1151,23 @@ impl<'a> ser::SerializeMap for MapSerializer<'a> {
     fn serialize_entry<K: ?Sized + Serialize, V: ?Sized + Serialize>(&mut self, key: &K, value: &V) -> Result<(), Self::Error> {
         // HACK: Serialize key to string
         let key_str = KeySerializer::serialize_key(key)?;
-        
+
+        match key_str.as_str() {
+            COMMENTS_KEY => {
+                self.ply.header.comments = serialize_string_list(value)?;
+                return Ok(());
+            }
+            OBJ_INFO_KEY => {
+                self.ply.header.obj_infos = serialize_string_list(value)?;
+                return Ok(());
+            }
+            _ => {}
+        }
+
         // Value must be a sequence of elements
-        let mut element_list_serializer = ElementListSerializer { elements: Vec::new() };
+        let mut element_list_serializer = ElementListSerializer { elements: Vec::new(), enum_repr: self.enum_repr, int64_policy: self.int64_policy };
         value.serialize(&mut element_list_serializer)?;
-        
+
         self.ply.payload.insert(key_str, element_list_serializer.elements);
         Ok(())
     }
@@ -517,6 +1179,8 @@ impl<'a> ser::SerializeMap for MapSerializer<'a> {
 
 struct StructSerializer<'a> {
     ply: &'a mut Ply<DefaultElement>,
+    enum_repr: EnumRepr,
+    int64_policy: Int64Policy,
 }
 
 impl<'a> ser::SerializeStruct for StructSerializer<'a> {
@@ -524,10 +1188,22 @@ impl<'a> ser::SerializeStruct for StructSerializer<'a> {
     type Error = PlyError;
 
     fn serialize_field<T: ?Sized + Serialize>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error> {
+        match key {
+            COMMENTS_KEY => {
+                self.ply.header.comments = serialize_string_list(value)?;
+                return Ok(());
+            }
+            OBJ_INFO_KEY => {
+                self.ply.header.obj_infos = serialize_string_list(value)?;
+                return Ok(());
+            }
+            _ => {}
+        }
+
         // Value must be a sequence of elements
-        let mut element_list_serializer = ElementListSerializer { elements: Vec::new() };
+        let mut element_list_serializer = ElementListSerializer { elements: Vec::new(), enum_repr: self.enum_repr, int64_policy: self.int64_policy };
         value.serialize(&mut element_list_serializer)?;
-        
+
         self.ply.payload.insert(key.to_string(), element_list_serializer.elements);
         Ok(())
     }
@@ -587,8 +1263,93 @@ impl KeySerializer {
     }
 }
 
+/// Serializes a `Vec<String>`-shaped value (as found under [`COMMENTS_KEY`]/[`OBJ_INFO_KEY`])
+/// into an owned `Vec<String>`, reusing [`KeySerializer`] for each element.
+fn serialize_string_list<T: ?Sized + Serialize>(value: &T) -> Result<Vec<String>, PlyError> {
+    let mut serializer = StringListSerializer { items: Vec::new() };
+    value.serialize(&mut serializer)?;
+    Ok(serializer.items)
+}
+
+struct StringListSerializer {
+    items: Vec<String>,
+}
+
+impl<'a> ser::Serializer for &'a mut StringListSerializer {
+    type Ok = ();
+    type Error = PlyError;
+    type SerializeSeq = StringListSeqSerializer<'a>;
+    type SerializeTuple = ser::Impossible<(), PlyError>;
+    type SerializeTupleStruct = ser::Impossible<(), PlyError>;
+    type SerializeTupleVariant = ser::Impossible<(), PlyError>;
+    type SerializeMap = ser::Impossible<(), PlyError>;
+    type SerializeStruct = ser::Impossible<(), PlyError>;
+    type SerializeStructVariant = ser::Impossible<(), PlyError>;
+
+    fn serialize_bool(self, _v: bool) -> Result<Self::Ok, Self::Error> { err_expected_string_list() }
+    fn serialize_i8(self, _v: i8) -> Result<Self::Ok, Self::Error> { err_expected_string_list() }
+    fn serialize_i16(self, _v: i16) -> Result<Self::Ok, Self::Error> { err_expected_string_list() }
+    fn serialize_i32(self, _v: i32) -> Result<Self::Ok, Self::Error> { err_expected_string_list() }
+    fn serialize_i64(self, _v: i64) -> Result<Self::Ok, Self::Error> { err_expected_string_list() }
+    fn serialize_i128(self, _v: i128) -> Result<Self::Ok, Self::Error> { err_expected_string_list() }
+    fn serialize_u8(self, _v: u8) -> Result<Self::Ok, Self::Error> { err_expected_string_list() }
+    fn serialize_u16(self, _v: u16) -> Result<Self::Ok, Self::Error> { err_expected_string_list() }
+    fn serialize_u32(self, _v: u32) -> Result<Self::Ok, Self::Error> { err_expected_string_list() }
+    fn serialize_u64(self, _v: u64) -> Result<Self::Ok, Self::Error> { err_expected_string_list() }
+    fn serialize_u128(self, _v: u128) -> Result<Self::Ok, Self::Error> { err_expected_string_list() }
+    fn serialize_f32(self, _v: f32) -> Result<Self::Ok, Self::Error> { err_expected_string_list() }
+    fn serialize_f64(self, _v: f64) -> Result<Self::Ok, Self::Error> { err_expected_string_list() }
+    fn serialize_char(self, _v: char) -> Result<Self::Ok, Self::Error> { err_expected_string_list() }
+    fn serialize_str(self, _v: &str) -> Result<Self::Ok, Self::Error> { err_expected_string_list() }
+    fn serialize_bytes(self, _v: &[u8]) -> Result<Self::Ok, Self::Error> { err_expected_string_list() }
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Self::Ok, Self::Error> { value.serialize(self) }
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> { err_expected_string_list() }
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> { err_expected_string_list() }
+    fn serialize_unit_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str) -> Result<Self::Ok, Self::Error> { err_expected_string_list() }
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(self, _name: &'static str, value: &T) -> Result<Self::Ok, Self::Error> { value.serialize(self) }
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _value: &T) -> Result<Self::Ok, Self::Error> {
+        err_expected_string_list()
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Ok(StringListSeqSerializer { list: self })
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> { err_expected_string_list() }
+    fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeTupleStruct, Self::Error> { err_expected_string_list() }
+    fn serialize_tuple_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _len: usize) -> Result<Self::SerializeTupleVariant, Self::Error> { err_expected_string_list() }
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> { err_expected_string_list() }
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct, Self::Error> { err_expected_string_list() }
+    fn serialize_struct_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _len: usize) -> Result<Self::SerializeStructVariant, Self::Error> { err_expected_string_list() }
+}
+
+struct StringListSeqSerializer<'a> {
+    list: &'a mut StringListSerializer,
+}
+
+impl<'a> ser::SerializeSeq for StringListSeqSerializer<'a> {
+    type Ok = ();
+    type Error = PlyError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.list.items.push(KeySerializer::serialize_key(value)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+}
+
+fn err_expected_string_list<T>() -> Result<T, PlyError> {
+    Err(PlyError::Serialize(format!("Expected a sequence of strings for '{COMMENTS_KEY}'/'{OBJ_INFO_KEY}'")))
+}
+
 struct ElementListSerializer {
     elements: Vec<DefaultElement>,
+    enum_repr: EnumRepr,
+    int64_policy: Int64Policy,
 }
 
 impl<'a> ser::Serializer for &'a mut ElementListSerializer {
@@ -630,9 +1391,9 @@ impl<'a> ser::Serializer for &'a mut ElementListSerializer {
     }
     
     fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
-        Ok(ElementListSeqSerializer { list: self })
+        Ok(ElementListSeqSerializer { enum_repr: self.enum_repr, int64_policy: self.int64_policy, list: self })
     }
-    
+
     fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> { err_expected_sequence() }
     fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeTupleStruct, Self::Error> { err_expected_sequence() }
     fn serialize_tuple_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _len: usize) -> Result<Self::SerializeTupleVariant, Self::Error> { err_expected_sequence() }
@@ -643,6 +1404,8 @@ impl<'a> ser::Serializer for &'a mut ElementListSerializer {
 
 struct ElementListSeqSerializer<'a> {
     list: &'a mut ElementListSerializer,
+    enum_repr: EnumRepr,
+    int64_policy: Int64Policy,
 }
 
 impl<'a> ser::SerializeSeq for ElementListSeqSerializer<'a> {
@@ -650,7 +1413,7 @@ impl<'a> ser::SerializeSeq for ElementListSeqSerializer<'a> {
     type Error = PlyError;
 
     fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
-        let mut element_serializer = ElementSerializer { element: DefaultElement::new() };
+        let mut element_serializer = ElementSerializer { element: DefaultElement::new(), enum_repr: self.enum_repr, int64_policy: self.int64_policy };
         value.serialize(&mut element_serializer)?;
         self.list.elements.push(element_serializer.element);
         Ok(())
@@ -663,6 +1426,8 @@ impl<'a> ser::SerializeSeq for ElementListSeqSerializer<'a> {
 
 struct ElementSerializer {
     element: DefaultElement,
+    enum_repr: EnumRepr,
+    int64_policy: Int64Policy,
 }
 
 fn err_must_be_struct_or_map<T>() -> Result<T, PlyError> {
@@ -691,7 +1456,7 @@ impl<'a> ser::Serializer for &'a mut ElementSerializer {
     type SerializeTupleVariant = ser::Impossible<(), PlyError>;
     type SerializeMap = ElementMapSerializer<'a>;
     type SerializeStruct = ElementStructSerializer<'a>;
-    type SerializeStructVariant = ser::Impossible<(), PlyError>;
+    type SerializeStructVariant = ElementStructSerializer<'a>;
 
     // ... scalars error ...
     fn serialize_bool(self, _v: bool) -> Result<Self::Ok, Self::Error> { err_must_be_struct_or_map() }
@@ -715,53 +1480,86 @@ impl<'a> ser::Serializer for &'a mut ElementSerializer {
     fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Self::Ok, Self::Error> { value.serialize(self) }
     fn serialize_unit(self) -> Result<Self::Ok, Self::Error> { err_must_be_struct_or_map() }
     fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> { err_must_be_struct_or_map() }
-    fn serialize_unit_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str) -> Result<Self::Ok, Self::Error> { err_must_be_struct_or_map() }
+    /// A unit variant tags the whole element with a discriminant and nothing else - useful
+    /// for an enum that's all unit variants (a tag-only marker element).
+    fn serialize_unit_variant(self, _name: &'static str, variant_index: u32, variant: &'static str) -> Result<Self::Ok, Self::Error> {
+        self.element.insert(VARIANT_TAG_KEY.to_string(), variant_tag_property(self.enum_repr, variant_index, variant));
+        Ok(())
+    }
     fn serialize_newtype_struct<T: ?Sized + Serialize>(self, _name: &'static str, value: &T) -> Result<Self::Ok, Self::Error> { value.serialize(self) }
-    fn serialize_newtype_variant<T: ?Sized + Serialize>(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _value: &T) -> Result<Self::Ok, Self::Error> {
-         Err(PlyError::Serialize("Newtype variant in element not supported".into()))
+    /// Internally tags the element with the variant's discriminant (see [`VARIANT_TAG_KEY`]),
+    /// then serializes `value` into the same element - `value` is expected to be a struct or
+    /// map, same as [`serialize_newtype_struct`](ser::Serializer::serialize_newtype_struct).
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(self, _name: &'static str, variant_index: u32, variant: &'static str, value: &T) -> Result<Self::Ok, Self::Error> {
+        self.element.insert(VARIANT_TAG_KEY.to_string(), variant_tag_property(self.enum_repr, variant_index, variant));
+        value.serialize(self)
     }
     fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> { err_must_be_struct_or_map() }
     fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> { err_must_be_struct_or_map() }
     fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeTupleStruct, Self::Error> { err_must_be_struct_or_map() }
     fn serialize_tuple_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _len: usize) -> Result<Self::SerializeTupleVariant, Self::Error> { err_must_be_struct_or_map() }
-    
+
     fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
-        Ok(ElementMapSerializer { element: &mut self.element })
+        Ok(ElementMapSerializer { element: &mut self.element, pending_key: None, enum_repr: self.enum_repr, int64_policy: self.int64_policy })
     }
-    
+
     fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct, Self::Error> {
-        Ok(ElementStructSerializer { element: &mut self.element })
+        Ok(ElementStructSerializer { element: &mut self.element, enum_repr: self.enum_repr, int64_policy: self.int64_policy })
+    }
+
+    /// Internally tags the element with the variant's discriminant (see [`VARIANT_TAG_KEY`]),
+    /// then hands back the same struct serializer [`serialize_struct`](ser::Serializer::serialize_struct)
+    /// uses so the variant's fields are written as ordinary properties alongside the tag.
+    fn serialize_struct_variant(self, _name: &'static str, variant_index: u32, variant: &'static str, _len: usize) -> Result<Self::SerializeStructVariant, Self::Error> {
+        self.element.insert(VARIANT_TAG_KEY.to_string(), variant_tag_property(self.enum_repr, variant_index, variant));
+        Ok(ElementStructSerializer { element: &mut self.element, enum_repr: self.enum_repr, int64_policy: self.int64_policy })
     }
-    
-    fn serialize_struct_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _len: usize) -> Result<Self::SerializeStructVariant, Self::Error> { err_must_be_struct_or_map() }
 }
 
 struct ElementMapSerializer<'a> {
     element: &'a mut DefaultElement,
+    /// Key serialized by [`serialize_key`](ser::SerializeMap::serialize_key) and awaiting its
+    /// matching [`serialize_value`](ser::SerializeMap::serialize_value) call - the standard
+    /// two-call `SerializeMap` protocol serde's derive and most hand-written impls use, as
+    /// opposed to the single-call `serialize_entry` shortcut.
+    pending_key: Option<String>,
+    enum_repr: EnumRepr,
+    int64_policy: Int64Policy,
 }
 
 impl<'a> ser::SerializeMap for ElementMapSerializer<'a> {
     type Ok = ();
     type Error = PlyError;
 
-    fn serialize_key<T: ?Sized + Serialize>(&mut self, _key: &T) -> Result<(), Self::Error> { Err(PlyError::Serialize("Element map requires string keys".into())) }
-    fn serialize_value<T: ?Sized + Serialize>(&mut self, _value: &T) -> Result<(), Self::Error> { Err(PlyError::Serialize("Element map value called without key".into())) }
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Self::Error> {
+        self.pending_key = Some(KeySerializer::serialize_key(key)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let key_str = self.pending_key.take().ok_or_else(|| {
+            PlyError::Serialize("Element map value called without a preceding key".into())
+        })?;
+        value.serialize(FlattenFieldSerializer::new(self.element, key_str, self.enum_repr, self.int64_policy))
+    }
 
     fn serialize_entry<K: ?Sized + Serialize, V: ?Sized + Serialize>(&mut self, key: &K, value: &V) -> Result<(), Self::Error> {
-        let key_str = KeySerializer::serialize_key(key)?;
-        let mut prop_serializer = PropertySerializer { property: None };
-        value.serialize(&mut prop_serializer)?;
-        if let Some(prop) = prop_serializer.property {
-            self.element.insert(key_str, prop);
+        self.serialize_key(key)?;
+        self.serialize_value(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        if self.pending_key.is_some() {
+            return Err(PlyError::Serialize("Element map ended with a key but no matching value".into()));
         }
         Ok(())
     }
-
-    fn end(self) -> Result<Self::Ok, Self::Error> { Ok(()) }
 }
 
 struct ElementStructSerializer<'a> {
     element: &'a mut DefaultElement,
+    enum_repr: EnumRepr,
+    int64_policy: Int64Policy,
 }
 
 impl<'a> ser::SerializeStruct for ElementStructSerializer<'a> {
@@ -769,12 +1567,7 @@ impl<'a> ser::SerializeStruct for ElementStructSerializer<'a> {
     type Error = PlyError;
 
     fn serialize_field<T: ?Sized + Serialize>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error> {
-        let mut prop_serializer = PropertySerializer { property: None };
-        value.serialize(&mut prop_serializer)?;
-        if let Some(prop) = prop_serializer.property {
-            self.element.insert(key.to_string(), prop);
-        }
-        Ok(())
+        value.serialize(FlattenFieldSerializer::new(self.element, key.to_string(), self.enum_repr, self.int64_policy))
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> { Ok(()) }
@@ -782,6 +1575,8 @@ impl<'a> ser::SerializeStruct for ElementStructSerializer<'a> {
 
 struct PropertySerializer {
     property: Option<Property>,
+    enum_repr: EnumRepr,
+    int64_policy: Int64Policy,
 }
 
 impl<'a> ser::Serializer for &'a mut PropertySerializer {
@@ -796,27 +1591,31 @@ impl<'a> ser::Serializer for &'a mut PropertySerializer {
     type SerializeStruct = ser::Impossible<(), PlyError>;
     type SerializeStructVariant = ser::Impossible<(), PlyError>;
 
-    fn serialize_bool(self, _v: bool) -> Result<Self::Ok, Self::Error> { Err(PlyError::Serialize("Boolean properties not supported".into())) }
+    /// Encodes as `Property::UChar(0)`/`UChar(1)` - the same 0/1 convention the CSV and
+    /// avro serializers use for a scalar with no dedicated boolean type. Indistinguishable
+    /// from a genuine `0`/`1` `u8` on the wire; the target field's Rust type (`bool` vs.
+    /// `u8`) is what disambiguates on deserialization.
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> { self.property = Some(Property::UChar(v as u8)); Ok(()) }
     fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> { self.property = Some(Property::Char(v)); Ok(()) }
     fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> { self.property = Some(Property::Short(v)); Ok(()) }
     fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> { self.property = Some(Property::Int(v)); Ok(()) }
     fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
-        self.property = Some(Property::Double(v as f64));
+        self.property = Some(signed_int64_property(self.int64_policy, v as i128, 64)?);
         Ok(())
     }
     fn serialize_i128(self, v: i128) -> Result<Self::Ok, Self::Error> {
-        self.property = Some(Property::Double(v as f64));
+        self.property = Some(signed_int64_property(self.int64_policy, v, 128)?);
         Ok(())
     }
     fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> { self.property = Some(Property::UChar(v)); Ok(()) }
     fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> { self.property = Some(Property::UShort(v)); Ok(()) }
     fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> { self.property = Some(Property::UInt(v)); Ok(()) }
     fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
-        self.property = Some(Property::Double(v as f64));
+        self.property = Some(unsigned_int64_property(self.int64_policy, v as u128, 64)?);
         Ok(())
     }
     fn serialize_u128(self, v: u128) -> Result<Self::Ok, Self::Error> {
-        self.property = Some(Property::Double(v as f64));
+        self.property = Some(unsigned_int64_property(self.int64_policy, v, 128)?);
         Ok(())
     }
     fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> { self.property = Some(Property::Float(v)); Ok(()) }
@@ -832,15 +1631,29 @@ impl<'a> ser::Serializer for &'a mut PropertySerializer {
         self.property = Some(Property::ListChar(chars));
         Ok(())
     }
-    fn serialize_bytes(self, _v: &[u8]) -> Result<Self::Ok, Self::Error> { Err(PlyError::Serialize("Bytes not supported".into())) }
+    /// Encodes as `Property::ListUChar`, the same representation a `Vec<u8>`/`[u8]` field
+    /// gets via [`serialize_seq`](ser::Serializer::serialize_seq) - lets `serde_bytes`-style
+    /// fields and raw binary blobs serialize without a manual byte-by-byte unroll.
+    fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        self.property = Some(Property::ListUChar(v.to_vec()));
+        Ok(())
+    }
     fn serialize_none(self) -> Result<Self::Ok, Self::Error> { self.property = None; Ok(()) }
     fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Self::Ok, Self::Error> { value.serialize(self) }
     fn serialize_unit(self) -> Result<Self::Ok, Self::Error> { Err(PlyError::Serialize("Unit not supported".into())) }
     fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> { Err(PlyError::Serialize("Unit struct not supported".into())) }
-    fn serialize_unit_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str) -> Result<Self::Ok, Self::Error> { Err(PlyError::Serialize("Unit variant not supported".into())) }
+    /// A unit variant in a scalar field has no inner value to nest, so the tag itself
+    /// becomes the property - see [`variant_tag_property`].
+    fn serialize_unit_variant(self, _name: &'static str, variant_index: u32, variant: &'static str) -> Result<Self::Ok, Self::Error> {
+        self.property = Some(variant_tag_property(self.enum_repr, variant_index, variant));
+        Ok(())
+    }
     fn serialize_newtype_struct<T: ?Sized + Serialize>(self, _name: &'static str, value: &T) -> Result<Self::Ok, Self::Error> { value.serialize(self) }
-    fn serialize_newtype_variant<T: ?Sized + Serialize>(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _value: &T) -> Result<Self::Ok, Self::Error> {
-         Err(PlyError::Serialize("Newtype variant in property not supported".into()))
+    /// The discriminant has nowhere to live alongside a single scalar property, so a
+    /// newtype variant in property position is serialized as just its inner value -
+    /// same as [`serialize_newtype_struct`](ser::Serializer::serialize_newtype_struct).
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(self, _name: &'static str, _variant_index: u32, _variant: &'static str, value: &T) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
     }
     
     fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
@@ -855,6 +1668,65 @@ impl<'a> ser::Serializer for &'a mut PropertySerializer {
     fn serialize_struct_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _len: usize) -> Result<Self::SerializeStructVariant, Self::Error> { Err(PlyError::Serialize("Struct variant not supported".into())) }
 }
 
+/// Scalar `ScalarType` candidates for [`promote_scalar_list`], narrowest first. `Half` is
+/// omitted - nothing in [`PropertySerializer`] ever produces a `Property::Half` to promote
+/// towards or from.
+const PROMOTION_CANDIDATES: [ScalarType; 10] = [
+    ScalarType::UChar, ScalarType::Char,
+    ScalarType::UShort, ScalarType::Short,
+    ScalarType::UInt, ScalarType::Int,
+    ScalarType::ULong, ScalarType::Long,
+    ScalarType::Float, ScalarType::Double,
+];
+
+/// Promotes a list of scalar [`Property`] values - of mixed but numeric variants - to the
+/// narrowest common type that holds every value losslessly, the way arrow2 unifies mixed
+/// integer-width columns. A list with any `Float`/`Double` element goes straight to `Double`;
+/// otherwise tries [`PROMOTION_CANDIDATES`] in order and returns the list built
+/// from the first type every element [`Property::coerce_to`]s into; rejects only if even
+/// `Double` can't take it (i.e. some element is itself a list property).
+fn promote_scalar_list(list: Vec<Property>) -> Result<Property, PlyError> {
+    let has_float = list.iter().any(|p| matches!(p, Property::Float(_) | Property::Double(_)));
+    for candidate in PROMOTION_CANDIDATES {
+        if has_float && candidate != ScalarType::Double {
+            continue;
+        }
+        let target = PropertyType::Scalar(candidate.clone());
+        let coerced: Option<Vec<Property>> = list.iter().map(|p| p.coerce_to(&target)).collect();
+        if let Some(values) = coerced {
+            return Ok(scalar_list_property(&candidate, values));
+        }
+    }
+    Err(PlyError::Serialize("Heterogeneous list contains a value that doesn't fit any common PLY scalar type".into()))
+}
+
+/// Packs `values` - all of them [`Property::coerce_to`]'d to `candidate` - into the matching
+/// `Property::ListXxx`. Panics if a value isn't the `candidate` variant; only called with
+/// `coerce_to(&PropertyType::Scalar(candidate))` output, which always matches.
+fn scalar_list_property(candidate: &ScalarType, values: Vec<Property>) -> Property {
+    macro_rules! list_of {
+        ($variant:ident, $list_variant:ident) => {
+            Property::$list_variant(values.into_iter().map(|p| match p {
+                Property::$variant(v) => v,
+                _ => unreachable!("coerce_to(&PropertyType::Scalar({:?})) returned a different variant", candidate),
+            }).collect())
+        };
+    }
+    match candidate {
+        ScalarType::Char => list_of!(Char, ListChar),
+        ScalarType::UChar => list_of!(UChar, ListUChar),
+        ScalarType::Short => list_of!(Short, ListShort),
+        ScalarType::UShort => list_of!(UShort, ListUShort),
+        ScalarType::Int => list_of!(Int, ListInt),
+        ScalarType::UInt => list_of!(UInt, ListUInt),
+        ScalarType::Long => list_of!(Long, ListLong),
+        ScalarType::ULong => list_of!(ULong, ListULong),
+        ScalarType::Float => list_of!(Float, ListFloat),
+        ScalarType::Double => list_of!(Double, ListDouble),
+        ScalarType::Half => unreachable!("Half is not a PROMOTION_CANDIDATES entry"),
+    }
+}
+
 struct PropertySeqSerializer<'a> {
     property_serializer: &'a mut PropertySerializer,
     list: Vec<Property>,
@@ -865,7 +1737,11 @@ impl<'a> ser::SerializeSeq for PropertySeqSerializer<'a> {
     type Error = PlyError;
 
     fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
-        let mut ps = PropertySerializer { property: None };
+        let mut ps = PropertySerializer {
+            property: None,
+            enum_repr: self.property_serializer.enum_repr,
+            int64_policy: self.property_serializer.int64_policy,
+        };
         value.serialize(&mut ps)?;
         if let Some(prop) = ps.property {
             self.list.push(prop);
@@ -874,52 +1750,251 @@ impl<'a> ser::SerializeSeq for PropertySeqSerializer<'a> {
     }
 
     fn end(self) -> Result<Self::Ok, Self::Error> {
-        // Check homogeneity
-        if self.list.is_empty() {
-             // We can't determine type. Default to ListInt?
-             self.property_serializer.property = Some(Property::ListInt(Vec::new()));
-             return Ok(());
+        self.property_serializer.property = Some(finish_list(self.list)?);
+        Ok(())
+    }
+}
+
+/// Turns a serialized sequence's scalar values into a single list [`Property`], coercing
+/// mixed-but-compatible element types to their narrowest common scalar type (see
+/// [`promote_scalar_list`]) instead of rejecting the list outright.
+fn finish_list(list: Vec<Property>) -> Result<Property, PlyError> {
+    // Check homogeneity
+    if list.is_empty() {
+         // We can't determine type. Default to ListInt?
+         return Ok(Property::ListInt(Vec::new()));
+    }
+
+    // Coerce all to the same list type based on first element. Uses `iter().cloned()`
+    // rather than consuming `list` so a homogeneity miss can still fall through to
+    // the numeric-promotion attempt below instead of losing the data.
+    let first = &list[0];
+    match first {
+        Property::Char(_) => {
+            let vec: Result<Vec<i8>, _> = list.iter().cloned().map(|p| match p { Property::Char(v) => Ok(v), _ => Err(()) }).collect();
+            if let Ok(v) = vec { return Ok(Property::ListChar(v)); }
+        },
+        Property::UChar(_) => {
+            let vec: Result<Vec<u8>, _> = list.iter().cloned().map(|p| match p { Property::UChar(v) => Ok(v), _ => Err(()) }).collect();
+            if let Ok(v) = vec { return Ok(Property::ListUChar(v)); }
+        },
+        Property::Short(_) => {
+            let vec: Result<Vec<i16>, _> = list.iter().cloned().map(|p| match p { Property::Short(v) => Ok(v), _ => Err(()) }).collect();
+            if let Ok(v) = vec { return Ok(Property::ListShort(v)); }
+        },
+        Property::UShort(_) => {
+            let vec: Result<Vec<u16>, _> = list.iter().cloned().map(|p| match p { Property::UShort(v) => Ok(v), _ => Err(()) }).collect();
+            if let Ok(v) = vec { return Ok(Property::ListUShort(v)); }
+        },
+        Property::Int(_) => {
+            let vec: Result<Vec<i32>, _> = list.iter().cloned().map(|p| match p { Property::Int(v) => Ok(v), _ => Err(()) }).collect();
+            if let Ok(v) = vec { return Ok(Property::ListInt(v)); }
+        },
+        Property::UInt(_) => {
+            let vec: Result<Vec<u32>, _> = list.iter().cloned().map(|p| match p { Property::UInt(v) => Ok(v), _ => Err(()) }).collect();
+            if let Ok(v) = vec { return Ok(Property::ListUInt(v)); }
+        },
+        Property::Float(_) => {
+            let vec: Result<Vec<f32>, _> = list.iter().cloned().map(|p| match p { Property::Float(v) => Ok(v), _ => Err(()) }).collect();
+            if let Ok(v) = vec { return Ok(Property::ListFloat(v)); }
+        },
+        Property::Double(_) => {
+            let vec: Result<Vec<f64>, _> = list.iter().cloned().map(|p| match p { Property::Double(v) => Ok(v), _ => Err(()) }).collect();
+            if let Ok(v) = vec { return Ok(Property::ListDouble(v)); }
+        },
+        Property::Long(_) => {
+            let vec: Result<Vec<i64>, _> = list.iter().cloned().map(|p| match p { Property::Long(v) => Ok(v), _ => Err(()) }).collect();
+            if let Ok(v) = vec { return Ok(Property::ListLong(v)); }
+        },
+        Property::ULong(_) => {
+            let vec: Result<Vec<u64>, _> = list.iter().cloned().map(|p| match p { Property::ULong(v) => Ok(v), _ => Err(()) }).collect();
+            if let Ok(v) = vec { return Ok(Property::ListULong(v)); }
+        },
+        _ => return Err(PlyError::Serialize("Nested lists not supported".into())),
+    }
+
+    // Not a homogeneous list of the first element's exact variant - try to promote
+    // every element to the narrowest common scalar type instead of giving up. Mirrors
+    // [`Property::coerce_to`]'s fallibility rules: an integer destination is rejected
+    // unless the value fits exactly, a float destination always accepts.
+    promote_scalar_list(list)
+}
+
+/// Flattens a struct/map field whose value is itself a struct/map into its parent element,
+/// instead of erroring with "Struct not supported in property" the way a bare leaf
+/// [`PropertySerializer`] does. Each leaf scalar/list becomes its own PLY property named
+/// `"{prefix}.{child}"`, recursing through arbitrary nesting depth - e.g. a `Vertex { pos: Vec3,
+/// color: Rgb }` field ends up as `pos.x`, `pos.y`, `pos.z`, `color.r`, ... in declaration order.
+/// A plain scalar or list value is written as a single property under `prefix`, identical to
+/// what [`PropertySerializer`] would produce.
+struct FlattenFieldSerializer<'a> {
+    element: &'a mut DefaultElement,
+    prefix: String,
+    enum_repr: EnumRepr,
+    int64_policy: Int64Policy,
+    pending_key: Option<String>,
+}
+
+impl<'a> FlattenFieldSerializer<'a> {
+    fn new(element: &'a mut DefaultElement, prefix: String, enum_repr: EnumRepr, int64_policy: Int64Policy) -> Self {
+        FlattenFieldSerializer { element, prefix, enum_repr, int64_policy, pending_key: None }
+    }
+
+    fn child(&mut self, key: &str) -> FlattenFieldSerializer<'_> {
+        FlattenFieldSerializer::new(self.element, format!("{}.{}", self.prefix, key), self.enum_repr, self.int64_policy)
+    }
+}
+
+macro_rules! flatten_scalar_leaf {
+    ($method:ident, $ty:ty) => {
+        fn $method(self, v: $ty) -> Result<Self::Ok, Self::Error> {
+            let mut ps = PropertySerializer { property: None, enum_repr: self.enum_repr, int64_policy: self.int64_policy };
+            ser::Serializer::$method(&mut ps, v)?;
+            if let Some(prop) = ps.property {
+                self.element.insert(self.prefix, prop);
+            }
+            Ok(())
         }
-        
-        // Coerce all to the same list type based on first element
-        // Since Property stores the value, we need to extract values.
-        let first = &self.list[0];
-        match first {
-            Property::Char(_) => {
-                let vec: Result<Vec<i8>, _> = self.list.into_iter().map(|p| match p { Property::Char(v) => Ok(v), _ => Err(()) }).collect();
-                if let Ok(v) = vec { self.property_serializer.property = Some(Property::ListChar(v)); return Ok(()); }
-            },
-            Property::UChar(_) => {
-                let vec: Result<Vec<u8>, _> = self.list.into_iter().map(|p| match p { Property::UChar(v) => Ok(v), _ => Err(()) }).collect();
-                if let Ok(v) = vec { self.property_serializer.property = Some(Property::ListUChar(v)); return Ok(()); }
-            },
-            Property::Short(_) => {
-                let vec: Result<Vec<i16>, _> = self.list.into_iter().map(|p| match p { Property::Short(v) => Ok(v), _ => Err(()) }).collect();
-                if let Ok(v) = vec { self.property_serializer.property = Some(Property::ListShort(v)); return Ok(()); }
-            },
-            Property::UShort(_) => {
-                let vec: Result<Vec<u16>, _> = self.list.into_iter().map(|p| match p { Property::UShort(v) => Ok(v), _ => Err(()) }).collect();
-                if let Ok(v) = vec { self.property_serializer.property = Some(Property::ListUShort(v)); return Ok(()); }
-            },
-            Property::Int(_) => {
-                let vec: Result<Vec<i32>, _> = self.list.into_iter().map(|p| match p { Property::Int(v) => Ok(v), _ => Err(()) }).collect();
-                if let Ok(v) = vec { self.property_serializer.property = Some(Property::ListInt(v)); return Ok(()); }
-            },
-            Property::UInt(_) => {
-                let vec: Result<Vec<u32>, _> = self.list.into_iter().map(|p| match p { Property::UInt(v) => Ok(v), _ => Err(()) }).collect();
-                if let Ok(v) = vec { self.property_serializer.property = Some(Property::ListUInt(v)); return Ok(()); }
-            },
-            Property::Float(_) => {
-                let vec: Result<Vec<f32>, _> = self.list.into_iter().map(|p| match p { Property::Float(v) => Ok(v), _ => Err(()) }).collect();
-                if let Ok(v) = vec { self.property_serializer.property = Some(Property::ListFloat(v)); return Ok(()); }
-            },
-            Property::Double(_) => {
-                let vec: Result<Vec<f64>, _> = self.list.into_iter().map(|p| match p { Property::Double(v) => Ok(v), _ => Err(()) }).collect();
-                if let Ok(v) = vec { self.property_serializer.property = Some(Property::ListDouble(v)); return Ok(()); }
-            },
-            _ => return Err(PlyError::Serialize("Nested lists not supported".into())),
+    };
+}
+
+impl<'a> ser::Serializer for FlattenFieldSerializer<'a> {
+    type Ok = ();
+    type Error = PlyError;
+    type SerializeSeq = FlattenFieldSeqSerializer<'a>;
+    type SerializeTuple = ser::Impossible<(), PlyError>;
+    type SerializeTupleStruct = ser::Impossible<(), PlyError>;
+    type SerializeTupleVariant = ser::Impossible<(), PlyError>;
+    type SerializeMap = Self;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Self;
+
+    flatten_scalar_leaf!(serialize_bool, bool);
+    flatten_scalar_leaf!(serialize_i8, i8);
+    flatten_scalar_leaf!(serialize_i16, i16);
+    flatten_scalar_leaf!(serialize_i32, i32);
+    flatten_scalar_leaf!(serialize_i64, i64);
+    flatten_scalar_leaf!(serialize_i128, i128);
+    flatten_scalar_leaf!(serialize_u8, u8);
+    flatten_scalar_leaf!(serialize_u16, u16);
+    flatten_scalar_leaf!(serialize_u32, u32);
+    flatten_scalar_leaf!(serialize_u64, u64);
+    flatten_scalar_leaf!(serialize_u128, u128);
+    flatten_scalar_leaf!(serialize_f32, f32);
+    flatten_scalar_leaf!(serialize_f64, f64);
+    flatten_scalar_leaf!(serialize_char, char);
+    flatten_scalar_leaf!(serialize_str, &str);
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        self.element.insert(self.prefix, Property::ListUChar(v.to_vec()));
+        Ok(())
+    }
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Self::Ok, Self::Error> { value.serialize(self) }
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> { Ok(()) }
+    /// Tags the field itself with the variant's discriminant, same convention a leaf
+    /// [`PropertySerializer`] uses for a unit-only enum (see [`variant_tag_property`]).
+    fn serialize_unit_variant(self, _name: &'static str, variant_index: u32, variant: &'static str) -> Result<Self::Ok, Self::Error> {
+        self.element.insert(self.prefix, variant_tag_property(self.enum_repr, variant_index, variant));
+        Ok(())
+    }
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(self, _name: &'static str, value: &T) -> Result<Self::Ok, Self::Error> { value.serialize(self) }
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(self, _name: &'static str, variant_index: u32, variant: &'static str, value: &T) -> Result<Self::Ok, Self::Error> {
+        self.element.insert(format!("{}.{}", self.prefix, VARIANT_TAG_KEY), variant_tag_property(self.enum_repr, variant_index, variant));
+        value.serialize(self)
+    }
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Ok(FlattenFieldSeqSerializer { element: self.element, prefix: self.prefix, list: Vec::new(), enum_repr: self.enum_repr, int64_policy: self.int64_policy })
+    }
+    fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple, Self::Error> { err_must_be_struct_or_map() }
+    fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeTupleStruct, Self::Error> { err_must_be_struct_or_map() }
+    fn serialize_tuple_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _len: usize) -> Result<Self::SerializeTupleVariant, Self::Error> { err_must_be_struct_or_map() }
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> { Ok(self) }
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct, Self::Error> { Ok(self) }
+    /// Tags the flattened group with the variant's discriminant under `"{prefix}.{VARIANT_TAG_KEY}"`,
+    /// then flattens the variant's own fields the same way a plain struct field would.
+    fn serialize_struct_variant(self, _name: &'static str, variant_index: u32, variant: &'static str, _len: usize) -> Result<Self::SerializeStructVariant, Self::Error> {
+        self.element.insert(format!("{}.{}", self.prefix, VARIANT_TAG_KEY), variant_tag_property(self.enum_repr, variant_index, variant));
+        Ok(self)
+    }
+}
+
+impl<'a> ser::SerializeStruct for FlattenFieldSerializer<'a> {
+    type Ok = ();
+    type Error = PlyError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error> {
+        value.serialize(self.child(key))
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> { Ok(()) }
+}
+
+impl<'a> ser::SerializeStructVariant for FlattenFieldSerializer<'a> {
+    type Ok = ();
+    type Error = PlyError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error> {
+        value.serialize(self.child(key))
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> { Ok(()) }
+}
+
+impl<'a> ser::SerializeMap for FlattenFieldSerializer<'a> {
+    type Ok = ();
+    type Error = PlyError;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Self::Error> {
+        self.pending_key = Some(KeySerializer::serialize_key(key)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let key = self.pending_key.take().ok_or_else(|| {
+            PlyError::Serialize("Flattened map value called without a preceding key".into())
+        })?;
+        value.serialize(self.child(&key))
+    }
+
+    fn serialize_entry<K: ?Sized + Serialize, V: ?Sized + Serialize>(&mut self, key: &K, value: &V) -> Result<(), Self::Error> {
+        self.serialize_key(key)?;
+        self.serialize_value(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        if self.pending_key.is_some() {
+            return Err(PlyError::Serialize("Flattened map ended with a key but no matching value".into()));
         }
-        
-        Err(PlyError::Serialize("Heterogeneous lists not supported".into()))
+        Ok(())
+    }
+}
+
+struct FlattenFieldSeqSerializer<'a> {
+    element: &'a mut DefaultElement,
+    prefix: String,
+    list: Vec<Property>,
+    enum_repr: EnumRepr,
+    int64_policy: Int64Policy,
+}
+
+impl<'a> ser::SerializeSeq for FlattenFieldSeqSerializer<'a> {
+    type Ok = ();
+    type Error = PlyError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let mut ps = PropertySerializer { property: None, enum_repr: self.enum_repr, int64_policy: self.int64_policy };
+        value.serialize(&mut ps)?;
+        if let Some(prop) = ps.property {
+            self.list.push(prop);
+        }
+        Ok(())
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        let property = finish_list(self.list)?;
+        self.element.insert(self.prefix, property);
+        Ok(())
     }
 }
@@ -1,21 +1,137 @@
+use std::fmt;
 
-/// Tracks the current line number while parsing.
+/// A position in a PLY stream where a parse error occurred.
 ///
-/// This is primarily used to add line-context to I/O and parse errors.
-#[derive(Debug, Clone, Copy)]
+/// ASCII encoding is inherently line-oriented, while binary encoding has no line concept at
+/// all - a truncated record or an out-of-range list length only makes sense reported as an
+/// absolute byte offset, plus which element/property was being decoded at the time. This enum
+/// lets [`crate::errors::PlyError`] messages report whichever is meaningful without binary
+/// callers having to fake a line number or ASCII callers having to fake an offset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Location {
+    /// 1-based line index, reported while decoding the ASCII encoding.
+    Line(usize),
+    /// Absolute byte offset into the payload, reported while decoding a binary encoding.
+    Byte {
+        /// Byte offset from the start of the payload (not the whole file).
+        offset: u64,
+        /// The element group being decoded, e.g. `"face"`.
+        element: Option<String>,
+        /// 0-based index of the record within `element`, e.g. `124012`.
+        index: Option<usize>,
+        /// The property being decoded when the error occurred, e.g. `"vertex_indices"`.
+        property: Option<String>,
+    },
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Location::Line(line) => write!(f, "Line {}", line),
+            Location::Byte { offset, element, index, property } => {
+                if let Some(property) = property {
+                    write!(f, "failed reading property `{}`", property)?;
+                    if let (Some(element), Some(index)) = (element, index) {
+                        write!(f, " of element `{}` #{}", element, index)?;
+                    }
+                } else if let (Some(element), Some(index)) = (element, index) {
+                    write!(f, "failed reading element `{}` #{}", element, index)?;
+                } else {
+                    write!(f, "failed reading")?;
+                }
+                write!(f, " at byte offset {:#X}", offset)
+            }
+        }
+    }
+}
+
+/// Tracks the current position while parsing, for [`Location`]-annotated error messages.
+///
+/// ASCII parsing advances [`LocationTracker::line_index`] one line at a time; binary parsing
+/// advances the byte offset instead and records which element/record/property is currently
+/// being decoded via [`LocationTracker::enter_record`]/[`LocationTracker::enter_property`], so
+/// a binary decode error can be reported as a [`Location::Byte`] with that context attached.
+#[derive(Debug, Clone, Default)]
 pub struct LocationTracker {
     /// Current 1-based line index in the input stream.
     pub line_index: usize,
+    byte_offset: u64,
+    element: Option<String>,
+    index: Option<usize>,
+    property: Option<String>,
 }
 
 impl LocationTracker {
     /// Creates a new tracker at the start of a stream.
     pub fn new() -> Self {
-        LocationTracker { line_index: 0 }
+        LocationTracker { line_index: 0, byte_offset: 0, element: None, index: None, property: None }
     }
 
     /// Advances the tracker to the next line.
     pub fn next_line(&mut self) {
         self.line_index += 1;
     }
+
+    /// Advances the tracked byte offset by `n`, the number of bytes just consumed from the
+    /// binary payload.
+    pub fn advance_bytes(&mut self, n: u64) {
+        self.byte_offset += n;
+    }
+
+    /// Records that decoding has moved on to record `index` of `element`, clearing any
+    /// previously recorded property.
+    pub fn enter_record(&mut self, element: &str, index: usize) {
+        self.element = Some(element.to_string());
+        self.index = Some(index);
+        self.property = None;
+    }
+
+    /// Records that decoding has moved on to `property` within the current record.
+    pub fn enter_property(&mut self, property: &str) {
+        self.property = Some(property.to_string());
+    }
+
+    /// The current position as a line-based [`Location`], for ASCII error messages.
+    pub fn line_location(&self) -> Location {
+        Location::Line(self.line_index)
+    }
+
+    /// The current position as a byte-based [`Location`], for binary error messages.
+    pub fn byte_location(&self) -> Location {
+        Location::Byte {
+            offset: self.byte_offset,
+            element: self.element.clone(),
+            index: self.index,
+            property: self.property.clone(),
+        }
+    }
+}
+
+/// Wraps a [`std::io::Read`] and counts the bytes read through it.
+///
+/// Used by the binary decode paths in [`crate::parser`] to keep [`LocationTracker`]'s byte
+/// offset accurate without every low-level `read_u8`/`read_f32`/... call having to report its
+/// own size back up the call stack.
+pub(crate) struct CountingReader<R> {
+    inner: R,
+    count: u64,
+}
+
+impl<R> CountingReader<R> {
+    pub(crate) fn new(inner: R) -> Self {
+        CountingReader { inner, count: 0 }
+    }
+
+    /// Total bytes read through this wrapper so far.
+    pub(crate) fn bytes_read(&self) -> u64 {
+        self.count
+    }
+}
+
+impl<R: std::io::Read> std::io::Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.count += n as u64;
+        Ok(n)
+    }
 }
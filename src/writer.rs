@@ -0,0 +1,488 @@
+//! Writes [`Ply`] data back out as ascii or binary PLY.
+//!
+//! Mirrors [`crate::parser::Parser`] on the write side: [`Writer::write_ply`] serializes a
+//! whole [`Ply`] in one call, while [`Writer::write_header`] and the single-element methods let
+//! the `#[derive(ToPly)]` macro (see `ply-rs-macros`) write one field's `Vec<T>` at a time
+//! without building an intermediate `Ply`. [`ToPly`] is the trait that derive implements.
+
+use std::io::Write;
+use std::marker::PhantomData;
+
+use byteorder::{BigEndian, LittleEndian, WriteBytesExt};
+
+use crate::errors::{PlyError, PlyResult};
+use crate::ply::{ElementDef, Encoding, Header, Ply, PropertyAccess, PropertyType, ScalarType};
+
+type Result<T> = PlyResult<T>;
+
+/// Implemented by `#[derive(ToPly)]` containers (see `ply-rs-macros`) to write a whole mesh
+/// back out, the symmetric counterpart of [`crate::parser::Parser::read_payload_for_element`]
+/// on the read side.
+pub trait ToPly {
+    /// Writes `self` as an ascii PLY file.
+    fn write_ply<W: Write>(&self, writer: &mut W) -> PlyResult<usize>;
+
+    /// Writes `self` as a PLY file encoded as `encoding`.
+    fn write_ply_with_encoding<W: Write>(&self, writer: &mut W, encoding: Encoding) -> PlyResult<usize>;
+
+    /// Starts a fluent [`ToPlyWriter`] for picking an output [`Encoding`] before writing, e.g.
+    /// `payload.to_ply_writer().format(Encoding::BinaryBigEndian).write(&mut w)`. Equivalent to
+    /// calling [`write_ply_with_encoding`](ToPly::write_ply_with_encoding) directly; this just
+    /// reads better at call sites that pick the encoding far from the write itself.
+    fn to_ply_writer(&self) -> ToPlyWriter<'_, Self>
+    where
+        Self: Sized,
+    {
+        ToPlyWriter::new(self)
+    }
+}
+
+/// Builder returned by [`ToPly::to_ply_writer`]; defaults to [`Encoding::Ascii`] until
+/// [`format`](ToPlyWriter::format) is called.
+pub struct ToPlyWriter<'a, T: ToPly> {
+    value: &'a T,
+    encoding: Encoding,
+}
+
+impl<'a, T: ToPly> ToPlyWriter<'a, T> {
+    fn new(value: &'a T) -> Self {
+        ToPlyWriter { value, encoding: Encoding::Ascii }
+    }
+
+    /// Selects the output encoding.
+    pub fn format(mut self, encoding: Encoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    /// Writes the payload in the configured encoding, returning the number of bytes written.
+    pub fn write<W: Write>(&self, writer: &mut W) -> PlyResult<usize> {
+        self.value.write_ply_with_encoding(writer, self.encoding)
+    }
+}
+
+/// Serializes [`PropertyAccess`] elements of type `E` as ascii or binary PLY.
+///
+/// Stateless beyond its type parameter; construct one with [`Writer::new`] and reuse it for as
+/// many writes as needed.
+pub struct Writer<E: PropertyAccess> {
+    _marker: PhantomData<E>,
+}
+
+impl<E: PropertyAccess> Default for Writer<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: PropertyAccess> Writer<E> {
+    /// Creates a writer for element type `E`.
+    pub fn new() -> Self {
+        Writer { _marker: PhantomData }
+    }
+
+    /// Writes an entire `Ply`: the header, then every element group named in
+    /// `ply.header.elements`, in that order, encoded as `ply.header.encoding`. Returns the
+    /// number of bytes written.
+    pub fn write_ply<W: Write>(&self, writer: &mut W, ply: &Ply<E>) -> Result<usize> {
+        let mut written = self.write_header(writer, &ply.header)?;
+        let empty = Vec::new();
+        for (name, element_def) in &ply.header.elements {
+            let elements = ply.payload.get(name).unwrap_or(&empty);
+            written += self.write_payload_of_element(writer, elements, element_def, &ply.header)?;
+        }
+        writer.flush()?;
+        Ok(written)
+    }
+
+    /// Writes just the header: `ply`, `format`, comments, obj_info, one `element`/`property`
+    /// block per entry of `header.elements`, and `end_header`.
+    pub fn write_header<W: Write>(&self, writer: &mut W, header: &Header) -> Result<usize> {
+        let mut buf = Vec::new();
+        writeln!(buf, "ply")?;
+        writeln!(buf, "format {} {}", header.encoding, header.version)?;
+        for comment in &header.comments {
+            writeln!(buf, "comment {}", comment)?;
+        }
+        for obj_info in &header.obj_infos {
+            writeln!(buf, "obj_info {}", obj_info)?;
+        }
+        for (name, element_def) in &header.elements {
+            writeln!(buf, "element {} {}", name, element_def.count)?;
+            for (prop_name, prop) in &element_def.properties {
+                writeln!(buf, "property {} {}", property_type_name(&prop.data_type)?, prop_name)?;
+            }
+        }
+        writeln!(buf, "end_header")?;
+        writer.write_all(&buf)?;
+        Ok(buf.len())
+    }
+
+    /// Writes every element of `elements` (one `element_def` group), in `header.encoding`.
+    pub fn write_payload_of_element<W: Write>(
+        &self,
+        writer: &mut W,
+        elements: &[E],
+        element_def: &ElementDef,
+        header: &Header,
+    ) -> Result<usize> {
+        let mut written = 0;
+        for element in elements {
+            written += match header.encoding {
+                Encoding::Ascii => self.write_ascii_element(writer, element, element_def)?,
+                Encoding::BinaryLittleEndian => self.write_little_endian_element(writer, element, element_def)?,
+                Encoding::BinaryBigEndian => self.write_big_endian_element(writer, element, element_def)?,
+            };
+        }
+        Ok(written)
+    }
+
+    /// Writes a single element as one ascii line: space-separated properties, in declaration
+    /// order, terminated by `\n`.
+    pub fn write_ascii_element<W: Write>(&self, writer: &mut W, element: &E, element_def: &ElementDef) -> Result<usize> {
+        let mut tokens = Vec::with_capacity(element_def.properties.len());
+        for (name, prop) in &element_def.properties {
+            tokens.push(format_ascii_property(element, name, &prop.data_type)?);
+        }
+        let line = tokens.join(" ");
+        let written = line.len() + 1;
+        writeln!(writer, "{}", line)?;
+        Ok(written)
+    }
+
+    /// Writes a single element in binary little-endian, properties in declaration order.
+    pub fn write_little_endian_element<W: Write>(&self, writer: &mut W, element: &E, element_def: &ElementDef) -> Result<usize> {
+        self.write_binary_element::<W, LittleEndian>(writer, element, element_def)
+    }
+
+    /// Writes a single element in binary big-endian, properties in declaration order.
+    pub fn write_big_endian_element<W: Write>(&self, writer: &mut W, element: &E, element_def: &ElementDef) -> Result<usize> {
+        self.write_binary_element::<W, BigEndian>(writer, element, element_def)
+    }
+
+    fn write_binary_element<W: Write, B: byteorder::ByteOrder>(
+        &self,
+        writer: &mut W,
+        element: &E,
+        element_def: &ElementDef,
+    ) -> Result<usize> {
+        let mut written = 0;
+        for (name, prop) in &element_def.properties {
+            written += write_binary_property::<W, E, B>(writer, element, name, &prop.data_type)?;
+        }
+        Ok(written)
+    }
+}
+
+fn property_type_name(data_type: &PropertyType) -> Result<String> {
+    Ok(match data_type {
+        PropertyType::Scalar(t) => scalar_name(t)?.to_string(),
+        PropertyType::List(count_type, elem_type) => format!("list {} {}", scalar_name(count_type)?, scalar_name(elem_type)?),
+    })
+}
+
+fn scalar_name(t: &ScalarType) -> Result<&'static str> {
+    Ok(match t {
+        ScalarType::Char => "char",
+        ScalarType::UChar => "uchar",
+        ScalarType::Short => "short",
+        ScalarType::UShort => "ushort",
+        ScalarType::Int => "int",
+        ScalarType::UInt => "uint",
+        ScalarType::Half => "float16",
+        ScalarType::Float => "float",
+        ScalarType::Double => "double",
+        ScalarType::Long => "int64",
+        ScalarType::ULong => "uint64",
+    })
+}
+
+fn format_ascii_property<E: PropertyAccess>(element: &E, name: &str, data_type: &PropertyType) -> Result<String> {
+    let missing = || PlyError::Inconsistent(format!("Missing property '{}'.", name));
+    Ok(match data_type {
+        PropertyType::Scalar(ScalarType::Char) => element.get_char(name).ok_or_else(missing)?.to_string(),
+        PropertyType::Scalar(ScalarType::UChar) => element.get_uchar(name).ok_or_else(missing)?.to_string(),
+        PropertyType::Scalar(ScalarType::Short) => element.get_short(name).ok_or_else(missing)?.to_string(),
+        PropertyType::Scalar(ScalarType::UShort) => element.get_ushort(name).ok_or_else(missing)?.to_string(),
+        PropertyType::Scalar(ScalarType::Int) => element.get_int(name).ok_or_else(missing)?.to_string(),
+        PropertyType::Scalar(ScalarType::UInt) => element.get_uint(name).ok_or_else(missing)?.to_string(),
+        PropertyType::Scalar(ScalarType::Half) => element.get_half(name).ok_or_else(missing)?.to_string(),
+        PropertyType::Scalar(ScalarType::Float) => element.get_float(name).ok_or_else(missing)?.to_string(),
+        PropertyType::Scalar(ScalarType::Double) => element.get_double(name).ok_or_else(missing)?.to_string(),
+        PropertyType::Scalar(ScalarType::Long) => element.get_long(name).ok_or_else(missing)?.to_string(),
+        PropertyType::Scalar(ScalarType::ULong) => element.get_ulong(name).ok_or_else(missing)?.to_string(),
+        PropertyType::List(_, elem_type) => {
+            let values = format_ascii_list(element, name, elem_type)?;
+            format!("{} {}", values.len(), values.join(" "))
+        }
+    })
+}
+
+fn format_ascii_list<E: PropertyAccess>(element: &E, name: &str, elem_type: &ScalarType) -> Result<Vec<String>> {
+    let missing = || PlyError::Inconsistent(format!("Missing list property '{}'.", name));
+    Ok(match elem_type {
+        ScalarType::Char => element.get_list_char(name).ok_or_else(missing)?.iter().map(|v| v.to_string()).collect(),
+        ScalarType::UChar => element.get_list_uchar(name).ok_or_else(missing)?.iter().map(|v| v.to_string()).collect(),
+        ScalarType::Short => element.get_list_short(name).ok_or_else(missing)?.iter().map(|v| v.to_string()).collect(),
+        ScalarType::UShort => element.get_list_ushort(name).ok_or_else(missing)?.iter().map(|v| v.to_string()).collect(),
+        ScalarType::Int => element.get_list_int(name).ok_or_else(missing)?.iter().map(|v| v.to_string()).collect(),
+        ScalarType::UInt => element.get_list_uint(name).ok_or_else(missing)?.iter().map(|v| v.to_string()).collect(),
+        ScalarType::Half => element.get_list_half(name).ok_or_else(missing)?.iter().map(|v| v.to_string()).collect(),
+        ScalarType::Float => element.get_list_float(name).ok_or_else(missing)?.iter().map(|v| v.to_string()).collect(),
+        ScalarType::Double => element.get_list_double(name).ok_or_else(missing)?.iter().map(|v| v.to_string()).collect(),
+        ScalarType::Long => element.get_list_long(name).ok_or_else(missing)?.iter().map(|v| v.to_string()).collect(),
+        ScalarType::ULong => element.get_list_ulong(name).ok_or_else(missing)?.iter().map(|v| v.to_string()).collect(),
+    })
+}
+
+fn write_binary_property<W: Write, E: PropertyAccess, B: byteorder::ByteOrder>(
+    writer: &mut W,
+    element: &E,
+    name: &str,
+    data_type: &PropertyType,
+) -> Result<usize> {
+    let missing = || PlyError::Inconsistent(format!("Missing property '{}'.", name));
+    Ok(match data_type {
+        PropertyType::Scalar(ScalarType::Char) => {
+            writer.write_i8(element.get_char(name).ok_or_else(missing)?)?;
+            1
+        }
+        PropertyType::Scalar(ScalarType::UChar) => {
+            writer.write_u8(element.get_uchar(name).ok_or_else(missing)?)?;
+            1
+        }
+        PropertyType::Scalar(ScalarType::Short) => {
+            writer.write_i16::<B>(element.get_short(name).ok_or_else(missing)?)?;
+            2
+        }
+        PropertyType::Scalar(ScalarType::UShort) => {
+            writer.write_u16::<B>(element.get_ushort(name).ok_or_else(missing)?)?;
+            2
+        }
+        PropertyType::Scalar(ScalarType::Int) => {
+            writer.write_i32::<B>(element.get_int(name).ok_or_else(missing)?)?;
+            4
+        }
+        PropertyType::Scalar(ScalarType::UInt) => {
+            writer.write_u32::<B>(element.get_uint(name).ok_or_else(missing)?)?;
+            4
+        }
+        PropertyType::Scalar(ScalarType::Half) => {
+            writer.write_u16::<B>(element.get_half(name).ok_or_else(missing)?.to_bits())?;
+            2
+        }
+        PropertyType::Scalar(ScalarType::Float) => {
+            writer.write_f32::<B>(element.get_float(name).ok_or_else(missing)?)?;
+            4
+        }
+        PropertyType::Scalar(ScalarType::Double) => {
+            writer.write_f64::<B>(element.get_double(name).ok_or_else(missing)?)?;
+            8
+        }
+        PropertyType::Scalar(ScalarType::Long) => {
+            writer.write_i64::<B>(element.get_long(name).ok_or_else(missing)?)?;
+            8
+        }
+        PropertyType::Scalar(ScalarType::ULong) => {
+            writer.write_u64::<B>(element.get_ulong(name).ok_or_else(missing)?)?;
+            8
+        }
+        PropertyType::List(index_type, elem_type) => write_binary_list::<W, E, B>(writer, element, name, index_type, elem_type)?,
+    })
+}
+
+fn write_binary_list<W: Write, E: PropertyAccess, B: byteorder::ByteOrder>(
+    writer: &mut W,
+    element: &E,
+    name: &str,
+    index_type: &ScalarType,
+    elem_type: &ScalarType,
+) -> Result<usize> {
+    let missing = || PlyError::Inconsistent(format!("Missing list property '{}'.", name));
+
+    macro_rules! write_list {
+        ($getter:ident, $write:ident $(::<$b:ty>)?, $size:expr) => {{
+            let values = element.$getter(name).ok_or_else(missing)?;
+            let mut written = write_list_len::<W, B>(writer, index_type, values.len())?;
+            for v in values.iter() {
+                writer.$write $(::<$b>)? (*v)?;
+                written += $size;
+            }
+            written
+        }};
+    }
+
+    Ok(match elem_type {
+        ScalarType::Char => write_list!(get_list_char, write_i8, 1),
+        ScalarType::UChar => write_list!(get_list_uchar, write_u8, 1),
+        ScalarType::Short => write_list!(get_list_short, write_i16::<B>, 2),
+        ScalarType::UShort => write_list!(get_list_ushort, write_u16::<B>, 2),
+        ScalarType::Int => write_list!(get_list_int, write_i32::<B>, 4),
+        ScalarType::UInt => write_list!(get_list_uint, write_u32::<B>, 4),
+        ScalarType::Half => {
+            let values = element.get_list_half(name).ok_or_else(missing)?;
+            let mut written = write_list_len::<W, B>(writer, index_type, values.len())?;
+            for v in values.iter() {
+                writer.write_u16::<B>(v.to_bits())?;
+                written += 2;
+            }
+            written
+        }
+        ScalarType::Float => write_list!(get_list_float, write_f32::<B>, 4),
+        ScalarType::Double => write_list!(get_list_double, write_f64::<B>, 8),
+        ScalarType::Long => write_list!(get_list_long, write_i64::<B>, 8),
+        ScalarType::ULong => write_list!(get_list_ulong, write_u64::<B>, 8),
+    })
+}
+
+fn write_list_len<W: Write, B: byteorder::ByteOrder>(writer: &mut W, index_type: &ScalarType, len: usize) -> Result<usize> {
+    Ok(match index_type {
+        ScalarType::Char => {
+            writer.write_i8(i8::try_from(len).map_err(|_| PlyError::Serialize("List too long for i8 index.".to_string()))?)?;
+            1
+        }
+        ScalarType::UChar => {
+            writer.write_u8(u8::try_from(len).map_err(|_| PlyError::Serialize("List too long for u8 index.".to_string()))?)?;
+            1
+        }
+        ScalarType::Short => {
+            writer.write_i16::<B>(i16::try_from(len).map_err(|_| PlyError::Serialize("List too long for i16 index.".to_string()))?)?;
+            2
+        }
+        ScalarType::UShort => {
+            writer.write_u16::<B>(u16::try_from(len).map_err(|_| PlyError::Serialize("List too long for u16 index.".to_string()))?)?;
+            2
+        }
+        ScalarType::Int => {
+            writer.write_i32::<B>(i32::try_from(len).map_err(|_| PlyError::Serialize("List too long for i32 index.".to_string()))?)?;
+            4
+        }
+        ScalarType::UInt => {
+            writer.write_u32::<B>(u32::try_from(len).map_err(|_| PlyError::Serialize("List too long for u32 index.".to_string()))?)?;
+            4
+        }
+        ScalarType::Long => {
+            writer.write_i64::<B>(i64::try_from(len).map_err(|_| PlyError::Serialize("List too long for i64 index.".to_string()))?)?;
+            8
+        }
+        ScalarType::ULong => {
+            writer.write_u64::<B>(u64::try_from(len).map_err(|_| PlyError::Serialize("List too long for u64 index.".to_string()))?)?;
+            8
+        }
+        ScalarType::Half => return Err(PlyError::Serialize("Index of list must be an integer type, float16 declared in ScalarType.".to_string())),
+        ScalarType::Float => return Err(PlyError::Serialize("Index of list must be an integer type, float declared in ScalarType.".to_string())),
+        ScalarType::Double => return Err(PlyError::Serialize("Index of list must be an integer type, double declared in ScalarType.".to_string())),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ply::{Addable, DefaultElement, KeyMap, PropertyDef, Property};
+
+    fn point_element() -> (ElementDef, DefaultElement) {
+        let mut def = ElementDef::new("point".to_string());
+        def.count = 1;
+        def.properties.add(PropertyDef::new("x".to_string(), PropertyType::Scalar(ScalarType::Int)));
+        def.properties.add(PropertyDef::new("y".to_string(), PropertyType::Scalar(ScalarType::Float)));
+
+        let mut point = KeyMap::new();
+        point.insert("x".to_string(), Property::Int(-7));
+        point.insert("y".to_string(), Property::Float(2.5));
+        (def, point)
+    }
+
+    #[test]
+    fn write_ascii_element_joins_properties_with_spaces() {
+        let (def, point) = point_element();
+        let writer = Writer::<DefaultElement>::new();
+        let mut buf = Vec::new();
+        writer.write_ascii_element(&mut buf, &point, &def).unwrap();
+        assert_eq!(String::from_utf8(buf).unwrap(), "-7 2.5\n");
+    }
+
+    #[test]
+    fn write_little_endian_element_reports_bytes_written() {
+        let (def, point) = point_element();
+        let writer = Writer::<DefaultElement>::new();
+        let mut buf = Vec::new();
+        let written = writer.write_little_endian_element(&mut buf, &point, &def).unwrap();
+        assert_eq!(written, 4 + 4);
+        assert_eq!(buf.len(), written);
+        assert_eq!(&buf[0..4], &(-7i32).to_le_bytes());
+        assert_eq!(&buf[4..8], &2.5f32.to_le_bytes());
+    }
+
+    #[test]
+    fn write_ply_round_trips_through_parser() {
+        use crate::parser::Parser;
+
+        let mut ply = Ply::<DefaultElement>::new();
+        let (def, point) = point_element();
+        ply.header.elements.add(def);
+        ply.payload.insert("point".to_string(), vec![point]);
+
+        let writer = Writer::<DefaultElement>::new();
+        let mut buf = Vec::new();
+        writer.write_ply(&mut buf, &ply).unwrap();
+
+        let parser = Parser::<DefaultElement>::new();
+        let read_back = parser.read_ply(&mut buf.as_slice()).unwrap();
+        assert_eq!(read_back.header.encoding, Encoding::Ascii);
+        assert_eq!(read_back.payload["point"][0].get_int("x"), Some(-7));
+        assert_eq!(read_back.payload["point"][0].get_float("y"), Some(2.5));
+    }
+
+    struct Shape {
+        points: Vec<DefaultElement>,
+    }
+
+    impl ToPly for Shape {
+        fn write_ply<W: Write>(&self, writer: &mut W) -> PlyResult<usize> {
+            self.write_ply_with_encoding(writer, Encoding::Ascii)
+        }
+
+        fn write_ply_with_encoding<W: Write>(&self, writer: &mut W, encoding: Encoding) -> PlyResult<usize> {
+            let (def, _) = point_element();
+            let mut header = Header::new();
+            header.encoding = encoding;
+            header.elements.add(def);
+
+            let w = Writer::<DefaultElement>::new();
+            let mut written = w.write_header(writer, &header)?;
+            let element_def = header.elements.get("point").unwrap();
+            written += w.write_payload_of_element(writer, &self.points, element_def, &header)?;
+            Ok(written)
+        }
+    }
+
+    #[test]
+    fn to_ply_writer_selects_encoding() {
+        let (_, point) = point_element();
+        let shape = Shape { points: vec![point] };
+
+        let mut ascii_buf = Vec::new();
+        shape.to_ply_writer().write(&mut ascii_buf).unwrap();
+        assert!(String::from_utf8_lossy(&ascii_buf).contains("format ascii 1.0"));
+
+        let mut be_buf = Vec::new();
+        shape.to_ply_writer().format(Encoding::BinaryBigEndian).write(&mut be_buf).unwrap();
+        assert!(String::from_utf8_lossy(&be_buf).contains("format binary_big_endian 1.0"));
+        assert_ne!(ascii_buf, be_buf);
+    }
+
+    #[test]
+    fn write_header_rejects_float_list_index() {
+        let mut def = ElementDef::new("face".to_string());
+        def.properties.add(PropertyDef::new(
+            "vertex_index".to_string(),
+            PropertyType::List(ScalarType::Float, ScalarType::Int),
+        ));
+        let mut header = Header::new();
+        header.elements.add(def);
+
+        let writer = Writer::<DefaultElement>::new();
+        let mut buf = Vec::new();
+        assert!(writer.write_header(&mut buf, &header).is_err());
+    }
+}
@@ -0,0 +1,55 @@
+use ply_rs_bw::{PlyRead, PlyWrite};
+use ply_rs_bw::ply::{Property, PropertyAccess, WriteSchema, ReadSchema, PropertyType, ScalarType, Requiredness};
+
+#[derive(Debug, Default, PlyRead, PlyWrite, PartialEq)]
+struct Vertex {
+    #[ply(name = "x, y, z")]
+    position: [f32; 3],
+    #[ply(name = "red, green, blue", type = "uchar")]
+    color: (u8, u8, u8),
+}
+
+#[test]
+fn test_array_and_tuple_fields_expand_to_one_property_each() {
+    assert_eq!(Vertex::schema(), vec![
+        ("x".to_string(), Requiredness::Required),
+        ("y".to_string(), Requiredness::Required),
+        ("z".to_string(), Requiredness::Required),
+        ("red".to_string(), Requiredness::Required),
+        ("green".to_string(), Requiredness::Required),
+        ("blue".to_string(), Requiredness::Required),
+    ]);
+
+    assert_eq!(Vertex::property_type_schema(), vec![
+        ("x".to_string(), PropertyType::Scalar(ScalarType::Float)),
+        ("y".to_string(), PropertyType::Scalar(ScalarType::Float)),
+        ("z".to_string(), PropertyType::Scalar(ScalarType::Float)),
+        ("red".to_string(), PropertyType::Scalar(ScalarType::UChar)),
+        ("green".to_string(), PropertyType::Scalar(ScalarType::UChar)),
+        ("blue".to_string(), PropertyType::Scalar(ScalarType::UChar)),
+    ]);
+}
+
+#[test]
+fn test_array_field_round_trips_by_slot() {
+    let mut v = Vertex::default();
+    v.set_property("x", Property::Float(1.0));
+    v.set_property("y", Property::Float(2.0));
+    v.set_property("z", Property::Float(3.0));
+    assert_eq!(v.position, [1.0, 2.0, 3.0]);
+    assert_eq!(v.get_float("x"), Some(1.0));
+    assert_eq!(v.get_float("y"), Some(2.0));
+    assert_eq!(v.get_float("z"), Some(3.0));
+}
+
+#[test]
+fn test_tuple_field_round_trips_by_slot_with_explicit_type() {
+    let mut v = Vertex::default();
+    v.set_property("red", Property::UChar(255));
+    v.set_property("green", Property::UChar(128));
+    v.set_property("blue", Property::UChar(0));
+    assert_eq!(v.color, (255, 128, 0));
+    assert_eq!(v.get_uchar("red"), Some(255));
+    assert_eq!(v.get_uchar("green"), Some(128));
+    assert_eq!(v.get_uchar("blue"), Some(0));
+}
@@ -0,0 +1,52 @@
+//! Behavioral coverage for `#[ply(coerce = "strict")]`: unlike `ply-rs-macros/src/tests.rs`,
+//! which only checks the attribute parses, these exercise the generated conversion against a
+//! real parsed `int64`/`uint64` property.
+
+use ply_rs_bw::parser::Parser;
+use ply_rs_bw::PlyRead;
+use std::io::BufReader;
+
+#[derive(Debug, Default, PlyRead, Clone, PartialEq)]
+struct Wide {
+    #[ply(coerce = "strict")]
+    signed: i64,
+    #[ply(coerce = "strict")]
+    unsigned: u64,
+}
+
+#[test]
+fn coerce_strict_round_trips_int64_and_uint64() {
+    let bytes = b"ply\n\
+format ascii 1.0\n\
+element wide 1\n\
+property int64 signed\n\
+property uint64 unsigned\n\
+end_header\n\
+-9000000000 18000000000000000000\n";
+    let mut reader = BufReader::new(&bytes[..]);
+    let parser = Parser::<Wide>::new();
+    let ply = parser.read_ply(&mut reader).expect("strict coerce of int64/uint64 should succeed");
+    let element = &ply.payload["wide"][0];
+    assert_eq!(element.signed, -9_000_000_000);
+    assert_eq!(element.unsigned, 18_000_000_000_000_000_000);
+}
+
+#[derive(Debug, Default, PlyRead, Clone, PartialEq)]
+struct Narrow {
+    #[ply(coerce = "strict")]
+    value: i64,
+}
+
+#[test]
+#[should_panic(expected = "ply(coerce = \"strict\") rejected out-of-range value")]
+fn coerce_strict_panics_on_uint64_value_out_of_i64_range() {
+    let bytes = b"ply\n\
+format ascii 1.0\n\
+element narrow 1\n\
+property uint64 value\n\
+end_header\n\
+18446744073709551615\n";
+    let mut reader = BufReader::new(&bytes[..]);
+    let parser = Parser::<Narrow>::new();
+    let _ = parser.read_ply(&mut reader);
+}
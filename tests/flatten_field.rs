@@ -0,0 +1,66 @@
+use ply_rs_bw::{PlyRead, PlyWrite};
+use ply_rs_bw::ply::{Property, PropertyAccess, WriteSchema, ReadSchema, PropertyType, ScalarType, Requiredness};
+
+#[derive(Debug, Default, PlyRead, PlyWrite, Clone, PartialEq)]
+struct Rgba {
+    r: u8,
+    g: u8,
+    b: u8,
+    a: u8,
+}
+
+#[derive(Debug, Default, PlyRead, PlyWrite, PartialEq)]
+struct Vertex {
+    x: f32,
+    y: f32,
+    z: f32,
+    #[ply(flatten)]
+    color: Rgba,
+}
+
+#[test]
+fn test_flattened_schema_merges_child_properties_after_its_own() {
+    assert_eq!(Vertex::schema(), vec![
+        ("x".to_string(), Requiredness::Required),
+        ("y".to_string(), Requiredness::Required),
+        ("z".to_string(), Requiredness::Required),
+        ("r".to_string(), Requiredness::Required),
+        ("g".to_string(), Requiredness::Required),
+        ("b".to_string(), Requiredness::Required),
+        ("a".to_string(), Requiredness::Required),
+    ]);
+
+    assert_eq!(Vertex::property_type_schema(), vec![
+        ("x".to_string(), PropertyType::Scalar(ScalarType::Float)),
+        ("y".to_string(), PropertyType::Scalar(ScalarType::Float)),
+        ("z".to_string(), PropertyType::Scalar(ScalarType::Float)),
+        ("r".to_string(), PropertyType::Scalar(ScalarType::UChar)),
+        ("g".to_string(), PropertyType::Scalar(ScalarType::UChar)),
+        ("b".to_string(), PropertyType::Scalar(ScalarType::UChar)),
+        ("a".to_string(), PropertyType::Scalar(ScalarType::UChar)),
+    ]);
+}
+
+#[test]
+fn test_set_property_delegates_to_the_flattened_child() {
+    let mut v = Vertex::default();
+    v.set_property("x", Property::Float(1.0));
+    v.set_property("r", Property::UChar(255));
+    v.set_property("g", Property::UChar(128));
+    v.set_property("b", Property::UChar(64));
+    v.set_property("a", Property::UChar(32));
+    assert_eq!(v.x, 1.0);
+    assert_eq!(v.color, Rgba { r: 255, g: 128, b: 64, a: 32 });
+}
+
+#[test]
+fn test_getters_fall_back_to_the_flattened_child() {
+    let v = Vertex {
+        x: 1.0, y: 2.0, z: 3.0,
+        color: Rgba { r: 10, g: 20, b: 30, a: 40 },
+    };
+    assert_eq!(v.get_float("x"), Some(1.0));
+    assert_eq!(v.get_uchar("r"), Some(10));
+    assert_eq!(v.get_uchar("a"), Some(40));
+    assert_eq!(v.get_uchar("nonexistent"), None);
+}
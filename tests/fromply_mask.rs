@@ -0,0 +1,81 @@
+use ply_rs_bw::parser::{ElementPresence, FromPlyWithMask};
+use ply_rs_bw::{FromPly, PlyRead};
+
+#[derive(Debug, Default, PlyRead, PartialEq)]
+struct Vertex {
+    #[ply(name = "x, X")]
+    x: f32,
+    #[ply(name = "y, Y")]
+    y: f32,
+}
+
+#[derive(Debug, FromPly, PartialEq)]
+struct Mesh {
+    #[ply(name = "vertex")]
+    vertices: Vec<Vertex>,
+    #[ply(optional)]
+    cameras: Vec<Vertex>,
+}
+
+fn header_and_vertices() -> &'static [u8] {
+    b"ply\n\
+format ascii 1.0\n\
+element vertex 1\n\
+property float x\n\
+property float y\n\
+end_header\n\
+1.0 2.0\n"
+}
+
+#[test]
+fn present_element_is_reported_present() {
+    let mut reader = std::io::Cursor::new(header_and_vertices());
+    let (mesh, mask) = Mesh::read_ply_with_mask(&mut reader).unwrap();
+
+    assert_eq!(mesh.vertices.len(), 1);
+    assert_eq!(mask.get("vertices"), Some(&ElementPresence::Present));
+}
+
+#[test]
+fn missing_optional_element_defaults_and_is_reported_missing() {
+    let mut reader = std::io::Cursor::new(header_and_vertices());
+    let (mesh, mask) = Mesh::read_ply_with_mask(&mut reader).unwrap();
+
+    assert!(mesh.cameras.is_empty());
+    assert_eq!(mask.get("cameras"), Some(&ElementPresence::Missing));
+}
+
+#[test]
+fn missing_required_element_is_an_error() {
+    let txt = b"ply\n\
+format ascii 1.0\n\
+element camera 1\n\
+property float x\n\
+property float y\n\
+end_header\n\
+1.0 2.0\n";
+    let mut reader = std::io::Cursor::new(txt.as_slice());
+    assert!(Mesh::read_ply(&mut reader).is_err());
+}
+
+#[derive(Debug, FromPly, PartialEq)]
+struct SynonymMesh {
+    #[ply(name = "vertex, vertices")]
+    vertices: Vec<Vertex>,
+}
+
+#[test]
+fn synonym_match_is_reported_with_the_name_seen_in_the_file() {
+    let txt = b"ply\n\
+format ascii 1.0\n\
+element vertices 1\n\
+property float x\n\
+property float y\n\
+end_header\n\
+1.0 2.0\n";
+    let mut reader = std::io::Cursor::new(txt.as_slice());
+    let (mesh, mask) = SynonymMesh::read_ply_with_mask(&mut reader).unwrap();
+
+    assert_eq!(mesh.vertices.len(), 1);
+    assert_eq!(mask.get("vertices"), Some(&ElementPresence::Synonym("vertices".to_string())));
+}
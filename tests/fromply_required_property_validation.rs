@@ -0,0 +1,49 @@
+use ply_rs_bw::{FromPly, PlyRead};
+use ply_rs_bw::PlyError;
+
+#[derive(Debug, Default, PlyRead, PartialEq)]
+struct Vertex {
+    x: f32,
+    y: f32,
+    #[ply(default = "1.0")]
+    confidence: f32,
+}
+
+#[derive(Debug, FromPly, PartialEq)]
+struct Mesh {
+    #[ply(name = "vertex")]
+    vertices: Vec<Vertex>,
+}
+
+#[test]
+fn missing_required_property_is_a_schema_error_naming_the_element_and_property() {
+    let txt = b"ply\n\
+format ascii 1.0\n\
+element vertex 1\n\
+property float x\n\
+end_header\n\
+1.0\n";
+    let mut reader = std::io::Cursor::new(txt.as_slice());
+    let err = Mesh::read_ply(&mut reader).unwrap_err();
+    match err {
+        PlyError::Schema(schema_err) => {
+            assert_eq!(schema_err.element, "vertex");
+            assert_eq!(schema_err.property, "y");
+        }
+        other => panic!("expected a schema error, got {other:?}"),
+    }
+}
+
+#[test]
+fn omitted_defaulted_property_falls_back_to_ply_default() {
+    let txt = b"ply\n\
+format ascii 1.0\n\
+element vertex 1\n\
+property float x\n\
+property float y\n\
+end_header\n\
+1.0 2.0\n";
+    let mut reader = std::io::Cursor::new(txt.as_slice());
+    let mesh = Mesh::read_ply(&mut reader).unwrap();
+    assert_eq!(mesh.vertices[0].confidence, 1.0);
+}
@@ -0,0 +1,126 @@
+use ply_rs_bw::{FromPly, PlyRead};
+
+#[derive(Debug, Default, PlyRead, PartialEq, Clone)]
+struct Vertex {
+    #[ply(name = "x")]
+    x: f32,
+    #[ply(name = "y")]
+    y: f32,
+}
+
+#[derive(Debug, Default, PlyRead, PartialEq, Clone)]
+struct Face {
+    #[ply(name = "vertex_index")]
+    indices: Vec<i32>,
+}
+
+#[derive(Debug, FromPly, PartialEq)]
+struct Mesh {
+    #[ply(name = "vertex")]
+    vertices: Vec<Vertex>,
+    #[ply(name = "face", optional)]
+    faces: Vec<Face>,
+}
+
+fn sample() -> &'static [u8] {
+    b"ply\n\
+format ascii 1.0\n\
+element vertex 3\n\
+property float x\n\
+property float y\n\
+element face 1\n\
+property list uchar int vertex_index\n\
+end_header\n\
+0.0 0.0\n\
+1.0 0.0\n\
+1.0 1.0\n\
+3 0 1 2\n"
+}
+
+#[test]
+fn streams_every_record_without_collecting_into_vecs() {
+    let mut reader = std::io::Cursor::new(sample());
+
+    let mut vertex_count = 0;
+    let mut max_x = f32::MIN;
+    let mut face_indices = Vec::new();
+
+    Mesh::read_ply_streaming(
+        &mut reader,
+        |v: &Vertex| {
+            vertex_count += 1;
+            max_x = max_x.max(v.x);
+            Ok(())
+        },
+        |f: &Face| {
+            face_indices.extend(f.indices.iter().copied());
+            Ok(())
+        },
+    )
+    .unwrap();
+
+    assert_eq!(vertex_count, 3);
+    assert_eq!(max_x, 1.0);
+    assert_eq!(face_indices, vec![0, 1, 2]);
+}
+
+#[test]
+fn missing_optional_element_never_invokes_its_callback() {
+    let txt = b"ply\n\
+format ascii 1.0\n\
+element vertex 1\n\
+property float x\n\
+property float y\n\
+end_header\n\
+0.0 0.0\n";
+    let mut reader = std::io::Cursor::new(txt.as_slice());
+
+    let mut face_calls = 0;
+    Mesh::read_ply_streaming(
+        &mut reader,
+        |_v: &Vertex| Ok(()),
+        |_f: &Face| {
+            face_calls += 1;
+            Ok(())
+        },
+    )
+    .unwrap();
+
+    assert_eq!(face_calls, 0);
+}
+
+#[test]
+fn missing_required_element_is_an_error() {
+    let txt = b"ply\n\
+format ascii 1.0\n\
+element face 1\n\
+property list uchar int vertex_index\n\
+end_header\n\
+3 0 1 2\n";
+    let mut reader = std::io::Cursor::new(txt.as_slice());
+
+    let result = Mesh::read_ply_streaming(&mut reader, |_v: &Vertex| Ok(()), |_f: &Face| Ok(()));
+    assert!(result.is_err());
+}
+
+#[test]
+fn callback_error_aborts_the_read_early() {
+    let mut reader = std::io::Cursor::new(sample());
+
+    let mut vertex_count = 0;
+    let result = Mesh::read_ply_streaming(
+        &mut reader,
+        |_v: &Vertex| {
+            vertex_count += 1;
+            if vertex_count == 2 {
+                Err(ply_rs_bw::PlyError::Parse("stopping early".to_string()))
+            } else {
+                Ok(())
+            }
+        },
+        |_f: &Face| Ok(()),
+    );
+
+    assert!(result.is_err());
+    assert_eq!(vertex_count, 2);
+}
@@ -0,0 +1,35 @@
+//! `position: [f32; 3]`-style array fields were already added to `PlyRead`/`PlyWrite`
+//! (expanding to one scalar property per slot) and `WriteSchema` picks them up automatically
+//! since `#[derive(PlyWrite)]` implements it. This exercises the remaining, previously
+//! untested path: a `FromPly`/`ToPly` container whose element type uses such a field.
+
+use ply_rs_bw::{FromPly, PlyRead, PlyWrite};
+use ply_rs_bw::writer::ToPly;
+
+#[derive(Debug, Default, PlyRead, PlyWrite, Clone, PartialEq)]
+struct Vertex {
+    #[ply(name = "x, y, z")]
+    position: [f32; 3],
+}
+
+#[derive(Debug, FromPly, ToPly, PartialEq)]
+struct Mesh {
+    vertices: Vec<Vertex>,
+}
+
+#[test]
+fn array_field_round_trips_through_fromply_toply_container() {
+    let mesh = Mesh {
+        vertices: vec![
+            Vertex { position: [1.0, 2.0, 3.0] },
+            Vertex { position: [4.0, 5.0, 6.0] },
+        ],
+    };
+
+    let mut bytes = Vec::new();
+    mesh.write_ply(&mut bytes).unwrap();
+
+    let mut reader = std::io::Cursor::new(bytes);
+    let read_back = Mesh::read_ply(&mut reader).unwrap();
+    assert_eq!(read_back, mesh);
+}
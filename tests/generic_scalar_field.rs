@@ -0,0 +1,66 @@
+use ply_rs_bw::{PlyRead, PlyWrite};
+use ply_rs_bw::ply::{Property, PropertyAccess, WriteSchema, PropertyType, ScalarType, PlyScalar};
+
+#[derive(Debug, Default, PlyRead, PlyWrite, Clone, PartialEq)]
+struct Vertex<S: PlyScalar> {
+    x: S,
+    y: S,
+    z: S,
+}
+
+#[test]
+fn test_property_type_schema_follows_monomorphized_scalar() {
+    let f32_schema = Vertex::<f32>::property_type_schema();
+    assert_eq!(f32_schema, vec![
+        ("x".to_string(), PropertyType::Scalar(ScalarType::Float)),
+        ("y".to_string(), PropertyType::Scalar(ScalarType::Float)),
+        ("z".to_string(), PropertyType::Scalar(ScalarType::Float)),
+    ]);
+
+    let f64_schema = Vertex::<f64>::property_type_schema();
+    assert_eq!(f64_schema, vec![
+        ("x".to_string(), PropertyType::Scalar(ScalarType::Double)),
+        ("y".to_string(), PropertyType::Scalar(ScalarType::Double)),
+        ("z".to_string(), PropertyType::Scalar(ScalarType::Double)),
+    ]);
+}
+
+#[test]
+fn test_set_property_widens_float_into_f64() {
+    let mut v = Vertex::<f64>::default();
+    v.set_property("x", Property::Float(1.5));
+    v.set_property("y", Property::Double(2.5));
+    assert_eq!(v.x, 1.5);
+    assert_eq!(v.y, 2.5);
+}
+
+#[test]
+fn test_set_property_narrows_double_into_f32() {
+    let mut v = Vertex::<f32>::default();
+    v.set_property("x", Property::Double(3.25));
+    assert_eq!(v.x, 3.25);
+}
+
+#[test]
+fn test_get_dispatches_to_the_bucket_matching_the_monomorphized_type() {
+    let v32 = Vertex::<f32> { x: 1.5, y: 2.5, z: 3.5 };
+    assert_eq!(v32.get_float("x"), Some(1.5));
+    assert_eq!(v32.get_double("x"), None);
+
+    let v64 = Vertex::<f64> { x: 1.5, y: 2.5, z: 3.5 };
+    assert_eq!(v64.get_double("y"), Some(2.5));
+    assert_eq!(v64.get_float("y"), None);
+}
+
+#[derive(Debug, Default, PlyRead, PlyWrite, Clone, PartialEq)]
+struct Polyline<S: PlyScalar> {
+    #[ply(list)]
+    points: Vec<S>,
+}
+
+#[test]
+fn test_set_property_converts_a_generic_list_element_by_element() {
+    let mut line = Polyline::<f32>::default();
+    line.set_property("points", Property::ListDouble(vec![1.0, 2.0, 3.0]));
+    assert_eq!(line.points, vec![1.0f32, 2.0, 3.0]);
+}
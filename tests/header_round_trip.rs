@@ -0,0 +1,73 @@
+use ply_rs_bw::writer::ToPly;
+use ply_rs_bw::{FromPly, PlyRead, PlyWrite};
+use ply_rs_bw::ply::PlyHeaderMeta;
+
+#[derive(Debug, Default, PlyRead, PlyWrite, Clone, PartialEq)]
+struct Vertex {
+    #[ply(type = "double")]
+    x: f32,
+    #[ply(type = "double")]
+    y: f32,
+}
+
+#[derive(Debug, FromPly, ToPly, PartialEq)]
+struct Mesh {
+    #[ply(name = "vertex")]
+    vertices: Vec<Vertex>,
+    #[ply(header)]
+    header: PlyHeaderMeta,
+}
+
+fn source_bytes() -> &'static [u8] {
+    b"ply\n\
+format ascii 1.0\n\
+comment generated by a vendor tool\n\
+obj_info scan_id 42\n\
+element vertex 2\n\
+property double x\n\
+property double y\n\
+end_header\n\
+1 2\n\
+3 4\n"
+}
+
+#[test]
+fn read_populates_header_meta() {
+    let mut reader = std::io::Cursor::new(source_bytes());
+    let mesh = Mesh::read_ply(&mut reader).unwrap();
+    let header = mesh.header.0.as_ref().expect("header should be populated");
+    assert_eq!(header.comments, vec!["generated by a vendor tool".to_string()]);
+    assert_eq!(header.obj_infos, vec!["scan_id 42".to_string()]);
+}
+
+#[test]
+fn write_preserves_comments_obj_info_and_original_property_types() {
+    let mut reader = std::io::Cursor::new(source_bytes());
+    let mesh = Mesh::read_ply(&mut reader).unwrap();
+
+    let mut out = Vec::new();
+    mesh.write_ply(&mut out).unwrap();
+    let out_text = String::from_utf8(out).unwrap();
+
+    // The Rust fields are `f32` but carry `#[ply(type = "double")]`, so the written header
+    // keeps the original `double` declarations along with its comment and obj_info.
+    assert!(out_text.contains("property double x"));
+    assert!(out_text.contains("property double y"));
+    assert!(out_text.contains("comment generated by a vendor tool"));
+    assert!(out_text.contains("obj_info scan_id 42"));
+}
+
+#[test]
+fn write_falls_back_to_a_fresh_header_when_never_read_from_a_file() {
+    let mesh = Mesh {
+        vertices: vec![Vertex { x: 1.0, y: 2.0 }],
+        header: PlyHeaderMeta::default(),
+    };
+
+    let mut out = Vec::new();
+    mesh.write_ply(&mut out).unwrap();
+    let out_text = String::from_utf8(out).unwrap();
+
+    assert!(out_text.contains("property double x"));
+    assert!(!out_text.contains("comment"));
+}
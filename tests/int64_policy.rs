@@ -0,0 +1,97 @@
+//! Coverage for [`ply_rs_bw::serde_impl::Int64Policy`], which decides how `i64`/`u64`/`i128`/
+//! `u128` values are encoded when serializing: there was previously no test anywhere in the
+//! tree for `Widen`, `SplitList`, or the default `Error` policy's actual serialize-time
+//! behavior.
+
+use serde::{Deserialize, Serialize};
+use ply_rs_bw::serde_impl::{words_to_u128, Int64Policy};
+use ply_rs_bw::PlyWriteConfig;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Wide64 {
+    v: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Mesh64 {
+    point: Vec<Wide64>,
+}
+
+#[derive(Serialize, Debug)]
+struct Wide128 {
+    v: i128,
+}
+
+#[derive(Serialize, Debug)]
+struct Mesh128 {
+    point: Vec<Wide128>,
+}
+
+fn ascii_data_line(buf: &[u8]) -> &str {
+    let text = std::str::from_utf8(buf).unwrap();
+    let header_end = text.find("end_header\n").expect("header should be present");
+    text[header_end + "end_header\n".len()..].trim_end()
+}
+
+#[test]
+fn error_policy_round_trips_i64_as_property_long() {
+    let mesh = Mesh64 { point: vec![Wide64 { v: -9_000_000_000 }] };
+
+    let mut buf = Vec::new();
+    ply_rs_bw::to_writer(&mut buf, &mesh).unwrap();
+    let text = String::from_utf8(buf.clone()).unwrap();
+    assert!(text.contains("property int64 v"));
+    assert_eq!(ascii_data_line(&buf), "-9000000000");
+
+    let mesh_read: Mesh64 = ply_rs_bw::from_reader(&buf[..]).unwrap();
+    assert_eq!(mesh, mesh_read);
+}
+
+#[test]
+fn error_policy_rejects_i128_outside_i64_range() {
+    let mesh = Mesh128 { point: vec![Wide128 { v: i128::from(i64::MAX) + 1 }] };
+
+    let mut buf = Vec::new();
+    let err = ply_rs_bw::to_writer(&mut buf, &mesh).unwrap_err();
+    assert!(err.to_string().contains("does not fit a PLY int64 property"));
+}
+
+#[test]
+fn widen_policy_encodes_exact_values_as_double_and_rejects_imprecise_ones() {
+    let config = PlyWriteConfig { int64_policy: Int64Policy::Widen, ..Default::default() };
+
+    let exact = Mesh64 { point: vec![Wide64 { v: 1_000_000 }] };
+    let mut buf = Vec::new();
+    ply_rs_bw::to_writer_with(&mut buf, &exact, config).unwrap();
+    let text = String::from_utf8(buf.clone()).unwrap();
+    assert!(text.contains("property double v"));
+    assert_eq!(ascii_data_line(&buf), "1000000");
+
+    // 2^53 + 1 is the smallest positive integer that can't be represented exactly as f64.
+    let imprecise = Mesh64 { point: vec![Wide64 { v: (1i64 << 53) + 1 }] };
+    let mut buf = Vec::new();
+    let err = ply_rs_bw::to_writer_with(&mut buf, &imprecise, config).unwrap_err();
+    assert!(err.to_string().contains("cannot be represented exactly as f64"));
+}
+
+#[test]
+fn split_list_policy_round_trips_64_and_128_bit_values_through_list_uint_words() {
+    let config = PlyWriteConfig { int64_policy: Int64Policy::SplitList, ..Default::default() };
+
+    let mesh = Mesh64 { point: vec![Wide64 { v: -1 } ] };
+    let mut buf = Vec::new();
+    ply_rs_bw::to_writer_with(&mut buf, &mesh, config).unwrap();
+    let text = String::from_utf8(buf.clone()).unwrap();
+    assert!(text.contains("property list uchar uint v"));
+    let words: Vec<u32> = ascii_data_line(&buf).split_whitespace().skip(1).map(|w| w.parse().unwrap()).collect();
+    assert_eq!(words.len(), 2);
+    assert_eq!(words_to_u128(&words).unwrap() as i64 as i128, -1i128);
+
+    let value = i128::from(u64::MAX) + 42;
+    let mesh = Mesh128 { point: vec![Wide128 { v: value }] };
+    let mut buf = Vec::new();
+    ply_rs_bw::to_writer_with(&mut buf, &mesh, config).unwrap();
+    let words: Vec<u32> = ascii_data_line(&buf).split_whitespace().skip(1).map(|w| w.parse().unwrap()).collect();
+    assert_eq!(words.len(), 4);
+    assert_eq!(words_to_u128(&words).unwrap() as i128, value);
+}
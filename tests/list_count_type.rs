@@ -0,0 +1,24 @@
+use ply_rs_bw::{PlyRead, PlyWrite};
+use ply_rs_bw::ply::{PropertyAccess, WriteSchema, PropertyType, ScalarType};
+
+#[derive(PlyRead, PlyWrite, Default, Debug, Clone)]
+struct Face {
+    #[ply(name = "vertex_indices", list, count = "ushort")]
+    indices: Vec<u32>,
+}
+
+#[test]
+fn test_list_count_type_schema() {
+    let props = Face::property_type_schema();
+    let (name, type_) = &props[0];
+    assert_eq!(name, "vertex_indices");
+    assert_eq!(*type_, PropertyType::List(ScalarType::UShort, ScalarType::UInt));
+}
+
+#[test]
+fn test_list_count_type_access() {
+    let face = Face {
+        indices: vec![1, 2, 3],
+    };
+    assert_eq!(face.get_list_uint("vertex_indices").unwrap().to_vec(), vec![1, 2, 3]);
+}
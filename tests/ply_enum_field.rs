@@ -0,0 +1,43 @@
+use ply_rs_bw::{PlyEnum, PlyRead, PlyWrite};
+use ply_rs_bw::ply::{Property, PropertyAccess, PropertyType, ScalarType, WriteSchema};
+
+#[derive(Debug, PlyEnum, PartialEq, Clone, Copy, Default)]
+#[ply(repr = "uchar")]
+enum Material {
+    #[default]
+    Wood,
+    Metal,
+    #[ply(value = 9)]
+    Glass,
+}
+
+#[derive(Debug, PlyRead, PlyWrite, PartialEq, Default)]
+struct Vertex {
+    #[ply(enum, type = "uchar")]
+    material: Material,
+}
+
+#[test]
+fn test_ply_enum_schema_uses_repr() {
+    let props = Vertex::property_type_schema();
+    assert_eq!(props[0], ("material".to_string(), PropertyType::Scalar(ScalarType::UChar)));
+}
+
+#[test]
+fn test_ply_enum_round_trips_declared_and_explicit_discriminants() {
+    let mut v = Vertex::new();
+    v.set_property("material", Property::UChar(1));
+    assert_eq!(v.material, Material::Metal);
+    assert_eq!(v.get_uchar("material"), Some(1));
+
+    v.set_property("material", Property::UChar(9));
+    assert_eq!(v.material, Material::Glass);
+    assert_eq!(v.get_uchar("material"), Some(9));
+}
+
+#[test]
+fn test_ply_enum_unknown_discriminant_leaves_field_at_default() {
+    let mut v = Vertex::new();
+    v.set_property("material", Property::UChar(200));
+    assert_eq!(v.material, Material::Wood);
+}
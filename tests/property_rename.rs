@@ -0,0 +1,44 @@
+use ply_rs_bw::{PlyRead, PlyWrite, FromPly};
+use ply_rs_bw::ply::{PropertyAccess, WriteSchema};
+
+#[derive(Debug, PlyRead, PlyWrite, PartialEq, Default)]
+struct Normal {
+    #[ply(name = "normal_x, nx", rename = "nx")]
+    x: f32,
+    #[ply(name = "normal_y, ny", rename = "ny")]
+    y: f32,
+}
+
+#[derive(Debug, FromPly, PartialEq)]
+struct Mesh {
+    #[ply(name = "vertex")]
+    normals: Vec<Normal>,
+}
+
+#[test]
+fn test_rename_picks_canonical_write_name() {
+    let props = Normal::property_type_schema();
+    let names: Vec<&str> = props.iter().map(|(n, _)| n.as_str()).collect();
+    // Reading accepts either alias, but writing always emits the `rename`d
+    // spelling, regardless of alias order in `#[ply(name = "...")]`.
+    assert_eq!(names, vec!["nx", "ny"]);
+}
+
+#[test]
+fn test_rename_reads_either_alias() {
+    // The header spells the properties the long way ("normal_x"/"normal_y"),
+    // which is only accepted because it's listed in `#[ply(name = "...")]`.
+    let txt = b"ply\n\
+format ascii 1.0\n\
+element vertex 1\n\
+property float normal_x\n\
+property float normal_y\n\
+end_header\n\
+1.0 2.0\n";
+
+    let mut reader = std::io::Cursor::new(&txt[..]);
+    let mesh = Mesh::read_ply(&mut reader).unwrap();
+    assert_eq!(mesh.normals.len(), 1);
+    assert_eq!(mesh.normals[0].x, 1.0);
+    assert_eq!(mesh.normals[0].y, 2.0);
+}
@@ -0,0 +1,72 @@
+//! `BorrowedPropertyDeserializer` ties its data to the `'de` lifetime of the
+//! [`DefaultElement`](ply_rs_bw::ply::DefaultElement) it reads from instead of the call, so a
+//! `ListUChar` property can be handed to `visit_borrowed_bytes` with no copy. There's no
+//! built-in `&'de [u8]` wrapper type in `serde` itself (that's what `serde_bytes` is for), so
+//! this test supplies a minimal one and checks the returned slice is the exact same memory as
+//! the source element's, not merely an equal copy of it.
+
+use ply_rs_bw::ply::{DefaultElement, ElementDef, Property, PropertyDef, PropertyType, ScalarType, PropertyAccess};
+use ply_rs_bw::serde_impl::from_element_borrowed;
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use std::fmt;
+
+struct BorrowedColor<'a>(&'a [u8]);
+
+impl<'de> Deserialize<'de> for BorrowedColor<'de> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ColorVisitor;
+        impl<'de> Visitor<'de> for ColorVisitor {
+            type Value = BorrowedColor<'de>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a borrowed byte slice")
+            }
+
+            fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(BorrowedColor(v))
+            }
+        }
+        deserializer.deserialize_bytes(ColorVisitor)
+    }
+}
+
+#[derive(Deserialize)]
+struct Point<'a> {
+    #[serde(borrow)]
+    rgb: BorrowedColor<'a>,
+}
+
+fn color_element() -> (DefaultElement, ElementDef) {
+    let mut element = DefaultElement::new();
+    element.set_property("rgb", Property::ListUChar(vec![10, 20, 30]));
+
+    let mut element_def = ElementDef::new("vertex".to_string());
+    element_def.properties.insert(
+        "rgb".to_string(),
+        PropertyDef::new("rgb".to_string(), PropertyType::List(ScalarType::UChar, ScalarType::UChar)),
+    );
+
+    (element, element_def)
+}
+
+#[test]
+fn list_uchar_property_deserializes_with_no_copy() {
+    let (element, element_def) = color_element();
+
+    let Property::ListUChar(ref backing) = *element.get("rgb").unwrap() else {
+        panic!("expected ListUChar");
+    };
+    let backing_ptr = backing.as_ptr();
+
+    let point: Point = from_element_borrowed(&element, &element_def).unwrap();
+
+    assert_eq!(point.rgb.0, &[10, 20, 30][..]);
+    // Same allocation as the element's own `Vec<u8>`, not a clone of it.
+    assert_eq!(point.rgb.0.as_ptr(), backing_ptr);
+}
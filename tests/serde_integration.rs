@@ -67,6 +67,368 @@ fn test_serde_write_simple() {
     assert_eq!(mesh, mesh_read);
 }
 
+#[test]
+fn test_serde_write_with_config_binary_and_wide_list_len() {
+    use ply_rs_bw::ply::{Encoding, ScalarType};
+    use ply_rs_bw::PlyWriteConfig;
+
+    let vertex = vec![
+        Vertex { x: 0.1, y: 0.2, z: 0.3 },
+        Vertex { x: 0.4, y: 0.5, z: 0.6 },
+    ];
+    let face = vec![
+        Face { vertex_index: vec![0, 1, 0] },
+    ];
+    let mesh = Mesh { vertex, face };
+
+    let config = PlyWriteConfig {
+        encoding: Encoding::BinaryLittleEndian,
+        list_len_type: ScalarType::UInt,
+        ..Default::default()
+    };
+
+    let mut buf = Vec::new();
+    ply_rs_bw::to_writer_with(&mut buf, &mesh, config).unwrap();
+
+    let header_end = buf.windows(10).position(|w| w == b"end_header").unwrap();
+    let header = String::from_utf8(buf[..header_end].to_vec()).unwrap();
+    assert!(header.contains("format binary_little_endian 1.0"));
+    assert!(header.contains("property list uint int vertex_index"));
+
+    let mesh_read: Mesh = ply_rs_bw::from_reader(&buf[..]).unwrap();
+    assert_eq!(mesh, mesh_read);
+}
+
+#[test]
+fn test_serde_write_with_config_binary_big_endian() {
+    use ply_rs_bw::ply::Encoding;
+    use ply_rs_bw::PlyWriteConfig;
+
+    let vertex = vec![
+        Vertex { x: 0.1, y: 0.2, z: 0.3 },
+        Vertex { x: 0.4, y: 0.5, z: 0.6 },
+    ];
+    let face = vec![Face { vertex_index: vec![0, 1, 0] }];
+    let mesh = Mesh { vertex, face };
+
+    let config = PlyWriteConfig {
+        encoding: Encoding::BinaryBigEndian,
+        ..Default::default()
+    };
+
+    let mut buf = Vec::new();
+    ply_rs_bw::to_writer_with(&mut buf, &mesh, config).unwrap();
+
+    let header_end = buf.windows(10).position(|w| w == b"end_header").unwrap();
+    let header = String::from_utf8(buf[..header_end].to_vec()).unwrap();
+    assert!(header.contains("format binary_big_endian 1.0"));
+
+    let mesh_read: Mesh = ply_rs_bw::from_reader(&buf[..]).unwrap();
+    assert_eq!(mesh, mesh_read);
+}
+
+#[test]
+fn test_serde_write_with_schema_coerces_scalar_to_declared_type() {
+    use ply_rs_bw::ply::{Addable, ElementDef, Header, PropertyDef, PropertyType, ScalarType};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Reading {
+        value: f64,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Readings {
+        reading: Vec<Reading>,
+    }
+
+    let readings = Readings {
+        reading: vec![Reading { value: 0.5 }, Reading { value: 1.25 }],
+    };
+
+    let mut header = Header::new();
+    let mut element_def = ElementDef::new("reading".to_string());
+    element_def.properties.add(PropertyDef::new("value".to_string(), PropertyType::Scalar(ScalarType::Float)));
+    header.elements.add(element_def);
+
+    let mut buf = Vec::new();
+    ply_rs_bw::to_writer_with_schema(&mut buf, &readings, &header).unwrap();
+
+    let header_end = buf.windows(10).position(|w| w == b"end_header").unwrap();
+    let header_text = String::from_utf8(buf[..header_end].to_vec()).unwrap();
+    assert!(header_text.contains("property float value"));
+    assert!(!header_text.contains("property double value"));
+
+    let readings_read: Readings = ply_rs_bw::from_reader(&buf[..]).unwrap();
+    assert_eq!(readings, readings_read);
+}
+
+#[test]
+fn test_serde_write_with_schema_coerces_empty_list_to_declared_element_type() {
+    use ply_rs_bw::ply::{Addable, ElementDef, Header, PropertyDef, PropertyType, ScalarType};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Poly {
+        indices: Vec<u8>,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Polys {
+        poly: Vec<Poly>,
+    }
+
+    let polys = Polys {
+        poly: vec![Poly { indices: vec![] }],
+    };
+
+    let mut header = Header::new();
+    let mut element_def = ElementDef::new("poly".to_string());
+    element_def.properties.add(PropertyDef::new(
+        "indices".to_string(),
+        PropertyType::List(ScalarType::UChar, ScalarType::UChar),
+    ));
+    header.elements.add(element_def);
+
+    let mut buf = Vec::new();
+    ply_rs_bw::to_writer_with_schema(&mut buf, &polys, &header).unwrap();
+
+    let header_end = buf.windows(10).position(|w| w == b"end_header").unwrap();
+    let header_text = String::from_utf8(buf[..header_end].to_vec()).unwrap();
+    assert!(header_text.contains("property list uchar uchar indices"));
+
+    let polys_read: Polys = ply_rs_bw::from_reader(&buf[..]).unwrap();
+    assert_eq!(polys, polys_read);
+}
+
+#[test]
+fn test_serde_write_with_schema_errors_on_undeclared_property() {
+    use ply_rs_bw::ply::Header;
+
+    let mesh = Mesh {
+        vertex: vec![Vertex { x: 0.1, y: 0.2, z: 0.3 }],
+        face: vec![Face { vertex_index: vec![0] }],
+    };
+
+    // An empty header declares no elements/properties at all.
+    let header = Header::new();
+    let mut buf = Vec::new();
+    let err = ply_rs_bw::to_writer_with_schema(&mut buf, &mesh, &header).unwrap_err();
+    assert!(err.to_string().contains("not declared in the given header"));
+}
+
+#[test]
+fn test_serde_elements_streaming_skips_preceding_groups() {
+    use ply_rs_bw::PlyDeserializer;
+
+    let ply_data = "ply
+format ascii 1.0
+element vertex 2
+property float x
+property float y
+property float z
+element face 1
+property list uchar int vertex_index
+end_header
+0.1 0.2 0.3
+0.4 0.5 0.6
+3 0 1 0
+";
+
+    let mut de = PlyDeserializer::from_reader(ply_data.as_bytes()).unwrap();
+    let faces: Vec<Face> = de.elements::<Face>("face").unwrap().collect::<Result<_, _>>().unwrap();
+    assert_eq!(faces, vec![Face { vertex_index: vec![0, 1, 0] }]);
+}
+
+#[test]
+fn test_serde_elements_streaming_one_group_at_a_time() {
+    use ply_rs_bw::PlyDeserializer;
+
+    let ply_data = "ply
+format ascii 1.0
+element vertex 2
+property float x
+property float y
+property float z
+element face 1
+property list uchar int vertex_index
+end_header
+0.1 0.2 0.3
+0.4 0.5 0.6
+3 0 1 0
+";
+
+    let mut de = PlyDeserializer::from_reader(ply_data.as_bytes()).unwrap();
+
+    let mut vertices = Vec::new();
+    for vertex in de.elements::<Vertex>("vertex").unwrap() {
+        vertices.push(vertex.unwrap());
+    }
+    assert_eq!(vertices, vec![
+        Vertex { x: 0.1, y: 0.2, z: 0.3 },
+        Vertex { x: 0.4, y: 0.5, z: 0.6 },
+    ]);
+
+    let mut faces = Vec::new();
+    for face in de.elements::<Face>("face").unwrap() {
+        faces.push(face.unwrap());
+    }
+    assert_eq!(faces, vec![Face { vertex_index: vec![0, 1, 0] }]);
+}
+
+#[test]
+fn test_serde_elements_streaming_unknown_element_errors() {
+    use ply_rs_bw::PlyDeserializer;
+
+    let ply_data = "ply
+format ascii 1.0
+element vertex 1
+property float x
+property float y
+property float z
+end_header
+0.1 0.2 0.3
+";
+
+    let mut de = PlyDeserializer::from_reader(ply_data.as_bytes()).unwrap();
+    assert!(de.elements::<Vertex>("edge").is_err());
+}
+
+#[test]
+fn test_serde_elements_streaming_partial_iteration_then_next_group() {
+    use ply_rs_bw::PlyDeserializer;
+
+    let ply_data = "ply
+format ascii 1.0
+element vertex 2
+property float x
+property float y
+property float z
+element face 1
+property list uchar int vertex_index
+end_header
+0.1 0.2 0.3
+0.4 0.5 0.6
+3 0 1 0
+";
+
+    let mut de = PlyDeserializer::from_reader(ply_data.as_bytes()).unwrap();
+
+    {
+        // Only take the first vertex, then drop the stream before it's exhausted.
+        let mut vertices = de.elements::<Vertex>("vertex").unwrap();
+        assert_eq!(vertices.next().unwrap().unwrap(), Vertex { x: 0.1, y: 0.2, z: 0.3 });
+    }
+
+    // The dropped stream must have drained the unread second vertex, so this lands on
+    // the face group instead of the middle of the vertex payload.
+    let faces: Vec<Face> = de.elements::<Face>("face").unwrap().collect::<Result<_, _>>().unwrap();
+    assert_eq!(faces, vec![Face { vertex_index: vec![0, 1, 0] }]);
+}
+
+#[test]
+fn test_serde_elements_streaming_already_consumed_errors() {
+    use ply_rs_bw::PlyDeserializer;
+
+    let ply_data = "ply
+format ascii 1.0
+element vertex 1
+property float x
+property float y
+property float z
+end_header
+0.1 0.2 0.3
+";
+
+    let mut de = PlyDeserializer::from_reader(ply_data.as_bytes()).unwrap();
+    let _: Vec<Vertex> = de.elements::<Vertex>("vertex").unwrap().collect::<Result<_, _>>().unwrap();
+    assert!(de.elements::<Vertex>("vertex").is_err());
+}
+
+#[test]
+fn test_serde_elements_streaming_truncated_drain_desyncs_deserializer() {
+    use ply_rs_bw::PlyDeserializer;
+
+    // The "vertex" group declares 2 records but the payload only has 1 - draining the
+    // unread record on drop will hit EOF instead of a clean boundary.
+    let ply_data = "ply
+format ascii 1.0
+element vertex 2
+property float x
+property float y
+property float z
+element face 1
+property list uchar int vertex_index
+end_header
+0.1 0.2 0.3
+";
+
+    let mut de = PlyDeserializer::from_reader(ply_data.as_bytes()).unwrap();
+    {
+        let mut vertices = de.elements::<Vertex>("vertex").unwrap();
+        assert_eq!(vertices.next().unwrap().unwrap(), Vertex { x: 0.1, y: 0.2, z: 0.3 });
+    }
+
+    // The dropped stream couldn't drain the missing second vertex, so the deserializer must
+    // refuse to start another stream instead of decoding whatever bytes remain.
+    assert!(de.elements::<Face>("face").is_err());
+}
+
+mod manual_map_protocol_tests {
+    use super::*;
+    use std::collections::BTreeMap;
+    use serde::ser::SerializeMap;
+
+    /// A hand-written `Serialize` impl that drives `SerializeMap` via the standard
+    /// `serialize_key`/`serialize_value` pair instead of the `serialize_entry` shortcut -
+    /// what serde's own derive does for map-shaped data, and what most hand-written impls do.
+    struct MapVertex(BTreeMap<String, f32>);
+
+    impl Serialize for MapVertex {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            let mut map = serializer.serialize_map(Some(self.0.len()))?;
+            for (k, v) in &self.0 {
+                map.serialize_key(k)?;
+                map.serialize_value(v)?;
+            }
+            map.end()
+        }
+    }
+
+    #[derive(Serialize)]
+    struct MapMesh {
+        vertex: Vec<MapVertex>,
+    }
+
+    #[test]
+    fn test_element_map_serializer_two_call_protocol() {
+        let mut props = BTreeMap::new();
+        props.insert("x".to_string(), 0.1f32);
+        props.insert("y".to_string(), 0.2f32);
+        props.insert("z".to_string(), 0.3f32);
+
+        let mesh = MapMesh { vertex: vec![MapVertex(props)] };
+
+        let mut buf = Vec::new();
+        ply_rs_bw::to_writer(&mut buf, &mesh).unwrap();
+
+        let output = String::from_utf8(buf).unwrap();
+        assert!(output.contains("property float x"));
+        assert!(output.contains("property float y"));
+        assert!(output.contains("property float z"));
+        assert!(output.contains("0.1 0.2 0.3"));
+
+        #[derive(Deserialize)]
+        struct VertexOnlyMesh {
+            vertex: Vec<Vertex>,
+        }
+
+        let mesh_read: VertexOnlyMesh = ply_rs_bw::from_reader(output.as_bytes()).unwrap();
+        assert_eq!(mesh_read.vertex, vec![Vertex { x: 0.1, y: 0.2, z: 0.3 }]);
+    }
+}
+
 mod rename_tests {
     use super::*;
 
@@ -0,0 +1,79 @@
+//! `PlyDeserializer`'s `deserialize_any` chain (`PlyMapAccess` -> `SeqDeserializer` ->
+//! `ElementDeserializer` -> `ElementPropertyAccess` -> `PropertyDeserializer`) is already wired
+//! to dispatch purely off the parsed header - every property is visited through the `Property`
+//! variant the header's declared `ScalarType` decoded into, and a list property always drives
+//! `visit_seq`, even when empty. This means a target that doesn't know the element/property
+//! layout up front - a `HashMap` keyed by property name instead of a matching struct - already
+//! works with no special-casing. These tests pin that behavior down.
+
+use std::collections::HashMap;
+
+#[test]
+fn element_stream_decodes_into_a_schema_free_map() {
+    let ply_data = "ply
+format ascii 1.0
+element vertex 2
+property float x
+property int n
+end_header
+0.5 3
+1.5 7
+";
+    let mut de = ply_rs_bw::serde_impl::PlyDeserializer::from_reader(ply_data.as_bytes()).unwrap();
+    let rows: Vec<HashMap<String, f64>> = de
+        .elements::<HashMap<String, f64>>("vertex")
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0]["x"], 0.5);
+    assert_eq!(rows[0]["n"], 3.0);
+    assert_eq!(rows[1]["x"], 1.5);
+    assert_eq!(rows[1]["n"], 7.0);
+}
+
+#[test]
+fn list_property_decodes_into_a_schema_free_vec_even_when_empty() {
+    let ply_data = "ply
+format ascii 1.0
+element face 2
+property list uchar int vertex_index
+end_header
+3 0 1 2
+0
+";
+    let mut de = ply_rs_bw::serde_impl::PlyDeserializer::from_reader(ply_data.as_bytes()).unwrap();
+    let rows: Vec<HashMap<String, Vec<i64>>> = de
+        .elements::<HashMap<String, Vec<i64>>>("face")
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0]["vertex_index"], vec![0, 1, 2]);
+    assert_eq!(rows[1]["vertex_index"], Vec::<i64>::new());
+}
+
+#[test]
+fn whole_file_decodes_into_a_map_of_element_name_to_schema_free_rows() {
+    // No comments/obj_info here: the same top-level map also surfaces those under reserved
+    // keys whose values are `Vec<String>`, which wouldn't fit `Vec<HashMap<_, _>>` once
+    // non-empty, so a truly schema-free decode of the *whole* file only works cleanly when
+    // they're absent - see `ply_rs_bw::serde_impl::WithHeader` for decoding them too.
+    let ply_data = "ply
+format ascii 1.0
+element vertex 1
+property float x
+property float y
+end_header
+1.5 2.5
+";
+    let file: HashMap<String, Vec<HashMap<String, f64>>> =
+        ply_rs_bw::from_reader(ply_data.as_bytes()).unwrap();
+
+    let vertices = &file["vertex"];
+    assert_eq!(vertices.len(), 1);
+    assert_eq!(vertices[0]["x"], 1.5);
+    assert_eq!(vertices[0]["y"], 2.5);
+}
@@ -0,0 +1,103 @@
+use ply_rs_bw::PlyRead;
+use std::io::Cursor;
+
+#[derive(Debug, Default, PlyRead, Clone, PartialEq)]
+struct Vertex {
+    x: f32,
+    y: f32,
+    z: f32,
+}
+
+#[derive(Debug, Default, PlyRead, Clone, PartialEq)]
+struct Face {
+    #[ply(name = "vertex_indices", list, count = "uchar")]
+    indices: Vec<i32>,
+}
+
+#[test]
+fn read_strict_accepts_matching_header() {
+    let bytes = b"ply\n\
+format ascii 1.0\n\
+element vertex 1\n\
+property float x\n\
+property float y\n\
+property float z\n\
+end_header\n\
+1 2 3\n";
+    let mut cursor = Cursor::new(&bytes[..]);
+    let vertices = Vertex::read_strict(&mut cursor, "vertex").expect("strict read should succeed");
+    assert_eq!(vertices, vec![Vertex { x: 1.0, y: 2.0, z: 3.0 }]);
+}
+
+#[test]
+fn read_strict_rejects_missing_required_property() {
+    let bytes = b"ply\n\
+format ascii 1.0\n\
+element vertex 1\n\
+property float x\n\
+property float y\n\
+end_header\n\
+1 2\n";
+    let mut cursor = Cursor::new(&bytes[..]);
+    let err = Vertex::read_strict(&mut cursor, "vertex").expect_err("z is missing, should fail");
+    assert!(err.to_string().contains("z"));
+}
+
+#[test]
+fn read_strict_rejects_unmapped_header_property() {
+    // Blender-style mismatch: the file calls its channels something the struct doesn't know.
+    let bytes = b"ply\n\
+format ascii 1.0\n\
+element vertex 1\n\
+property float x\n\
+property float y\n\
+property float w\n\
+end_header\n\
+1 2 3\n";
+    let mut cursor = Cursor::new(&bytes[..]);
+    let err = Vertex::read_strict(&mut cursor, "vertex").expect_err("w has no matching field, should fail");
+    assert!(err.to_string().contains("w"));
+}
+
+#[test]
+fn read_strict_rejects_missing_element() {
+    let bytes = b"ply\nformat ascii 1.0\nend_header\n";
+    let mut cursor = Cursor::new(&bytes[..]);
+    let err = Vertex::read_strict(&mut cursor, "vertex").expect_err("no such element, should fail");
+    assert!(err.to_string().contains("vertex"));
+}
+
+#[test]
+fn read_strict_rejects_scalar_declared_as_list() {
+    let bytes = b"ply\n\
+format ascii 1.0\n\
+element face 1\n\
+property int vertex_indices\n\
+end_header\n\
+7\n";
+    let mut cursor = Cursor::new(&bytes[..]);
+    let err = Face::read_strict(&mut cursor, "face")
+        .expect_err("vertex_indices is declared as a scalar, not a list, should fail");
+    assert!(err.to_string().contains("vertex_indices"));
+}
+
+#[test]
+fn read_strict_rejects_mismatched_scalar_type() {
+    // `read_ply` would silently narrow this `double` into `x: f32` with an `as` cast;
+    // `read_strict` should catch the type mismatch instead.
+    let bytes = b"ply\n\
+format ascii 1.0\n\
+element vertex 1\n\
+property double x\n\
+property float y\n\
+property float z\n\
+end_header\n\
+1 2 3\n";
+    let mut cursor = Cursor::new(&bytes[..]);
+    let err = Vertex::read_strict(&mut cursor, "vertex")
+        .expect_err("x is declared double but the field is f32, should fail");
+    let msg = err.to_string();
+    assert!(msg.contains("x"));
+    assert!(msg.contains("f32"));
+    assert!(msg.contains("f64"));
+}
@@ -0,0 +1,11 @@
+use ply_rs_bw::PlyEnum;
+
+#[derive(PlyEnum)]
+#[ply(repr = "uchar")]
+enum Material {
+    Wood,
+    #[ply(value = 0)]
+    Metal,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use ply_rs_bw::PlyEnum;
+
+#[derive(PlyEnum)]
+enum Material {
+    Wood,
+    Metal,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use ply_rs_bw::PlyRead;
+
+#[derive(PlyRead)]
+struct Vertex {
+    #[ply(name = "x, y")]
+    position: [f32; 3],
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use ply_rs_bw::PlyRead;
+
+#[derive(PlyRead)]
+struct Element {
+    // PLY has no 128-bit scalar type; this should be rejected at compile time rather than
+    // silently narrowed to a 64-bit one.
+    foo: i128,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use ply_rs_bw::PlyWrite;
+
+#[derive(PlyWrite, Default)]
+struct Vertex {
+    #[ply(name = "x", rename = "pos")]
+    x: f32,
+    #[ply(name = "y", rename = "pos")]
+    y: f32,
+}
+
+fn main() {}
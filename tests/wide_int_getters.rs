@@ -1,38 +1,60 @@
-use ply_rs_bw::ply::GetProperty;
+//! 64-bit integer fields used to be silently downcast to `int`/`uint` on disk (truncating any
+//! value outside `i32`/`u32` range) because the derive macros had no `ScalarType::Long`/`ULong`
+//! getters of their own. `i64`/`u64` fields now round-trip losslessly through their own
+//! `get_long`/`get_ulong` (and `get_list_long`/`get_list_ulong`) `PropertyAccess` methods.
 
+use ply_rs_bw::ply::PropertyAccess;
+use ply_rs_bw::writer::ToPly;
+use ply_rs_bw::{FromPly, PlyRead, PlyWrite};
+
+#[derive(Debug, Default, PlyRead, PlyWrite, Clone, PartialEq)]
 struct Wide {
     x_i64: i64,
     x_u64: u64,
-    x_i128: i128,
-    x_u128: u128,
+    #[ply(name = "ids")]
+    ids: Vec<i64>,
+}
+
+#[derive(Debug, FromPly, ToPly, PartialEq)]
+struct Mesh {
+    elements: Vec<Wide>,
 }
 
 #[test]
-fn wide_integer_scalars_are_exposed_via_int_uint_getters_when_in_range() {
+fn scalar_i64_and_u64_fields_are_exposed_through_get_long_get_ulong() {
     let w = Wide {
-        x_i64: -123,
-        x_u64: 456,
-        x_i128: 789,
-        x_u128: 1011,
+        x_i64: i64::from(i32::MAX) + 1,
+        x_u64: u64::from(u32::MAX) + 1,
+        ids: vec![i64::MAX, i64::MIN],
     };
 
-    assert_eq!(GetProperty::<i32>::get(&w.x_i64), Some(-123));
-    assert_eq!(GetProperty::<u32>::get(&w.x_u64), Some(456));
-    assert_eq!(GetProperty::<i32>::get(&w.x_i128), Some(789));
-    assert_eq!(GetProperty::<u32>::get(&w.x_u128), Some(1011));
+    assert_eq!(PropertyAccess::get_long(&w, "x_i64"), Some(i64::from(i32::MAX) + 1));
+    assert_eq!(PropertyAccess::get_ulong(&w, "x_u64"), Some(u64::from(u32::MAX) + 1));
+    assert_eq!(PropertyAccess::get_list_long(&w, "ids"), Some([i64::MAX, i64::MIN].as_slice()));
+
+    // These values overflow `i32`/`u32`, so the old `get_int`/`get_uint` getters must not
+    // somehow still expose a truncated copy of them.
+    assert_eq!(PropertyAccess::get_int(&w, "x_i64"), None);
+    assert_eq!(PropertyAccess::get_uint(&w, "x_u64"), None);
 }
 
 #[test]
-fn wide_integer_getters_return_none_on_overflow() {
-    let w = Wide {
-        x_i64: i64::from(i32::MAX) + 1,
-        x_u64: u64::from(u32::MAX) + 1,
-        x_i128: i128::from(i32::MIN) - 1,
-        x_u128: u128::from(u32::MAX) + 1,
+fn i64_and_u64_round_trip_through_a_ply_file() {
+    let mesh = Mesh {
+        elements: vec![Wide {
+            x_i64: -9_000_000_000,
+            x_u64: 9_000_000_000,
+            ids: vec![1, 2, 3],
+        }],
     };
 
-    assert_eq!(GetProperty::<i32>::get(&w.x_i64), None);
-    assert_eq!(GetProperty::<u32>::get(&w.x_u64), None);
-    assert_eq!(GetProperty::<i32>::get(&w.x_i128), None);
-    assert_eq!(GetProperty::<u32>::get(&w.x_u128), None);
+    let mut bytes = Vec::new();
+    mesh.write_ply(&mut bytes).unwrap();
+    let text = String::from_utf8(bytes.clone()).unwrap();
+    assert!(text.contains("property int64 x_i64"));
+    assert!(text.contains("property uint64 x_u64"));
+
+    let mut reader = std::io::Cursor::new(bytes);
+    let read_back = Mesh::read_ply(&mut reader).unwrap();
+    assert_eq!(read_back, mesh);
 }